@@ -202,7 +202,7 @@ fn test_interval_time_increments() -> Result<(), Box<dyn std::error::Error>> {
     for (interval, expected_gap) in intervals_and_expected_gaps {
         // Create a request that will be split into exactly 2 chunks
         let from_date = NaiveDateTime::parse_from_str("2023-01-01 09:15:00", "%Y-%m-%d %H:%M:%S")?;
-        let to_date = from_date + chrono::Duration::days((interval.max_days_allowed() as i64) + 10);
+        let to_date = from_date + chrono::Duration::days((interval.max_days() as i64) + 10);
 
         let request = HistoricalDataRequest::new(738561, from_date, to_date, interval);
         let chunks = request.split_into_valid_requests();
@@ -36,6 +36,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             base_delay: Duration::from_millis(100),
             max_delay: Duration::from_secs(10),
             exponential_backoff: true,
+            ..Default::default()
         },
         timeout: 60, // 60 seconds timeout
         ..Default::default()
@@ -95,7 +95,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             inst.is_option()
                 && inst
                     .days_to_expiry()
-                    .map_or(false, |days| days <= 7 && days >= 0)
+                    .is_some_and(|days| (0..=7).contains(&days))
         })
         .collect();
 
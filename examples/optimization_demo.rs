@@ -41,7 +41,7 @@ fn demo_newly_listed_stock() -> Result<(), Box<dyn std::error::Error>> {
     );
     
     let total_days = request.days_span();
-    let max_chunk_days = Interval::Day.max_days_allowed() as i64;
+    let max_chunk_days = Interval::Day.max_days() as i64;
     let total_possible_chunks = (total_days + max_chunk_days - 1) / max_chunk_days;
     
     // With reverse processing, we would process chunks like:
@@ -136,7 +136,7 @@ fn demo_interval_comparison() -> Result<(), Box<dyn std::error::Error>> {
     for (interval, name, use_case) in intervals_and_scenarios {
         let request = HistoricalDataRequest::new(123456, from_date, to_date, interval);
         let chunks = request.split_into_valid_requests();
-        let max_days = interval.max_days_allowed();
+        let max_days = interval.max_days();
         
         println!("📊 {} Data ({})", name, use_case);
         println!("   • API Limit: {} days per request", max_days);
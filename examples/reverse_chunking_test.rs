@@ -19,7 +19,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     println!("📊 Original request span: {} days", request.days_span());
-    println!("📊 Max allowed for 5-minute interval: {} days", Interval::FiveMinute.max_days_allowed());
+    println!("📊 Max allowed for 5-minute interval: {} days", Interval::FiveMinute.max_days());
     
     // Test forward chunking
     let forward_chunks = request.split_into_valid_requests();
@@ -9,7 +9,9 @@ use std::collections::HashMap;
 
 // Import typed models for dual API support
 use crate::models::auth::{SessionData, UserProfile};
-use crate::models::common::KiteResult;
+use crate::models::common::timestamps::IST;
+use crate::models::common::{KiteError, KiteResult};
+use crate::models::orders::PostbackPayload;
 
 // Native platform imports
 #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
@@ -27,6 +29,194 @@ use wasm_bindgen_futures::JsFuture;
 
 use crate::connect::KiteConnect;
 
+/// Source of the current time for a [`RefreshSchedule`]
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+type Clock = std::sync::Arc<dyn Fn() -> chrono::DateTime<chrono::Utc> + Send + Sync>;
+
+/// When a background token refresher renews the access token
+///
+/// Kite access tokens expire daily at 06:00 IST. The default [`daily`](Self::daily)
+/// schedule renews a few minutes before that. The clock is injectable so the
+/// schedule can be tested deterministically.
+///
+/// # Example
+///
+/// ```rust
+/// use kiteconnect_async_wasm::connect::RefreshSchedule;
+/// use chrono::{TimeZone, Utc};
+/// use std::time::Duration;
+///
+/// // 04:00 IST (22:30 UTC the previous day)
+/// let schedule = RefreshSchedule::daily()
+///     .with_clock(|| Utc.with_ymd_and_hms(2024, 1, 14, 22, 30, 0).unwrap());
+///
+/// // Renews at 05:55 IST
+/// assert_eq!(schedule.next_delay(), Duration::from_secs(115 * 60));
+/// ```
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+#[derive(Clone)]
+pub struct RefreshSchedule {
+    kind: ScheduleKind,
+    clock: Clock,
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+#[derive(Debug, Clone)]
+enum ScheduleKind {
+    /// Renew `lead` before `expiry` (IST wall-clock time) every day
+    Daily {
+        expiry: chrono::NaiveTime,
+        lead: std::time::Duration,
+    },
+    /// Renew at a fixed interval
+    Every(std::time::Duration),
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+impl RefreshSchedule {
+    /// Renew five minutes before the 06:00 IST daily expiry
+    pub fn daily() -> Self {
+        Self::daily_at(
+            chrono::NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+            std::time::Duration::from_secs(5 * 60),
+        )
+    }
+
+    /// Renew `lead` before `expiry`, an IST wall-clock time, every day
+    pub fn daily_at(expiry: chrono::NaiveTime, lead: std::time::Duration) -> Self {
+        Self {
+            kind: ScheduleKind::Daily { expiry, lead },
+            clock: std::sync::Arc::new(chrono::Utc::now),
+        }
+    }
+
+    /// Renew at a fixed interval
+    pub fn every(interval: std::time::Duration) -> Self {
+        Self {
+            kind: ScheduleKind::Every(interval),
+            clock: std::sync::Arc::new(chrono::Utc::now),
+        }
+    }
+
+    /// Replace the clock used to compute the next renewal time
+    pub fn with_clock(
+        mut self,
+        clock: impl Fn() -> chrono::DateTime<chrono::Utc> + Send + Sync + 'static,
+    ) -> Self {
+        self.clock = std::sync::Arc::new(clock);
+        self
+    }
+
+    /// Time to wait, from the clock's current time, until the next renewal
+    pub fn next_delay(&self) -> std::time::Duration {
+        match &self.kind {
+            ScheduleKind::Every(interval) => *interval,
+            ScheduleKind::Daily { expiry, lead } => {
+                use chrono::TimeZone;
+
+                let now = (self.clock)().with_timezone(&IST);
+                let lead = chrono::Duration::from_std(*lead).unwrap_or_default();
+
+                let mut renew_at = IST
+                    .from_local_datetime(&now.date_naive().and_time(*expiry))
+                    .unwrap()
+                    - lead;
+                if renew_at <= now {
+                    renew_at += chrono::Duration::days(1);
+                }
+
+                (renew_at - now).to_std().unwrap_or_default()
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+impl std::fmt::Debug for RefreshSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshSchedule")
+            .field("kind", &self.kind)
+            .finish_non_exhaustive()
+    }
+}
+
+/// SHA-256 hex digest - different implementations for native vs WASM
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+async fn compute_checksum(input: &str) -> Result<String> {
+    let mut hasher = Sha256::new();
+    hasher.update(input.as_bytes());
+    let result = hasher.finalize();
+    Ok(hex::encode(result))
+}
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+async fn compute_checksum(input: &str) -> Result<String> {
+    // WASM implementation using Web Crypto API
+    let window = window().ok_or_else(|| anyhow!("No window object"))?;
+    let crypto = window.crypto().map_err(|_| anyhow!("No crypto object"))?;
+    let subtle = crypto.subtle();
+
+    let data = Uint8Array::from(input.as_bytes());
+    let digest_promise = subtle
+        .digest_with_str_and_u8_array("SHA-256", &data.to_vec())
+        .map_err(|_| anyhow!("Failed to create digest"))?;
+
+    let digest_result = JsFuture::from(digest_promise)
+        .await
+        .map_err(|_| anyhow!("Failed to compute hash"))?;
+
+    let digest_array = Uint8Array::new(&digest_result);
+    let digest_vec: Vec<u8> = digest_array.to_vec();
+    Ok(hex::encode(digest_vec))
+}
+
+/// Fallback checksum implementation when neither native nor wasm features are enabled
+#[cfg(not(any(
+    all(feature = "native", not(target_arch = "wasm32")),
+    all(feature = "wasm", target_arch = "wasm32")
+)))]
+async fn compute_checksum(_input: &str) -> Result<String> {
+    Err(anyhow!(
+        "Checksum computation requires either 'native' or 'wasm' feature to be enabled"
+    ))
+}
+
+/// Verify that an order postback was sent by Kite
+///
+/// Kite signs each postback with the SHA-256 hex digest of
+/// `order_id + order_timestamp + api_secret`. Returns `false` when `checksum`
+/// does not match or the digest cannot be computed on this platform. The
+/// function is async because WASM hashes through the Web Crypto API.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kiteconnect_async_wasm::connect::verify_postback;
+/// use kiteconnect_async_wasm::models::orders::PostbackPayload;
+///
+/// # async fn example(body: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// let payload: PostbackPayload = serde_json::from_str(body)?;
+/// if verify_postback(&payload, "api_secret", &payload.checksum).await {
+///     println!("Order {} is {:?}", payload.order_id, payload.status);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn verify_postback(payload: &PostbackPayload, api_secret: &str, checksum: &str) -> bool {
+    match compute_checksum(&payload.checksum_input(api_secret)).await {
+        Ok(expected) => constant_time_eq(
+            expected.as_bytes(),
+            checksum.to_ascii_lowercase().as_bytes(),
+        ),
+        Err(_) => false,
+    }
+}
+
+/// Compare without short-circuiting on the first differing byte
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 impl KiteConnect {
     // === LEGACY API METHODS (JSON responses) ===
 
@@ -65,45 +255,39 @@ impl KiteConnect {
         )
     }
 
-    /// Compute checksum for authentication - different implementations for native vs WASM
-    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
-    async fn compute_checksum(&self, input: &str) -> Result<String> {
-        let mut hasher = Sha256::new();
-        hasher.update(input.as_bytes());
-        let result = hasher.finalize();
-        Ok(hex::encode(result))
-    }
-
-    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
-    async fn compute_checksum(&self, input: &str) -> Result<String> {
-        // WASM implementation using Web Crypto API
-        let window = window().ok_or_else(|| anyhow!("No window object"))?;
-        let crypto = window.crypto().map_err(|_| anyhow!("No crypto object"))?;
-        let subtle = crypto.subtle();
-
-        let data = Uint8Array::from(input.as_bytes());
-        let digest_promise = subtle
-            .digest_with_str_and_u8_array("SHA-256", &data.to_vec())
-            .map_err(|_| anyhow!("Failed to create digest"))?;
-
-        let digest_result = JsFuture::from(digest_promise)
-            .await
-            .map_err(|_| anyhow!("Failed to compute hash"))?;
-
-        let digest_array = Uint8Array::new(&digest_result);
-        let digest_vec: Vec<u8> = digest_array.to_vec();
-        Ok(hex::encode(digest_vec))
-    }
+    /// Generates the login URL with extra parameters passed through `redirect_params`
+    ///
+    /// Kite appends the decoded `redirect_params` to the redirect URL after
+    /// login, so app state such as a CSRF `state` token comes back with the
+    /// `request_token`. The pairs are URL-encoded; with none this equals
+    /// [`login_url`](Self::login_url).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// let client = KiteConnect::new("your_api_key", "");
+    /// let login_url = client.login_url_with_params(&[("state", "a1b2 c3")]);
+    ///
+    /// assert_eq!(
+    ///     login_url,
+    ///     "https://kite.trade/connect/login?api_key=your_api_key&v3&redirect_params=state%3Da1b2%2Bc3"
+    /// );
+    /// ```
+    pub fn login_url_with_params(&self, extra: &[(&str, &str)]) -> String {
+        if extra.is_empty() {
+            return self.login_url();
+        }
 
-    /// Fallback checksum implementation when neither native nor wasm features are enabled
-    #[cfg(not(any(
-        all(feature = "native", not(target_arch = "wasm32")),
-        all(feature = "wasm", target_arch = "wasm32")
-    )))]
-    async fn compute_checksum(&self, _input: &str) -> Result<String> {
-        Err(anyhow!(
-            "Checksum computation requires either 'native' or 'wasm' feature to be enabled"
-        ))
+        let redirect_params = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(extra)
+            .finish();
+        format!(
+            "{}&redirect_params={}",
+            self.login_url(),
+            url::form_urlencoded::byte_serialize(redirect_params.as_bytes()).collect::<String>()
+        )
     }
 
     /// Generates an access token using the request token from login
@@ -124,7 +308,8 @@ impl KiteConnect {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The request token is invalid or expired
+    /// - The request token is invalid, expired or already used
+    ///   ([`KiteError::RequestTokenUsed`]); restart the login flow to get a new one
     /// - The API secret is incorrect
     /// - Network request fails
     /// - Response parsing fails
@@ -156,14 +341,27 @@ impl KiteConnect {
     /// 3. User is redirected with `request_token` parameter
     /// 4. Call this method with the request token and API secret
     /// 5. Access token is automatically set for subsequent API calls
+    ///
+    /// Session generation is never retried automatically, since the request
+    /// token is consumed by the first submission.
+    #[cfg(feature = "legacy")]
     pub async fn generate_session(
         &mut self,
         request_token: &str,
         api_secret: &str,
+    ) -> Result<JsonValue> {
+        self.generate_session_json(request_token, api_secret).await
+    }
+
+    /// Exchange a request token for a session, shared by the legacy and typed APIs
+    async fn generate_session_json(
+        &mut self,
+        request_token: &str,
+        api_secret: &str,
     ) -> Result<JsonValue> {
         // Create a hex digest from api key, request token, api secret
         let input = format!("{}{}{}", self.api_key, request_token, api_secret);
-        let checksum = self.compute_checksum(&input).await?;
+        let checksum = compute_checksum(&input).await?;
 
         let api_key: &str = &self.api_key.clone();
         let mut data = HashMap::new();
@@ -171,8 +369,10 @@ impl KiteConnect {
         data.insert("request_token", request_token);
         data.insert("checksum", checksum.as_str());
 
+        // Request tokens are single-use, so a retried submission would always
+        // fail with a misleading token error. Submit exactly once.
         let resp = self
-            .send_request_with_rate_limiting_and_retry(
+            .send_request_with_rate_limiting_once(
                 KiteEndpoint::GenerateSession,
                 &[],
                 None,
@@ -181,13 +381,27 @@ impl KiteConnect {
             .await
             .map_err(|e| anyhow!("Generate session failed: {:?}", e))?;
 
-        if resp.status().is_success() {
-            let jsn: JsonValue = resp.json().await?;
-            self.set_access_token(jsn["data"]["access_token"].as_str().unwrap());
-            Ok(jsn)
-        } else {
-            let error_text: String = resp.text().await?;
-            Err(anyhow!(error_text))
+        match self.raise_or_return_json(resp).await {
+            Ok(jsn) => match jsn["data"]["access_token"].as_str() {
+                Some(access_token) if !access_token.is_empty() => {
+                    self.set_access_token(access_token);
+                    Ok(jsn)
+                }
+                // A success status without a token means the login did not
+                // actually go through; never leave the client half-authenticated
+                _ => Err(anyhow::Error::new(KiteError::auth_error(
+                    "Session response did not contain an access_token",
+                ))),
+            },
+            Err(e) => match e.downcast::<KiteError>() {
+                // A TokenException here means the request token was already
+                // exchanged (e.g. a double submit) or has expired
+                Ok(KiteError::TokenException(message)) => {
+                    Err(anyhow::Error::new(KiteError::request_token_used(message)))
+                }
+                Ok(kite_error) => Err(anyhow::Error::new(kite_error)),
+                Err(e) => Err(e),
+            },
         }
     }
 
@@ -219,7 +433,13 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn invalidate_access_token(&self, access_token: &str) -> Result<JsonValue> {
+        self.invalidate_access_token_json(access_token).await
+    }
+
+    /// Invalidate an access token, shared by the legacy and typed APIs
+    async fn invalidate_access_token_json(&self, access_token: &str) -> Result<JsonValue> {
         // For invalidate session, the API expects query parameters, not form data
         let query_params = vec![
             ("api_key", self.api_key.as_str()),
@@ -245,20 +465,22 @@ impl KiteConnect {
         }
     }
 
-    /// Request for new access token
+    /// Request for new access token using a refresh token
+    ///
+    /// On success the new access token is set on the client (and all of its clones).
     pub async fn renew_access_token(
         &mut self,
-        access_token: &str,
+        refresh_token: &str,
         api_secret: &str,
     ) -> Result<JsonValue> {
-        // Create a hex digest from api key, request token, api secret
-        let input = format!("{}{}{}", self.api_key, access_token, api_secret);
-        let checksum = self.compute_checksum(&input).await?;
+        // Create a hex digest from api key, refresh token, api secret
+        let input = format!("{}{}{}", self.api_key, refresh_token, api_secret);
+        let checksum = compute_checksum(&input).await?;
 
         let api_key: &str = &self.api_key.clone();
         let mut data = HashMap::new();
         data.insert("api_key", api_key);
-        data.insert("access_token", access_token);
+        data.insert("refresh_token", refresh_token);
         data.insert("checksum", checksum.as_str());
 
         let resp = self
@@ -273,7 +495,11 @@ impl KiteConnect {
 
         if resp.status().is_success() {
             let jsn: JsonValue = resp.json().await?;
-            self.set_access_token(jsn["access_token"].as_str().unwrap());
+            let access_token = jsn["data"]["access_token"]
+                .as_str()
+                .or_else(|| jsn["access_token"].as_str())
+                .ok_or_else(|| anyhow!("Renew access token response has no access_token"))?;
+            self.set_access_token(access_token);
             Ok(jsn)
         } else {
             let error_text: String = resp.text().await?;
@@ -281,6 +507,98 @@ impl KiteConnect {
         }
     }
 
+    /// Spawn a background task that renews the access token every day
+    ///
+    /// The task sleeps until shortly before the daily token expiry (06:00 IST),
+    /// calls [`renew_access_token`](Self::renew_access_token) and updates the
+    /// access token shared by this client and all of its clones, then repeats.
+    /// Failed renewals are retried according to the client's retry config
+    /// before falling back to the schedule.
+    ///
+    /// Cancel the refresher by calling [`abort`](tokio::task::JoinHandle::abort)
+    /// on the returned handle, or stop it along with the client via
+    /// [`shutdown`](Self::shutdown).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let refresher = client.spawn_token_refresher(
+    ///     "refresh_token".to_string(),
+    ///     "api_secret".to_string(),
+    /// );
+    ///
+    /// // ... use `client` as usual; its token stays fresh ...
+    ///
+    /// refresher.abort();
+    /// # }
+    /// ```
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    pub fn spawn_token_refresher(
+        &self,
+        refresh_token: String,
+        api_secret: String,
+    ) -> tokio::task::JoinHandle<()> {
+        self.spawn_token_refresher_with_schedule(
+            refresh_token,
+            api_secret,
+            RefreshSchedule::daily(),
+        )
+    }
+
+    /// Spawn a background token refresher using a custom [`RefreshSchedule`]
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    pub fn spawn_token_refresher_with_schedule(
+        &self,
+        refresh_token: String,
+        api_secret: String,
+        schedule: RefreshSchedule,
+    ) -> tokio::task::JoinHandle<()> {
+        let mut client = self.clone();
+
+        let handle = tokio::spawn(async move {
+            let mut refresh_token = refresh_token;
+            let mut failures = 0;
+            let mut delay = schedule.next_delay();
+
+            loop {
+                tokio::time::sleep(delay).await;
+
+                match client.renew_access_token(&refresh_token, &api_secret).await {
+                    Ok(response) => {
+                        // Kite may rotate the refresh token along with the access token
+                        if let Some(token) = response["data"]["refresh_token"].as_str() {
+                            refresh_token = token.to_string();
+                        }
+                        failures = 0;
+                        delay = schedule.next_delay();
+                    }
+                    #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+                    Err(e) => {
+                        #[cfg(feature = "debug")]
+                        log::warn!("Access token renewal failed: {}", e);
+
+                        if failures < client.retry_config.max_retries {
+                            delay = client.calculate_retry_delay(failures);
+                            failures += 1;
+                        } else {
+                            failures = 0;
+                            delay = schedule.next_delay();
+                        }
+                    }
+                }
+            }
+        });
+
+        self.track_background_task(handle.abort_handle());
+        handle
+    }
+
     /// Invalidates the refresh token
     pub async fn invalidate_refresh_token(&self, refresh_token: &str) -> Result<reqwest::Response> {
         let mut data = HashMap::new();
@@ -312,6 +630,14 @@ impl KiteConnect {
     ///
     /// A `KiteResult<SessionData>` containing typed session information
     ///
+    /// # Errors
+    ///
+    /// Returns [`KiteError::Authentication`] if the response lacks an
+    /// `access_token` or cannot be read as [`SessionData`], and
+    /// [`KiteError::RequestTokenUsed`] if the request token was already
+    /// exchanged. The client's access token is left unchanged when the
+    /// response carries no `access_token`.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -333,13 +659,14 @@ impl KiteConnect {
         api_secret: &str,
     ) -> KiteResult<SessionData> {
         let json_response = self
-            .generate_session(request_token, api_secret)
+            .generate_session_json(request_token, api_secret)
             .await
-            .map_err(crate::models::common::KiteError::Legacy)?;
+            .map_err(|e| e.downcast::<KiteError>().unwrap_or_else(KiteError::Legacy))?;
 
         // Extract the data field from response
         let data = json_response["data"].clone();
         self.parse_response(data)
+            .map_err(|e| KiteError::auth_error(format!("Unexpected session response: {}", e)))
     }
 
     /// Get user profile with typed response
@@ -404,7 +731,7 @@ impl KiteConnect {
     /// ```
     pub async fn invalidate_access_token_typed(&self, access_token: &str) -> KiteResult<bool> {
         let json_response = self
-            .invalidate_access_token(access_token)
+            .invalidate_access_token_json(access_token)
             .await
             .map_err(crate::models::common::KiteError::Legacy)?;
 
@@ -0,0 +1,191 @@
+//! # Client Builder Module
+//!
+//! Fluent construction of [`KiteConnect`] clients.
+//!
+//! [`KiteConnectBuilder`] starts from [`KiteConnectConfig::default`] and only
+//! overrides the settings that are explicitly chained, which keeps client
+//! setup in applications and tests down to the fields that matter.
+//! [`KiteConnect::new`] and [`KiteConnect::new_with_config`] remain available.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use kiteconnect_async_wasm::connect::{KiteConnect, RetryConfig};
+//! use std::time::Duration;
+//!
+//! let client = KiteConnect::builder()
+//!     .api_key("your_api_key")
+//!     .access_token("your_access_token")
+//!     .timeout(Duration::from_secs(10))
+//!     .retry(RetryConfig {
+//!         max_retries: 5,
+//!         ..Default::default()
+//!     })
+//!     .build()
+//!     .unwrap();
+//!
+//! assert_eq!(client.timeout(), Duration::from_secs(10));
+//! assert_eq!(client.access_token(), "your_access_token");
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{
+    market_data, CacheBackend, CacheConfig, KiteConnect, KiteConnectConfig, RateLimitCategory,
+    RetryConfig,
+};
+use crate::models::common::{KiteError, KiteResult};
+
+/// Builder for [`KiteConnect`] clients
+///
+/// Every setting not chained keeps its [`KiteConnectConfig::default`] value.
+/// Only the API key is required.
+#[derive(Debug, Clone, Default)]
+pub struct KiteConnectBuilder {
+    api_key: Option<String>,
+    access_token: Option<String>,
+    config: KiteConnectConfig,
+}
+
+impl KiteConnectBuilder {
+    /// Create a builder with the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the API key (required)
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Set the access token for authenticated requests
+    ///
+    /// May be omitted when the token will be obtained via `generate_session`.
+    pub fn access_token(mut self, access_token: impl Into<String>) -> Self {
+        self.access_token = Some(access_token.into());
+        self
+    }
+
+    /// Set the request timeout
+    ///
+    /// Timeouts are applied with one-second granularity; fractions of a
+    /// second are rounded up.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        let mut seconds = timeout.as_secs();
+        if timeout.subsec_nanos() > 0 {
+            seconds += 1;
+        }
+        self.config.timeout = seconds.max(1);
+        self
+    }
+
+    /// Set the retry behaviour for failed requests
+    pub fn retry(mut self, retry_config: RetryConfig) -> Self {
+        self.config.retry_config = retry_config;
+        self
+    }
+
+    /// Set the API base URL, e.g. to point at a mock server in tests
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.config.base_url = base_url.into();
+        self
+    }
+
+    /// Send requests in `category` to `base_url` instead of the main base URL
+    ///
+    /// Useful for pointing historical data at a mirror or recorded fixtures
+    /// while orders keep going to production.
+    pub fn endpoint_override(
+        mut self,
+        category: RateLimitCategory,
+        base_url: impl Into<String>,
+    ) -> Self {
+        self.config
+            .endpoint_overrides
+            .insert(category, base_url.into());
+        self
+    }
+
+    /// Keep the headers of the most recent response, see
+    /// [`KiteConnect::last_response_headers`]
+    pub fn capture_response_headers(mut self, enabled: bool) -> Self {
+        self.config.capture_response_headers = enabled;
+        self
+    }
+
+    /// Fall back to a null-tolerant parse when a typed response fails strict
+    /// deserialization, see [`KiteConnect::with_lenient_parsing`]
+    pub fn lenient_parsing(mut self, enabled: bool) -> Self {
+        self.config.lenient_parsing = enabled;
+        self
+    }
+
+    /// Enable or disable client-side rate limiting
+    pub fn rate_limiting(mut self, enabled: bool) -> Self {
+        self.config.enable_rate_limiting = enabled;
+        self
+    }
+
+    /// Set the response cache configuration
+    pub fn cache(mut self, cache_config: CacheConfig) -> Self {
+        self.config.cache_config = Some(cache_config);
+        self
+    }
+
+    /// Store cached responses in `backend` instead of process memory
+    pub fn cache_backend(mut self, backend: Arc<dyn CacheBackend>) -> Self {
+        self.config.cache_backend = Some(backend);
+        self
+    }
+
+    /// Start from a full configuration, keeping any API key and access token
+    /// already set on the builder
+    pub fn config(mut self, config: KiteConnectConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Build the client
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KiteError::InvalidParameter`] if no API key was provided,
+    /// or [`KiteError::InputException`] if `quote_batch_size` is outside
+    /// `1..=MAX_QUOTE_BATCH_SIZE` or `historical_max_chunks` is zero.
+    pub fn build(self) -> KiteResult<KiteConnect> {
+        let api_key = self
+            .api_key
+            .filter(|key| !key.trim().is_empty())
+            .ok_or_else(|| KiteError::invalid_param("api_key is required"))?;
+        market_data::check_quote_batch_size(self.config.quote_batch_size)?;
+        market_data::check_historical_max_chunks(self.config.historical_max_chunks)?;
+
+        let mut client = KiteConnect::new_with_config(&api_key, self.config);
+        if let Some(access_token) = self.access_token {
+            client.set_access_token(&access_token);
+        }
+        Ok(client)
+    }
+}
+
+impl KiteConnect {
+    /// Start building a client with [`KiteConnectBuilder`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// let client = KiteConnect::builder()
+    ///     .api_key("your_api_key")
+    ///     .rate_limiting(false)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(!client.is_rate_limiting_enabled());
+    /// ```
+    pub fn builder() -> KiteConnectBuilder {
+        KiteConnectBuilder::new()
+    }
+}
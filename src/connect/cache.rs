@@ -0,0 +1,117 @@
+//! # Cache Backends
+//!
+//! Storage behind the client's response cache. By default responses are kept
+//! in process memory with [`InMemoryCache`]; implement [`CacheBackend`] to
+//! share them between processes, e.g. through Redis or sled, and pass it as
+//! `KiteConnectConfig::cache_backend`.
+//!
+//! The client decides freshness itself from the stored time and
+//! `CacheConfig::cache_ttl_minutes`, so backends only need to store values.
+//!
+//! ## Keys
+//!
+//! - `instruments` - the full instruments list
+//! - `instruments:{EXCHANGE}` - the instruments of one exchange, e.g. `instruments:NSE`
+
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+/// Storage for cached API responses
+///
+/// # Example
+///
+/// ```rust
+/// use kiteconnect_async_wasm::connect::{CacheBackend, KiteConnect, KiteConnectConfig};
+/// use serde_json::Value as JsonValue;
+/// use std::collections::HashMap;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::SystemTime;
+///
+/// /// Stand-in for a shared store such as Redis
+/// #[derive(Debug, Default)]
+/// struct SharedStore(Mutex<HashMap<String, (JsonValue, SystemTime)>>);
+///
+/// impl CacheBackend for SharedStore {
+///     fn get(&self, key: &str) -> Option<(JsonValue, SystemTime)> {
+///         self.0.lock().unwrap().get(key).cloned()
+///     }
+///
+///     fn set(&self, key: &str, value: JsonValue) {
+///         self.set_at(key, value, SystemTime::now());
+///     }
+///
+///     fn set_at(&self, key: &str, value: JsonValue, stored_at: SystemTime) {
+///         self.0.lock().unwrap().insert(key.to_string(), (value, stored_at));
+///     }
+/// }
+///
+/// let config = KiteConnectConfig {
+///     cache_backend: Some(Arc::new(SharedStore::default())),
+///     ..Default::default()
+/// };
+/// let client = KiteConnect::new_with_config("api_key", config);
+/// ```
+pub trait CacheBackend: Send + Sync + std::fmt::Debug {
+    /// Value stored under `key` and the time it was stored
+    fn get(&self, key: &str) -> Option<(JsonValue, SystemTime)>;
+
+    /// Store `value` under `key`, stamped with the current time
+    fn set(&self, key: &str, value: JsonValue);
+
+    /// Store `value` under `key` as if stored at `stored_at`
+    ///
+    /// Used when restoring data fetched earlier, such as the on-disk
+    /// instruments cache, so it still expires on time. Defaults to [`set`](Self::set).
+    fn set_at(&self, key: &str, value: JsonValue, stored_at: SystemTime) {
+        let _ = stored_at;
+        self.set(key, value);
+    }
+
+    /// Remove every entry, called by `KiteConnect::clear_instruments_cache`
+    ///
+    /// Does nothing by default, so a shared store is left intact.
+    fn clear(&self) {}
+}
+
+/// Default [`CacheBackend`] keeping values in process memory
+#[derive(Debug, Default)]
+pub struct InMemoryCache {
+    entries: RwLock<HashMap<String, (JsonValue, SystemTime)>>,
+}
+
+impl InMemoryCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CacheBackend for InMemoryCache {
+    fn get(&self, key: &str) -> Option<(JsonValue, SystemTime)> {
+        self.entries
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(key)
+            .cloned()
+    }
+
+    fn set(&self, key: &str, value: JsonValue) {
+        self.set_at(key, value, SystemTime::now());
+    }
+
+    fn set_at(&self, key: &str, value: JsonValue, stored_at: SystemTime) {
+        self.entries
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(key.to_string(), (value, stored_at));
+    }
+
+    fn clear(&self) {
+        self.entries
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clear();
+    }
+}
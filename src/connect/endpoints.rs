@@ -243,6 +243,8 @@ pub enum KiteEndpoint {
     // === Portfolio Endpoints ===
     /// Get holdings
     Holdings,
+    /// Get holdings eligible for the auction market
+    HoldingsAuctions,
     /// Get positions
     Positions,
     /// Convert position
@@ -285,6 +287,10 @@ pub enum KiteEndpoint {
     TriggerRange,
     /// Get market margins
     MarketMargins,
+    /// Calculate margins for a basket of orders
+    OrderMargins,
+    /// Calculate combined margins for a basket, including hedge benefit
+    BasketMargins,
 
     // === Mutual Fund Endpoints ===
     /// Place MF order
@@ -384,6 +390,12 @@ impl KiteEndpoint {
                 RateLimitCategory::Standard,
                 true,
             ),
+            KiteEndpoint::HoldingsAuctions => Endpoint::new(
+                HttpMethod::GET,
+                "/portfolio/holdings/auctions",
+                RateLimitCategory::Standard,
+                true,
+            ),
             KiteEndpoint::Positions => Endpoint::new(
                 HttpMethod::GET,
                 "/portfolio/positions",
@@ -485,6 +497,18 @@ impl KiteEndpoint {
                 RateLimitCategory::Standard,
                 true,
             ),
+            KiteEndpoint::OrderMargins => Endpoint::new(
+                HttpMethod::POST,
+                "/margins/orders",
+                RateLimitCategory::Standard,
+                true,
+            ),
+            KiteEndpoint::BasketMargins => Endpoint::new(
+                HttpMethod::POST,
+                "/margins/basket",
+                RateLimitCategory::Standard,
+                true,
+            ),
 
             // === Mutual Fund Endpoints ===
             KiteEndpoint::PlaceMFOrder => Endpoint::new(
@@ -623,19 +647,20 @@ impl KiteEndpoint {
         }
     }
 
-    /// Get all endpoints in a specific rate limit category
-    pub fn by_rate_limit_category(category: RateLimitCategory) -> Vec<KiteEndpoint> {
+    /// Every endpoint known to the client
+    pub const ALL: [KiteEndpoint; 44] = {
         use KiteEndpoint::*;
-
-        let all_endpoints = vec![
+        [
             LoginUrl,
             GenerateSession,
             InvalidateSession,
             RenewAccessToken,
+            InvalidateRefreshToken,
             Profile,
             Margins,
             MarginsSegment,
             Holdings,
+            HoldingsAuctions,
             Positions,
             ConvertPosition,
             PlaceOrder,
@@ -653,6 +678,8 @@ impl KiteEndpoint {
             MFInstruments,
             TriggerRange,
             MarketMargins,
+            OrderMargins,
+            BasketMargins,
             PlaceMFOrder,
             CancelMFOrder,
             MFOrders,
@@ -668,9 +695,21 @@ impl KiteEndpoint {
             CancelGTT,
             GTTs,
             GTTInfo,
-        ];
+        ]
+    };
 
-        all_endpoints
+    /// Get all endpoints in a specific rate limit category
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::connect::endpoints::{KiteEndpoint, RateLimitCategory};
+    ///
+    /// let historical = KiteEndpoint::by_rate_limit_category(RateLimitCategory::Historical);
+    /// assert_eq!(historical, vec![KiteEndpoint::HistoricalData]);
+    /// ```
+    pub fn by_rate_limit_category(category: RateLimitCategory) -> Vec<KiteEndpoint> {
+        Self::ALL
             .into_iter()
             .filter(|endpoint| endpoint.rate_limit_category() == category)
             .collect()
@@ -736,6 +775,76 @@ mod tests {
         assert!(order_endpoints.contains(&KiteEndpoint::CancelOrder));
     }
 
+    #[test]
+    fn test_every_endpoint_category() {
+        use KiteEndpoint::*;
+
+        for endpoint in KiteEndpoint::ALL {
+            // No wildcard: adding an endpoint forces a decision here
+            let expected = match endpoint {
+                Quote | OHLC | LTP => RateLimitCategory::Quote,
+                HistoricalData => RateLimitCategory::Historical,
+                PlaceOrder | ModifyOrder | CancelOrder | PlaceMFOrder | CancelMFOrder
+                | PlaceSIP | ModifySIP | CancelSIP | PlaceGTT | ModifyGTT | CancelGTT => {
+                    RateLimitCategory::Orders
+                }
+                LoginUrl
+                | GenerateSession
+                | InvalidateSession
+                | RenewAccessToken
+                | InvalidateRefreshToken
+                | Profile
+                | Margins
+                | MarginsSegment
+                | Holdings
+                | HoldingsAuctions
+                | Positions
+                | ConvertPosition
+                | Orders
+                | OrderHistory
+                | Trades
+                | OrderTrades
+                | Instruments
+                | MFInstruments
+                | TriggerRange
+                | MarketMargins
+                | OrderMargins
+                | BasketMargins
+                | MFOrders
+                | MFOrderInfo
+                | MFHoldings
+                | SIPs
+                | SIPInfo
+                | GTTs
+                | GTTInfo => RateLimitCategory::Standard,
+            };
+            assert_eq!(endpoint.rate_limit_category(), expected, "{:?}", endpoint);
+        }
+    }
+
+    #[test]
+    fn test_all_endpoints_listed_once() {
+        let mut seen = std::collections::HashSet::new();
+        for endpoint in KiteEndpoint::ALL {
+            assert!(seen.insert(endpoint.clone()), "{:?} listed twice", endpoint);
+        }
+
+        let grouped: usize = [
+            RateLimitCategory::Quote,
+            RateLimitCategory::Historical,
+            RateLimitCategory::Orders,
+            RateLimitCategory::Standard,
+        ]
+        .into_iter()
+        .map(|category| KiteEndpoint::by_rate_limit_category(category).len())
+        .sum();
+        assert_eq!(grouped, KiteEndpoint::ALL.len());
+        assert!(
+            KiteEndpoint::by_rate_limit_category(RateLimitCategory::Standard)
+                .contains(&KiteEndpoint::InvalidateRefreshToken)
+        );
+    }
+
     #[test]
     fn test_authentication_requirements() {
         assert!(!KiteEndpoint::LoginUrl.requires_auth());
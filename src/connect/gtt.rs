@@ -338,6 +338,12 @@
 
 use crate::connect::endpoints::KiteEndpoint;
 use crate::connect::KiteConnect;
+use crate::models::common::timestamps::format_kite_time;
+use crate::models::common::{KiteError, KiteResult};
+use crate::models::gtt::{
+    GTTCondition, GTTCreateParams, GTTModifyParams, GTTOrderParams, GTTResponse, GTTTriggerType,
+    GTT,
+};
 use anyhow::Result;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
@@ -616,4 +622,263 @@ impl KiteConnect {
 
         self.raise_or_return_json(resp).await
     }
+
+    // === TYPED API METHODS ===
+
+    /// Get all GTT triggers with typed response
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// for gtt in client.get_gtts_typed().await? {
+    ///     println!("GTT {} on {}: {}", gtt.id, gtt.condition.trading_symbol, gtt.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_gtts_typed(&self) -> KiteResult<Vec<GTT>> {
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(KiteEndpoint::GTTs, &[], None, None)
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
+    /// Get a single GTT trigger with typed response
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger_id` - ID returned when the GTT was placed
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let gtt = client.get_gtt_typed(123456).await?;
+    /// println!("Triggers at {:?}", gtt.trigger_values());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_gtt_typed(&self, trigger_id: u64) -> KiteResult<GTT> {
+        let trigger_id = trigger_id.to_string();
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::GTTInfo,
+                &[&trigger_id],
+                None,
+                None,
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
+    /// Place a GTT with typed parameters
+    ///
+    /// The condition and orders are sent as JSON strings in the `condition`
+    /// and `orders` form fields, as Kite expects. Parameters are validated
+    /// first: a single trigger needs one trigger value and one order, a
+    /// two-leg (OCO) trigger needs two of each.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - GTT to place, e.g. from [`StopLossGTTBuilder`](crate::models::gtt::StopLossGTTBuilder) or [`BracketGTTBuilder`](crate::models::gtt::BracketGTTBuilder)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::{Exchange, Product, TransactionType};
+    /// use kiteconnect_async_wasm::models::gtt::BracketGTTBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let bracket = BracketGTTBuilder::new()
+    ///     .exchange(Exchange::NSE)
+    ///     .trading_symbol("RELIANCE")
+    ///     .transaction_type(TransactionType::SELL)
+    ///     .product(Product::CNC)
+    ///     .quantity(10)
+    ///     .stop_loss_price(2000.0)
+    ///     .target_price(2200.0)
+    ///     .current_price(2100.0)
+    ///     .build()?;
+    ///
+    /// let response = client.place_gtt_typed(bracket).await?;
+    /// println!("GTT placed: {}", response.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn place_gtt_typed(&self, params: GTTCreateParams) -> KiteResult<GTTResponse> {
+        params.validate().map_err(KiteError::input_exception)?;
+
+        let (gtt_type, condition, orders) =
+            gtt_form_fields(&params.gtt_type, &params.condition, &params.orders)?;
+        let expires_at = params.expires_at.as_ref().map(format_kite_time);
+
+        let mut form = HashMap::new();
+        form.insert("type", gtt_type.as_str());
+        form.insert("condition", condition.as_str());
+        form.insert("orders", orders.as_str());
+        if let Some(expires_at) = &expires_at {
+            form.insert("expires_at", expires_at.as_str());
+        }
+
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::PlaceGTT,
+                &[],
+                None,
+                Some(form),
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
+    /// Modify a GTT with typed parameters
+    ///
+    /// Kite replaces the trigger rather than patching it, so the full
+    /// condition and orders are sent again. The trigger type follows the
+    /// orders: two orders make an OCO trigger, whose two trigger values
+    /// must be in ascending order. `params.expires_at`, when set, replaces
+    /// its expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `trigger_id` - ID of the GTT to modify; `params.gtt_id` is ignored
+    /// * `params` - Replacement condition, orders and expiry
+    ///
+    /// # Errors
+    ///
+    /// Returns `KiteError::General` if the parameters fail
+    /// [`GTTModifyParams::validate`], without sending a request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::gtt::GTTModifyParams;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// // Trail the stop-loss of an existing OCO trigger
+    /// let mut gtt = client.get_gtt_typed(123456).await?;
+    /// gtt.condition.trigger_values[0] = 2050.0;
+    /// gtt.orders[0].price = 2045.0;
+    ///
+    /// let params = GTTModifyParams::new(gtt.id, gtt.condition, gtt.orders);
+    /// client.modify_gtt_typed(gtt.id, params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn modify_gtt_typed(
+        &self,
+        trigger_id: u64,
+        params: GTTModifyParams,
+    ) -> KiteResult<GTTResponse> {
+        params.validate().map_err(KiteError::general)?;
+
+        let gtt_type = params.gtt_type();
+        let (Some(condition), Some(orders)) = (&params.condition, &params.orders) else {
+            return Err(KiteError::general("GTT condition and orders are required"));
+        };
+        let (gtt_type, condition, orders) = gtt_form_fields(&gtt_type, condition, orders)?;
+        let expires_at = params.expires_at.as_ref().map(format_kite_time);
+
+        let mut form = HashMap::new();
+        form.insert("type", gtt_type.as_str());
+        form.insert("condition", condition.as_str());
+        form.insert("orders", orders.as_str());
+        if let Some(expires_at) = &expires_at {
+            form.insert("expires_at", expires_at.as_str());
+        }
+
+        let trigger_id = trigger_id.to_string();
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::ModifyGTT,
+                &[&trigger_id],
+                None,
+                Some(form),
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
+    /// Delete a GTT with typed response
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let deleted = client.delete_gtt_typed(123456).await?;
+    /// println!("Deleted GTT {}", deleted.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_gtt_typed(&self, trigger_id: u64) -> KiteResult<GTTResponse> {
+        let trigger_id = trigger_id.to_string();
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::CancelGTT,
+                &[&trigger_id],
+                None,
+                None,
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+}
+
+/// Encode the `type`, `condition` and `orders` form fields of a GTT request
+fn gtt_form_fields(
+    gtt_type: &GTTTriggerType,
+    condition: &GTTCondition,
+    orders: &[GTTOrderParams],
+) -> KiteResult<(String, String, String)> {
+    let gtt_type = match gtt_type {
+        GTTTriggerType::Single => "single",
+        GTTTriggerType::TwoLeg => "two-leg",
+    };
+    let condition = serde_json::to_string(condition).map_err(KiteError::Json)?;
+    let orders = serde_json::to_string(orders).map_err(KiteError::Json)?;
+    Ok((gtt_type.to_string(), condition, orders))
 }
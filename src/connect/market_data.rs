@@ -67,9 +67,10 @@
 //! ### Historical Data (Enhanced in v1.0.3)
 //! - [`historical_data()`](KiteConnect::historical_data) - Legacy historical data API
 //! - [`historical_data_typed()`](KiteConnect::historical_data_typed) - New structured request API
+//! - [`historical_data_batch()`](KiteConnect::historical_data_batch) - Concurrent fetch for many instruments
 //!
 //! ### Market Information
-//! - [`trigger_range()`](KiteConnect::trigger_range) - Get trigger range for instruments
+//! - [`trigger_range()`](KiteConnect::trigger_range) / [`trigger_range_typed()`](KiteConnect::trigger_range_typed) - Get trigger range for instruments
 //! - [`instruments_margins()`](KiteConnect::instruments_margins) - Get margin requirements
 //!
 //! ## Usage Examples
@@ -224,11 +225,11 @@
 //! # let client = kiteconnect_async_wasm::connect::KiteConnect::new("", "");
 //! match client.quote_typed(vec!["INVALID:SYMBOL"]).await {
 //!     Ok(quotes) => println!("Success: {} quotes", quotes.len()),
+//!     Err(KiteError::RateLimited { retry_after, .. }) => {
+//!         eprintln!("Rate limited - retry after {:?}", retry_after);
+//!     }
 //!     Err(KiteError::Api { status, message, .. }) => {
 //!         eprintln!("API Error {}: {}", status, message);
-//!         if status == "429" {
-//!             eprintln!("Rate limited - please wait before retrying");
-//!         }
 //!     }
 //!     Err(KiteError::Authentication(msg)) => {
 //!         eprintln!("Authentication failed: {}", msg);
@@ -273,11 +274,17 @@
 
 use crate::connect::endpoints::KiteEndpoint;
 use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use futures_util::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
 use serde_json::Value as JsonValue;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
-// Native platform imports
 #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
-use csv::ReaderBuilder;
+use std::path::PathBuf;
 
 #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
 use crate::connect::utils::parse_csv_with_core;
@@ -285,12 +292,300 @@ use crate::connect::utils::RequestHandler;
 use crate::connect::KiteConnect;
 
 // Import typed models for dual API support
-use crate::models::common::{Exchange, KiteError, KiteResult};
+use crate::models::common::timestamps::IST;
+use crate::models::common::{
+    Exchange, InstrumentType, KiteError, KiteResult, Segment, TransactionType,
+};
 use crate::models::market_data::{
-    HistoricalData, HistoricalDataRequest, HistoricalMetadata, Quote, LTP, OHLC,
+    HistoricalData, HistoricalDataRequest, HistoricalMetadata, Instrument, InstrumentParseReport,
+    MarketState, MarketStatus, OHLCQuote, Quote, QuoteBundle, TriggerRange, LTP,
 };
 use crate::models::mutual_funds::MFInstrument;
 
+/// How long a [`KiteConnect::market_status`] result is reused
+pub const MARKET_STATUS_TTL: Duration = Duration::from_secs(60);
+
+/// File name of the persisted instruments list inside `CacheConfig::cache_dir`
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+const INSTRUMENTS_CACHE_FILE: &str = "instruments.json";
+
+/// On-disk layout of the persisted instruments list
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+#[derive(serde::Deserialize)]
+struct InstrumentsCacheFile {
+    /// Fetch time in seconds since the Unix epoch
+    saved_at: u64,
+    instruments: JsonValue,
+}
+
+/// Borrowed form of [`InstrumentsCacheFile`] for writing without a copy
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+#[derive(serde::Serialize)]
+struct InstrumentsCacheFileRef<'a> {
+    saved_at: u64,
+    instruments: &'a JsonValue,
+}
+
+/// Incremental CSV parser fed with response body chunks
+///
+/// Each row becomes a JSON object of strings keyed by the header row. Only
+/// the field being read is buffered, so a download never has to be held in
+/// memory as text. Short rows are kept; `instruments_typed` skips and counts
+/// them.
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+struct StreamingCsv {
+    reader: csv_core::Reader,
+    field: Vec<u8>,
+    record: Vec<String>,
+    headers: Option<Vec<String>>,
+    rows: Vec<JsonValue>,
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+impl StreamingCsv {
+    fn new() -> Self {
+        Self {
+            reader: csv_core::Reader::new(),
+            field: Vec::new(),
+            record: Vec::new(),
+            headers: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Parse the next chunk of input
+    fn feed(&mut self, input: &[u8]) {
+        self.read(input, false);
+    }
+
+    /// Run the reader over `input`; csv-core takes an empty buffer to mean
+    /// the end of the input, so one is only passed when `eof` is set
+    fn read(&mut self, mut input: &[u8], eof: bool) {
+        let mut output = [0u8; 1024];
+        while eof || !input.is_empty() {
+            let (result, consumed, written) = self.reader.read_field(input, &mut output);
+            input = &input[consumed..];
+            self.field.extend_from_slice(&output[..written]);
+
+            match result {
+                csv_core::ReadFieldResult::InputEmpty | csv_core::ReadFieldResult::End => break,
+                csv_core::ReadFieldResult::OutputFull => {}
+                csv_core::ReadFieldResult::Field { record_end } => {
+                    let field = String::from_utf8_lossy(&self.field).into_owned();
+                    self.record.push(field);
+                    self.field.clear();
+                    if record_end {
+                        self.end_record();
+                    }
+                }
+            }
+        }
+    }
+
+    fn end_record(&mut self) {
+        let record = std::mem::take(&mut self.record);
+        let Some(headers) = &self.headers else {
+            self.headers = Some(record);
+            return;
+        };
+
+        let obj: serde_json::Map<String, JsonValue> = headers
+            .iter()
+            .zip(record)
+            .map(|(header, value)| (header.clone(), JsonValue::String(value)))
+            .collect();
+        self.rows.push(JsonValue::Object(obj));
+    }
+
+    /// Flush the last record and return the parsed rows
+    fn finish(mut self) -> JsonValue {
+        self.read(&[], true);
+        JsonValue::Array(self.rows)
+    }
+}
+
+/// Stream a CSV response body through [`StreamingCsv`], gunzipping on the fly
+///
+/// The body is treated as gzip when `Content-Encoding` or `Content-Type` says
+/// so, or when it starts with the gzip magic bytes; gzipped dumps are not
+/// always labelled.
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+async fn parse_csv_response(resp: reqwest::Response) -> Result<JsonValue> {
+    let header_says_gzip = |name: &str| {
+        resp.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|value| value.contains("gzip"))
+    };
+    let labelled_gzip = header_says_gzip("content-encoding") || header_says_gzip("content-type");
+
+    let gzip_decoder = |head: &[u8]| {
+        (labelled_gzip || head.starts_with(&GZIP_MAGIC))
+            .then(|| flate2::write::GzDecoder::new(Vec::new()))
+    };
+
+    let mut parser = StreamingCsv::new();
+    let mut decoder = None;
+    // Leading bytes held back until there are enough to check for gzip
+    let mut head = Vec::new();
+    let mut sniffed = false;
+    let mut body = resp.bytes_stream();
+
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk?;
+        if sniffed {
+            feed_csv_chunk(&mut parser, decoder.as_mut(), &chunk)?;
+            continue;
+        }
+
+        head.extend_from_slice(&chunk);
+        if head.len() >= GZIP_MAGIC.len() {
+            sniffed = true;
+            decoder = gzip_decoder(&head);
+            feed_csv_chunk(&mut parser, decoder.as_mut(), &std::mem::take(&mut head))?;
+        }
+    }
+
+    if !sniffed {
+        decoder = gzip_decoder(&head);
+        feed_csv_chunk(&mut parser, decoder.as_mut(), &head)?;
+    }
+    if let Some(decoder) = decoder {
+        parser.feed(&decoder.finish()?);
+    }
+
+    Ok(parser.finish())
+}
+
+/// Pass a body chunk to the parser, through the gzip decoder if there is one
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+fn feed_csv_chunk(
+    parser: &mut StreamingCsv,
+    decoder: Option<&mut flate2::write::GzDecoder<Vec<u8>>>,
+    chunk: &[u8],
+) -> std::io::Result<()> {
+    use std::io::Write;
+
+    match decoder {
+        Some(decoder) => {
+            decoder.write_all(chunk)?;
+            parser.feed(decoder.get_ref());
+            decoder.get_mut().clear();
+        }
+        None => parser.feed(chunk),
+    }
+    Ok(())
+}
+
+/// Leading bytes of every gzip stream
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Convert parsed CSV rows into instruments, recording the rows that fail
+fn parse_instrument_rows(rows: &JsonValue) -> KiteResult<InstrumentParseReport> {
+    let rows = rows
+        .as_array()
+        .ok_or_else(|| KiteError::general("Invalid instruments response format".to_string()))?;
+
+    let mut report = InstrumentParseReport {
+        parsed: Vec::with_capacity(rows.len()),
+        ..Default::default()
+    };
+
+    for (index, row) in rows.iter().enumerate() {
+        match serde_json::from_value::<Instrument>(row.clone()) {
+            Ok(instrument) => report.parsed.push(instrument),
+            Err(e) => {
+                // Line 1 is the header
+                let line = index + 2;
+                let message = match row.get("tradingsymbol").and_then(JsonValue::as_str) {
+                    Some(symbol) if !symbol.is_empty() => {
+                        format!("line {} ({}): {}", line, symbol, e)
+                    }
+                    _ => format!("line {}: {}", line, e),
+                };
+
+                #[cfg(feature = "debug")]
+                log::warn!("Skipping instrument row: {}", message);
+
+                report.errors.push(message);
+                report.skipped += 1;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Maximum instruments Kite accepts in a single quote request
+pub const MAX_QUOTE_BATCH_SIZE: usize = 500;
+
+/// Default cap on the sub-requests `historical_data_typed` issues for one range
+///
+/// Enough for about eight years of minute data or the full history of any
+/// other interval.
+pub const DEFAULT_HISTORICAL_MAX_CHUNKS: usize = 100;
+
+/// Upper bound on concurrent historical data chunk requests
+///
+/// Matches the historical data rate limit of 3 requests per second.
+pub const MAX_HISTORICAL_CONCURRENCY: usize = 3;
+
+/// Check `KiteConnectConfig::quote_batch_size` is within `1..=MAX_QUOTE_BATCH_SIZE`
+pub(crate) fn check_quote_batch_size(quote_batch_size: usize) -> KiteResult<()> {
+    if (1..=MAX_QUOTE_BATCH_SIZE).contains(&quote_batch_size) {
+        Ok(())
+    } else {
+        Err(KiteError::input_exception(format!(
+            "quote_batch_size must be between 1 and {}, got {}",
+            MAX_QUOTE_BATCH_SIZE, quote_batch_size
+        )))
+    }
+}
+
+/// Check `KiteConnectConfig::historical_max_chunks` allows at least one request
+pub(crate) fn check_historical_max_chunks(historical_max_chunks: usize) -> KiteResult<()> {
+    if historical_max_chunks == 0 {
+        return Err(KiteError::input_exception(
+            "historical_max_chunks must be at least 1",
+        ));
+    }
+    Ok(())
+}
+
+/// Check that every instrument identifier is `EXCHANGE:SYMBOL` or a numeric token
+///
+/// Returns a `KiteError::General` listing all invalid entries, so malformed
+/// input fails locally instead of with an opaque 400 from the API.
+fn validate_instrument_identifiers<S: AsRef<str>>(instruments: &[S]) -> KiteResult<()> {
+    let invalid: Vec<String> = instruments
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|id| {
+            let is_token = !id.is_empty() && id.chars().all(|c| c.is_ascii_digit());
+            let is_symbol = match id.split_once(':') {
+                Some((exchange, symbol)) => {
+                    !exchange.trim().is_empty()
+                        && !symbol.trim().is_empty()
+                        && !exchange.contains(char::is_whitespace)
+                }
+                None => false,
+            };
+            !(is_token || is_symbol)
+        })
+        .map(|id| format!("{:?}", id))
+        .collect();
+
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(KiteError::general(format!(
+            "Invalid instrument identifiers (expected EXCHANGE:SYMBOL or a numeric token): {}",
+            invalid.join(", ")
+        )))
+    }
+}
+
 impl KiteConnect {
     // === LEGACY API METHODS (JSON responses) ===
 
@@ -320,15 +615,33 @@ impl KiteConnect {
     }
 
     /// Get instruments list
-    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    ///
+    /// Returns each CSV row as a JSON object of strings. When caching is
+    /// enabled, the full list and each exchange's list are cached separately.
+    #[cfg(feature = "legacy")]
     pub async fn instruments(&self, exchange: Option<&str>) -> Result<JsonValue> {
+        self.instruments_json(exchange).await
+    }
+
+    /// Get mutual fund instruments list
+    ///
+    /// Returns each CSV row as a JSON object of strings.
+    #[cfg(feature = "legacy")]
+    pub async fn mf_instruments(&self) -> Result<JsonValue> {
+        self.mf_instruments_json().await
+    }
+
+    /// Fetch and parse the instruments CSV
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    pub(crate) async fn instruments_json(&self, exchange: Option<&str>) -> Result<JsonValue> {
         // Check cache first if enabled
         if let Some(ref cache_config) = self.cache_config {
-            if cache_config.enable_instruments_cache && exchange.is_none() {
-                // Only cache the full instruments list, not exchange-specific ones
+            if cache_config.enable_instruments_cache {
                 if let Ok(cache_guard) = self.response_cache.lock() {
                     if let Some(ref cache) = *cache_guard {
-                        if let Some(cached_data) = cache.get_instruments() {
+                        let cached = cache.get_instruments(exchange);
+                        self.metrics.record_cache_lookup(cached.is_some());
+                        if let Some(cached_data) = cached {
                             #[cfg(feature = "debug")]
                             log::debug!("Returning cached instruments data");
                             return Ok(cached_data);
@@ -338,6 +651,19 @@ impl KiteConnect {
             }
         }
 
+        if exchange.is_none() {
+            if let Some((cached_data, fetched_at)) = self.load_instruments_from_disk().await {
+                #[cfg(feature = "debug")]
+                log::debug!("Returning instruments data from disk cache");
+                if let Ok(mut cache_guard) = self.response_cache.lock() {
+                    if let Some(ref mut cache) = *cache_guard {
+                        cache.set_instruments_at(None, cached_data.clone(), fetched_at);
+                    }
+                }
+                return Ok(cached_data);
+            }
+        }
+
         let endpoint = KiteEndpoint::Instruments;
 
         let path_segments = if let Some(exchange) = exchange {
@@ -361,86 +687,162 @@ impl KiteConnect {
         #[cfg(feature = "debug")]
         log::debug!("Received response with status: {}", resp.status());
 
-        // Check if response is gzipped and handle accordingly
-        let content_encoding = resp
-            .headers()
-            .get("content-encoding")
-            .and_then(|v| v.to_str().ok())
-            .unwrap_or("")
-            .to_string();
-
-        let body_text = if content_encoding.contains("gzip") {
-            #[cfg(feature = "debug")]
-            log::debug!("Decompressing gzipped response");
-
-            let body_bytes = resp.bytes().await?;
-            use std::io::Read;
-            let mut decoder = flate2::read::GzDecoder::new(&body_bytes[..]);
-            let mut decompressed = String::new();
-            decoder.read_to_string(&mut decompressed)?;
-            decompressed
-        } else {
-            resp.text().await?
-        };
+        // Parse CSV response as it arrives rather than buffering the body
+        let result_json = parse_csv_response(resp).await?;
 
         #[cfg(feature = "debug")]
         log::debug!(
-            "Decompressed body length: {} bytes, first 200 chars: {}",
-            body_text.len(),
-            &body_text.chars().take(200).collect::<String>()
+            "Parsed {} records from CSV",
+            result_json.as_array().map_or(0, Vec::len)
         );
 
-        // Parse CSV response
-        let mut rdr = ReaderBuilder::new().from_reader(body_text.as_bytes());
-        let mut result = Vec::new();
+        // Cache the result per exchange filter if enabled
+        if let Some(ref cache_config) = self.cache_config {
+            if cache_config.enable_instruments_cache {
+                if let Ok(mut cache_guard) = self.response_cache.lock() {
+                    if let Some(ref mut cache) = *cache_guard {
+                        cache.set_instruments(exchange, result_json.clone());
+                    }
+                }
+            }
+        }
+        if exchange.is_none() {
+            self.save_instruments_to_disk(&result_json).await;
+        }
 
-        let headers = rdr.headers()?.clone();
-        #[cfg(feature = "debug")]
-        log::debug!("CSV headers: {:?}", headers);
+        Ok(result_json)
+    }
 
-        let mut _record_count = 0;
-        for record in rdr.records() {
-            let record = record?;
-            let mut obj = serde_json::Map::new();
+    /// Path of the persisted instruments list, if a cache directory is configured
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    fn instruments_cache_path(&self) -> Option<PathBuf> {
+        let cache_config = self.cache_config.as_ref()?;
+        cache_config
+            .cache_dir
+            .as_ref()
+            .map(|dir| dir.join(INSTRUMENTS_CACHE_FILE))
+    }
 
-            for (i, field) in record.iter().enumerate() {
-                if let Some(header) = headers.get(i) {
-                    obj.insert(header.to_string(), JsonValue::String(field.to_string()));
-                }
+    /// Load the persisted instruments list if it is within the cache TTL
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    async fn load_instruments_from_disk(&self) -> Option<(JsonValue, SystemTime)> {
+        let cache_config = self.cache_config.as_ref()?;
+        if !cache_config.enable_instruments_cache {
+            return None;
+        }
+        let bytes = tokio::fs::read(self.instruments_cache_path()?).await.ok()?;
+        let file: InstrumentsCacheFile = serde_json::from_slice(&bytes).ok()?;
+
+        let fetched_at = SystemTime::UNIX_EPOCH + Duration::from_secs(file.saved_at);
+        let ttl = Duration::from_secs(cache_config.cache_ttl_minutes * 60);
+        if fetched_at.elapsed().ok()? >= ttl {
+            return None;
+        }
+        Some((file.instruments, fetched_at))
+    }
+
+    /// Persist the full instruments list; failures only cost a re-download later
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    async fn save_instruments_to_disk(&self, instruments: &JsonValue) {
+        let enabled = self
+            .cache_config
+            .as_ref()
+            .is_some_and(|config| config.enable_instruments_cache);
+        let Some(path) = self.instruments_cache_path().filter(|_| enabled) else {
+            return;
+        };
+
+        let file = InstrumentsCacheFileRef {
+            saved_at: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or_default(),
+            instruments,
+        };
+
+        // Write then rename so a concurrent reader never sees a partial file
+        let result: std::io::Result<()> = async {
+            if let Some(dir) = path.parent() {
+                tokio::fs::create_dir_all(dir).await?;
             }
-            result.push(JsonValue::Object(obj));
-            _record_count += 1;
+            let temp_path = path.with_extension("json.tmp");
+            tokio::fs::write(&temp_path, serde_json::to_vec(&file)?).await?;
+            tokio::fs::rename(&temp_path, &path).await
         }
+        .await;
 
-        #[cfg(feature = "debug")]
-        log::debug!("Parsed {} records from CSV", _record_count);
+        #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+        if let Err(e) = result {
+            #[cfg(feature = "debug")]
+            log::warn!(
+                "Failed to write instruments cache {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
 
-        let result_json = JsonValue::Array(result);
+    /// Drop the cached instruments list, in memory and on disk
+    ///
+    /// The next instruments call downloads a fresh list. Use this after
+    /// Kite's daily instrument refresh if a long-running process must not
+    /// wait for `cache_ttl_minutes` to lapse.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::{CacheConfig, KiteConnect, KiteConnectConfig};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = KiteConnectConfig {
+    ///     cache_config: Some(CacheConfig {
+    ///         cache_dir: Some("/var/cache/kite".into()),
+    ///         ..Default::default()
+    ///     }),
+    ///     ..Default::default()
+    /// };
+    /// let client = KiteConnect::new_with_config("api_key", config);
+    ///
+    /// client.clear_instruments_cache()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clear_instruments_cache(&self) -> KiteResult<()> {
+        if let Ok(mut cache_guard) = self.response_cache.lock() {
+            if let Some(ref mut cache) = *cache_guard {
+                cache.clear();
+            }
+        }
 
-        // Cache the result if enabled and it's the full instruments list
-        if let Some(ref cache_config) = self.cache_config {
-            if cache_config.enable_instruments_cache && exchange.is_none() {
-                if let Ok(mut cache_guard) = self.response_cache.lock() {
-                    if let Some(ref mut cache) = *cache_guard {
-                        cache.set_instruments(result_json.clone());
-                    }
+        #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+        if let Some(path) = self.instruments_cache_path() {
+            match std::fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => {
+                    return Err(KiteError::general(format!(
+                        "Failed to remove instruments cache {}: {}",
+                        path.display(),
+                        e
+                    )))
                 }
             }
         }
 
-        Ok(result_json)
+        Ok(())
     }
 
-    /// Get instruments list (WASM version - now parses CSV using csv-core)
+    /// Fetch and parse the instruments CSV (WASM version - parses CSV using csv-core)
     #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
-    pub async fn instruments(&self, exchange: Option<&str>) -> Result<JsonValue> {
+    pub(crate) async fn instruments_json(&self, exchange: Option<&str>) -> Result<JsonValue> {
         // Check cache first if enabled
         if let Some(ref cache_config) = self.cache_config {
-            if cache_config.enable_instruments_cache && exchange.is_none() {
-                // Only cache the full instruments list, not exchange-specific ones
+            if cache_config.enable_instruments_cache {
                 if let Ok(cache_guard) = self.response_cache.lock() {
                     if let Some(ref cache) = *cache_guard {
-                        if let Some(cached_data) = cache.get_instruments() {
+                        let cached = cache.get_instruments(exchange);
+                        self.metrics.record_cache_lookup(cached.is_some());
+                        if let Some(cached_data) = cached {
                             return Ok(cached_data);
                         }
                     }
@@ -488,12 +890,12 @@ impl KiteConnect {
         // Parse CSV using csv-core for WASM compatibility
         let result = parse_csv_with_core(&body)?;
 
-        // Cache the result if enabled and it's the full instruments list
+        // Cache the result per exchange filter if enabled
         if let Some(ref cache_config) = self.cache_config {
-            if cache_config.enable_instruments_cache && exchange.is_none() {
+            if cache_config.enable_instruments_cache {
                 if let Ok(mut cache_guard) = self.response_cache.lock() {
                     if let Some(ref mut cache) = *cache_guard {
-                        cache.set_instruments(result.clone());
+                        cache.set_instruments(exchange, result.clone());
                     }
                 }
             }
@@ -502,38 +904,21 @@ impl KiteConnect {
         Ok(result)
     }
 
-    /// Get mutual fund instruments list
+    /// Fetch and parse the mutual fund instruments CSV
     #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
-    pub async fn mf_instruments(&self) -> Result<JsonValue> {
+    pub(crate) async fn mf_instruments_json(&self) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(KiteEndpoint::MFInstruments, &[], None, None)
             .await
             .map_err(|e| anyhow::anyhow!("Get MF instruments failed: {:?}", e))?;
-        let body = resp.text().await?;
 
         // Parse CSV response
-        let mut rdr = ReaderBuilder::new().from_reader(body.as_bytes());
-        let mut result = Vec::new();
-
-        let headers = rdr.headers()?.clone();
-        for record in rdr.records() {
-            let record = record?;
-            let mut obj = serde_json::Map::new();
-
-            for (i, field) in record.iter().enumerate() {
-                if let Some(header) = headers.get(i) {
-                    obj.insert(header.to_string(), JsonValue::String(field.to_string()));
-                }
-            }
-            result.push(JsonValue::Object(obj));
-        }
-
-        Ok(JsonValue::Array(result))
+        parse_csv_response(resp).await
     }
 
-    /// Get mutual fund instruments list (WASM version - now parses CSV using csv-core)
+    /// Fetch and parse the mutual fund instruments CSV (WASM version - parses CSV using csv-core)
     #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
-    pub async fn mf_instruments(&self) -> Result<JsonValue> {
+    pub(crate) async fn mf_instruments_json(&self) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(KiteEndpoint::MFInstruments, &[], None, None)
             .await
@@ -544,23 +929,23 @@ impl KiteConnect {
         parse_csv_with_core(&body)
     }
 
-    /// Get instruments list (fallback when no platform features are enabled)
+    /// Fetch instruments (fallback when no platform features are enabled)
     #[cfg(not(any(
         all(feature = "native", not(target_arch = "wasm32")),
         all(feature = "wasm", target_arch = "wasm32")
     )))]
-    pub async fn instruments(&self, _exchange: Option<&str>) -> Result<JsonValue> {
+    pub(crate) async fn instruments_json(&self, _exchange: Option<&str>) -> Result<JsonValue> {
         Err(anyhow!(
             "Instruments functionality requires either 'native' or 'wasm' feature to be enabled"
         ))
     }
 
-    /// Get mutual fund instruments list (fallback when no platform features are enabled)
+    /// Fetch mutual fund instruments (fallback when no platform features are enabled)
     #[cfg(not(any(
         all(feature = "native", not(target_arch = "wasm32")),
         all(feature = "wasm", target_arch = "wasm32")
     )))]
-    pub async fn mf_instruments(&self) -> Result<JsonValue> {
+    pub(crate) async fn mf_instruments_json(&self) -> Result<JsonValue> {
         Err(anyhow!(
             "MF instruments functionality requires either 'native' or 'wasm' feature to be enabled"
         ))
@@ -637,6 +1022,7 @@ impl KiteConnect {
     /// - Large date ranges may be split into multiple requests automatically
     /// - Intraday data older than a certain period may not be available
     /// - Weekend and holiday data will not be included in the response
+    #[cfg(feature = "legacy")]
     pub async fn historical_data(
         &self,
         instrument_token: &str,
@@ -697,6 +1083,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn quote(&self, instruments: Vec<&str>) -> Result<JsonValue> {
         let params: Vec<_> = instruments.into_iter().map(|i| ("i", i)).collect();
         let resp = self
@@ -734,6 +1121,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn ohlc(&self, instruments: Vec<&str>) -> Result<JsonValue> {
         let params: Vec<_> = instruments.into_iter().map(|i| ("i", i)).collect();
 
@@ -774,6 +1162,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn ltp(&self, instruments: Vec<&str>) -> Result<JsonValue> {
         let params: Vec<_> = instruments.into_iter().map(|i| ("i", i)).collect();
 
@@ -830,7 +1219,13 @@ impl KiteConnect {
 
     /// Get real-time quotes with typed response
     ///
-    /// Returns strongly typed quote data instead of JsonValue.
+    /// Returns strongly typed quote data instead of JsonValue. Each identifier
+    /// is validated locally before sending; malformed entries (missing `:`,
+    /// empty strings) produce a `KiteError::General` listing them.
+    ///
+    /// Quotes are returned in request order. Lists longer than
+    /// `KiteConnectConfig::quote_batch_size` (500 by default) are fetched in
+    /// sequential batches.
     ///
     /// # Arguments
     ///
@@ -858,22 +1253,107 @@ impl KiteConnect {
     /// # }
     /// ```
     pub async fn quote_typed(&self, instruments: Vec<&str>) -> KiteResult<Vec<Quote>> {
-        let params: Vec<_> = instruments.into_iter().map(|i| ("i", i)).collect();
+        let instruments: Vec<String> = instruments.into_iter().map(String::from).collect();
+        let mut by_id = self.quote_map_typed(&instruments).await?;
 
-        let resp = self
-            .send_request_with_rate_limiting_and_retry(KiteEndpoint::Quote, &[], Some(params), None)
-            .await?;
+        // Kite keys the response by identifier; return quotes in request order
+        Ok(instruments
+            .iter()
+            .filter_map(|id| by_id.remove(id))
+            .collect())
+    }
 
-        let json_response = self.raise_or_return_json_typed(resp).await?;
+    /// Get quotes aligned to the input order
+    ///
+    /// Returns one `(identifier, quote)` pair per requested instrument, in
+    /// the order given, with `None` where Kite omitted the instrument (e.g.
+    /// an expired contract). Duplicates are kept, so the result always lines
+    /// up index-for-index with `instruments`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// for (symbol, quote) in client.quote_ordered(vec!["NSE:INFY", "NSE:TCS"]).await? {
+    ///     match quote {
+    ///         Some(quote) => println!("{}: {}", symbol, quote.last_price),
+    ///         None => println!("{}: no quote", symbol),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quote_ordered(
+        &self,
+        instruments: Vec<&str>,
+    ) -> KiteResult<Vec<(String, Option<Quote>)>> {
+        let instruments: Vec<String> = instruments.into_iter().map(String::from).collect();
+        let by_id = self.quote_map_typed(&instruments).await?;
+
+        Ok(instruments
+            .into_iter()
+            .map(|id| {
+                let quote = by_id.get(&id).cloned();
+                (id, quote)
+            })
+            .collect())
+    }
 
-        // Extract the data field from response
-        let data = json_response["data"].clone();
-        self.parse_response(data)
+    /// Get quotes for instrument tokens, keyed by `instrument_token`
+    ///
+    /// Matches how the ticker identifies instruments, so quotes can be looked
+    /// up by the tokens already subscribed to without formatting
+    /// `EXCHANGE:SYMBOL` strings. Tokens Kite returns no quote for are absent
+    /// from the map; duplicates are requested once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let quotes = client.quote_by_token(&[408065, 884737]).await?;
+    /// if let Some(quote) = quotes.get(&408065) {
+    ///     println!("{}: {}", quote.trading_symbol, quote.last_price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quote_by_token(&self, tokens: &[u32]) -> KiteResult<HashMap<u32, Quote>> {
+        let mut instruments: Vec<String> = Vec::with_capacity(tokens.len());
+        for token in tokens {
+            let token = token.to_string();
+            if !instruments.contains(&token) {
+                instruments.push(token);
+            }
+        }
+        if instruments.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        Ok(self
+            .quote_map_typed(&instruments)
+            .await?
+            .into_values()
+            .map(|quote| (quote.instrument_token, quote))
+            .collect())
     }
 
     /// Get OHLC data with typed response
     ///
-    /// Returns strongly typed OHLC data instead of JsonValue.
+    /// Returns strongly typed OHLC quotes instead of JsonValue, keyed by
+    /// instrument identifier as requested (e.g. `"NSE:INFY"`). Instruments
+    /// Kite returns no data for are absent from the map. Lists longer than
+    /// `KiteConnectConfig::quote_batch_size` are fetched in sequential batches
+    /// and merged.
     ///
     /// # Arguments
     ///
@@ -881,7 +1361,7 @@ impl KiteConnect {
     ///
     /// # Returns
     ///
-    /// A `KiteResult<Vec<OHLC>>` containing typed OHLC data
+    /// A `KiteResult<HashMap<String, OHLCQuote>>` containing typed OHLC data
     ///
     /// # Example
     ///
@@ -894,30 +1374,31 @@ impl KiteConnect {
     ///
     /// let instruments = vec!["NSE:RELIANCE", "NSE:TCS"];
     /// let ohlc_data = client.ohlc_typed(instruments).await?;
-    /// for ohlc in ohlc_data {
-    ///     println!("Open: {}, High: {}, Low: {}, Close: {}",
-    ///         ohlc.open, ohlc.high, ohlc.low, ohlc.close);
+    /// for (symbol, quote) in &ohlc_data {
+    ///     println!("{}: Open: {}, High: {}, Low: {}, Close: {}",
+    ///         symbol, quote.ohlc.open, quote.ohlc.high, quote.ohlc.low, quote.ohlc.close);
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn ohlc_typed(&self, instruments: Vec<&str>) -> KiteResult<Vec<OHLC>> {
-        let params: Vec<_> = instruments.into_iter().map(|i| ("i", i)).collect();
-
-        let resp = self
-            .send_request_with_rate_limiting_and_retry(KiteEndpoint::OHLC, &[], Some(params), None)
-            .await?;
-
-        let json_response = self.raise_or_return_json_typed(resp).await?;
+    pub async fn ohlc_typed(
+        &self,
+        instruments: Vec<&str>,
+    ) -> KiteResult<HashMap<String, OHLCQuote>> {
+        validate_instrument_identifiers(&instruments)?;
 
-        // Extract the data field from response
-        let data = json_response["data"].clone();
-        self.parse_response(data)
+        let instruments: Vec<String> = instruments.into_iter().map(String::from).collect();
+        self.quote_endpoint_map(KiteEndpoint::OHLC, &instruments)
+            .await
     }
 
     /// Get Last Traded Price (LTP) with typed response
     ///
-    /// Returns strongly typed LTP data instead of JsonValue.
+    /// Returns strongly typed LTP data instead of JsonValue, keyed by
+    /// instrument identifier as requested (e.g. `"NSE:INFY"`). Instruments
+    /// Kite returns no data for are absent from the map. Lists longer than
+    /// `KiteConnectConfig::quote_batch_size` are fetched in sequential batches
+    /// and merged.
     ///
     /// # Arguments
     ///
@@ -925,7 +1406,7 @@ impl KiteConnect {
     ///
     /// # Returns
     ///
-    /// A `KiteResult<Vec<LTP>>` containing typed LTP data
+    /// A `KiteResult<HashMap<String, LTP>>` containing typed LTP data
     ///
     /// # Example
     ///
@@ -938,44 +1419,392 @@ impl KiteConnect {
     ///
     /// let instruments = vec!["NSE:RELIANCE", "NSE:TCS"];
     /// let ltp_data = client.ltp_typed(instruments).await?;
-    /// for ltp in ltp_data {
-    ///     println!("Token: {}, LTP: {}", ltp.instrument_token, ltp.last_price);
+    /// for (symbol, ltp) in &ltp_data {
+    ///     println!("{}: Token: {}, LTP: {}", symbol, ltp.instrument_token, ltp.last_price);
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn ltp_typed(&self, instruments: Vec<&str>) -> KiteResult<Vec<LTP>> {
-        let params: Vec<_> = instruments.into_iter().map(|i| ("i", i)).collect();
-
-        let resp = self
-            .send_request_with_rate_limiting_and_retry(KiteEndpoint::LTP, &[], Some(params), None)
-            .await?;
-
-        let json_response = self.raise_or_return_json_typed(resp).await?;
+    pub async fn ltp_typed(&self, instruments: Vec<&str>) -> KiteResult<HashMap<String, LTP>> {
+        validate_instrument_identifiers(&instruments)?;
 
-        // Extract the data field from response
-        let data = json_response["data"].clone();
-        self.parse_response(data)
+        let instruments: Vec<String> = instruments.into_iter().map(String::from).collect();
+        self.quote_endpoint_map(KiteEndpoint::LTP, &instruments)
+            .await
     }
 
-    /// Get historical data with typed response
+    /// Get the allowed trigger price range for instruments
     ///
-    /// Returns strongly typed historical data instead of JsonValue.
+    /// Returns the range within which a cover order's stop-loss trigger
+    /// must lie, keyed by instrument identifier (e.g. `"NSE:INFY"`).
     ///
     /// # Arguments
     ///
-    /// * `request` - A `HistoricalDataRequest` containing all the parameters for the request
-    ///
-    /// # Returns
-    ///
-    /// A `KiteResult<HistoricalData>` containing typed historical data
+    /// * `transaction_type` - Side of the order the trigger protects
+    /// * `instruments` - Instrument identifiers (`EXCHANGE:SYMBOL` or tokens)
     ///
     /// # Example
     ///
     /// ```rust,no_run
     /// use kiteconnect_async_wasm::connect::KiteConnect;
-    /// use kiteconnect_async_wasm::models::market_data::HistoricalDataRequest;
-    /// use kiteconnect_async_wasm::models::common::Interval;
+    /// use kiteconnect_async_wasm::models::common::TransactionType;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let ranges = client
+    ///     .trigger_range_typed(TransactionType::BUY, &["NSE:INFY"])
+    ///     .await?;
+    /// if let Some(range) = ranges.get("NSE:INFY") {
+    ///     println!("Trigger between {} and {}", range.lower, range.upper);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn trigger_range_typed(
+        &self,
+        transaction_type: TransactionType,
+        instruments: &[&str],
+    ) -> KiteResult<HashMap<String, TriggerRange>> {
+        validate_instrument_identifiers(instruments)?;
+
+        let side = transaction_type.to_string().to_lowercase();
+        let params: Vec<_> = instruments.iter().map(|i| ("i", *i)).collect();
+
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::TriggerRange,
+                &[&side],
+                Some(params),
+                None,
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        self.parse_response(json_response["data"].clone())
+    }
+
+    /// Get the market status of an exchange
+    ///
+    /// Derived locally from the exchange's regular session times (see
+    /// [`MarketStatus::from_schedule`]) and cached per exchange for
+    /// [`MARKET_STATUS_TTL`]. No request is made, so the check costs a mutex
+    /// lock and, at most once a minute, a clock read. Kite Connect does not
+    /// publish market status or holidays, so exchange holidays are only known
+    /// once seeded with [`set_market_holidays`](Self::set_market_holidays);
+    /// on those dates (IST) the status is [`MarketState::Holiday`].
+    pub fn market_status(&self, exchange: Exchange) -> MarketStatus {
+        let now = SystemTime::now();
+        let Ok(mut cache) = self.market_status_cache.lock() else {
+            return self.scheduled_status(exchange, now);
+        };
+
+        if let Some((status, at)) = cache.get(&exchange) {
+            if now
+                .duration_since(*at)
+                .is_ok_and(|age| age < MARKET_STATUS_TTL)
+            {
+                return status.clone();
+            }
+        }
+
+        let status = self.scheduled_status(exchange, now);
+        cache.insert(exchange, (status.clone(), now));
+        status
+    }
+
+    /// Get the market status of every exchange
+    ///
+    /// Same as calling [`market_status`](Self::market_status) for each of
+    /// [`Exchange::all`], in that order.
+    pub fn market_statuses(&self) -> Vec<MarketStatus> {
+        Exchange::all()
+            .into_iter()
+            .map(|exchange| self.market_status(exchange))
+            .collect()
+    }
+
+    /// Override the cached market status of an exchange
+    ///
+    /// The override is a cache entry like any other: it expires after
+    /// [`MARKET_STATUS_TTL`], after which [`market_status`](Self::market_status)
+    /// derives the status from the schedule again. For exchange holidays use
+    /// [`set_market_holidays`](Self::set_market_holidays), which lasts until
+    /// replaced.
+    pub fn set_market_status(&self, status: MarketStatus) {
+        if let Ok(mut cache) = self.market_status_cache.lock() {
+            cache.insert(status.exchange, (status, SystemTime::now()));
+        }
+    }
+
+    /// Set the holiday calendar of an exchange
+    ///
+    /// Replaces any dates set earlier for `exchange`. Dates are trading days
+    /// in IST; Zerodha publishes the calendar on its website each year. The
+    /// cached status for the exchange is dropped so the next
+    /// [`market_status`](Self::market_status) call reflects the calendar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::Exchange;
+    /// use chrono::NaiveDate;
+    ///
+    /// let client = KiteConnect::new("api_key", "access_token");
+    /// let republic_day = NaiveDate::from_ymd_opt(2025, 1, 26).unwrap();
+    ///
+    /// client.set_market_holidays(Exchange::NSE, [republic_day]);
+    /// assert!(client.is_market_holiday(Exchange::NSE, republic_day));
+    /// assert_eq!(client.market_holidays(Exchange::NSE), vec![republic_day]);
+    /// ```
+    pub fn set_market_holidays(
+        &self,
+        exchange: Exchange,
+        holidays: impl IntoIterator<Item = NaiveDate>,
+    ) {
+        if let Ok(mut calendar) = self.market_holidays.lock() {
+            calendar.insert(exchange, holidays.into_iter().collect());
+        }
+        if let Ok(mut cache) = self.market_status_cache.lock() {
+            cache.remove(&exchange);
+        }
+    }
+
+    /// Get the holidays set for an exchange, in date order
+    pub fn market_holidays(&self, exchange: Exchange) -> Vec<NaiveDate> {
+        self.market_holidays
+            .lock()
+            .ok()
+            .and_then(|calendar| {
+                calendar
+                    .get(&exchange)
+                    .map(|dates| dates.iter().copied().collect())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Check if `date` is a holiday set for an exchange
+    pub fn is_market_holiday(&self, exchange: Exchange, date: NaiveDate) -> bool {
+        self.market_holidays.lock().is_ok_and(|calendar| {
+            calendar
+                .get(&exchange)
+                .is_some_and(|dates| dates.contains(&date))
+        })
+    }
+
+    /// Status from the regular schedule, marked as a holiday when seeded
+    fn scheduled_status(&self, exchange: Exchange, now: SystemTime) -> MarketStatus {
+        let mut status = MarketStatus::from_schedule(exchange, now.into());
+        let today = DateTime::<Utc>::from(now).with_timezone(&IST).date_naive();
+        if self.is_market_holiday(exchange, today) {
+            status.status = MarketState::Holiday;
+        }
+        status
+    }
+
+    /// Tag quotes fetched while their exchange is closed, if enabled
+    fn tag_after_hours<'a>(&self, quotes: impl IntoIterator<Item = &'a mut Quote>) {
+        if !self.tag_after_hours_quotes {
+            return;
+        }
+        for quote in quotes {
+            let status = self.market_status(quote.exchange);
+            quote.is_after_hours = !status.is_unknown() && !status.is_trading_allowed();
+        }
+    }
+
+    /// Poll full quotes for a set of instruments at a fixed interval
+    ///
+    /// This is the REST analog of the WebSocket ticker for full-quote data. The
+    /// first batch is fetched immediately, then one batch per `interval`. Each
+    /// item maps the instrument identifier (e.g. `"NSE:INFY"`) to its quote.
+    ///
+    /// When rate limiting is enabled, an `interval` shorter than the quote
+    /// endpoint's minimum delay is clamped up to that delay. Polling stops
+    /// when the stream is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `instruments` - List of instrument identifiers
+    /// * `interval` - Delay between consecutive fetches
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use futures_util::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let stream = client.poll_quotes(vec!["NSE:INFY".to_string()], Duration::from_secs(2));
+    /// futures_util::pin_mut!(stream);
+    ///
+    /// while let Some(batch) = stream.next().await {
+    ///     for (symbol, quote) in batch? {
+    ///         println!("{}: {}", symbol, quote.last_price);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn poll_quotes(
+        &self,
+        instruments: Vec<String>,
+        interval: Duration,
+    ) -> impl Stream<Item = KiteResult<HashMap<String, Quote>>> + 'static {
+        let interval = if self.is_rate_limiting_enabled() {
+            interval.max(KiteEndpoint::Quote.rate_limit_category().min_delay())
+        } else {
+            interval
+        };
+
+        let client = self.clone();
+        futures_util::stream::unfold(
+            (client, instruments, true),
+            move |(client, instruments, first)| async move {
+                if !first {
+                    tokio::time::sleep(interval).await;
+                }
+                let batch = client.quote_map_typed(&instruments).await;
+                Some((batch, (client, instruments, false)))
+            },
+        )
+    }
+
+    /// Fetch full quotes keyed by instrument identifier
+    pub(crate) async fn quote_map_typed(
+        &self,
+        instruments: &[String],
+    ) -> KiteResult<HashMap<String, Quote>> {
+        validate_instrument_identifiers(instruments)?;
+        let mut quotes: HashMap<String, Quote> = self
+            .quote_endpoint_map(KiteEndpoint::Quote, instruments)
+            .await?;
+        self.tag_after_hours(quotes.values_mut());
+        Ok(quotes)
+    }
+
+    /// Fetch quote, OHLC and LTP for the same instruments concurrently
+    ///
+    /// This issues three underlying calls (`/quote`, `/quote/ohlc` and
+    /// `/quote/ltp`), all of which count against the quote rate limit. The
+    /// identifiers are validated once and duplicates are dropped before any
+    /// request is sent, so each instrument is only requested once per call.
+    /// With rate limiting enabled the limiter still spaces the three calls
+    /// apart; the bundle fails if any one of them fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `instruments` - List of instrument identifiers
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let bundle = client.quote_bundle(vec!["NSE:INFY", "NSE:TCS"]).await?;
+    /// if let Some(ohlc) = bundle.ohlc.get("NSE:INFY") {
+    ///     println!("INFY open: {}", ohlc.ohlc.open);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quote_bundle(&self, instruments: Vec<&str>) -> KiteResult<QuoteBundle> {
+        validate_instrument_identifiers(&instruments)?;
+
+        let mut seen = BTreeSet::new();
+        let instruments: Vec<String> = instruments
+            .into_iter()
+            .filter(|i| seen.insert(*i))
+            .map(String::from)
+            .collect();
+
+        let (mut quotes, ohlc, ltp) = futures_util::future::try_join3(
+            self.quote_endpoint_map(KiteEndpoint::Quote, &instruments),
+            self.quote_endpoint_map(KiteEndpoint::OHLC, &instruments),
+            self.quote_endpoint_map(KiteEndpoint::LTP, &instruments),
+        )
+        .await?;
+        self.tag_after_hours(quotes.values_mut());
+
+        Ok(QuoteBundle { quotes, ohlc, ltp })
+    }
+
+    /// Fetch a quote endpoint's `data` map keyed by instrument identifier
+    ///
+    /// Requests are split into sequential batches of `quote_batch_size`
+    /// instruments and the results merged. An out-of-range batch size fails
+    /// with `KiteError::InputException` before anything is sent.
+    async fn quote_endpoint_map<T: DeserializeOwned>(
+        &self,
+        endpoint: KiteEndpoint,
+        instruments: &[String],
+    ) -> KiteResult<HashMap<String, T>> {
+        check_quote_batch_size(self.quote_batch_size)?;
+
+        let mut merged = HashMap::with_capacity(instruments.len());
+
+        for batch in instruments.chunks(self.quote_batch_size) {
+            let params: Vec<_> = batch.iter().map(|i| ("i", i.as_str())).collect();
+
+            let resp = self
+                .send_request_with_rate_limiting_and_retry(
+                    endpoint.clone(),
+                    &[],
+                    Some(params),
+                    None,
+                )
+                .await?;
+
+            let json_response = self.raise_or_return_json_typed(resp).await?;
+
+            // Extract the data field from response
+            let data = json_response["data"].clone();
+            let quotes: HashMap<String, T> = self.parse_response(data)?;
+            merged.extend(quotes);
+        }
+
+        Ok(merged)
+    }
+
+    /// Get historical data with typed response
+    ///
+    /// Returns strongly typed historical data instead of JsonValue. The call
+    /// goes through [`KiteEndpoint::HistoricalData`], so it is paced by the
+    /// historical rate limit (3 requests per second).
+    ///
+    /// Ranges longer than the interval allows (see
+    /// [`Interval::max_days`](crate::models::common::Interval::max_days))
+    /// are split into sequential sub-requests whose candles are concatenated
+    /// in date order, with duplicates at chunk boundaries removed. A range
+    /// needing more than `KiteConnectConfig::historical_max_chunks`
+    /// sub-requests fails with `KiteError::InputException` before anything
+    /// is sent. A zero instrument token or a `from` after `to` fails with
+    /// `KiteError::General` (see [`HistoricalDataRequest::validate`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `HistoricalDataRequest` containing all the parameters for the request
+    ///
+    /// # Returns
+    ///
+    /// A `KiteResult<HistoricalData>` containing typed historical data
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::market_data::HistoricalDataRequest;
+    /// use kiteconnect_async_wasm::models::common::Interval;
     /// use chrono::NaiveDateTime;
     ///
     /// # #[tokio::main]
@@ -1001,6 +1830,49 @@ impl KiteConnect {
     pub async fn historical_data_typed(
         &self,
         request: HistoricalDataRequest,
+    ) -> KiteResult<HistoricalData> {
+        check_historical_max_chunks(self.historical_max_chunks)?;
+        request.validate_bounds()?;
+
+        let chunk_requests = request.split_into_valid_requests();
+        if chunk_requests.len() == 1 {
+            return self.historical_data_single(request).await;
+        }
+
+        if chunk_requests.len() > self.historical_max_chunks {
+            return Err(KiteError::input_exception(format!(
+                "Historical range of {} days at {} interval needs {} requests, more than the limit of {} (KiteConnectConfig::historical_max_chunks)",
+                request.days_span(),
+                request.interval,
+                chunk_requests.len(),
+                self.historical_max_chunks
+            )));
+        }
+
+        // Sequential so the historical rate limiter paces the sub-requests
+        let mut candles = Vec::new();
+        for chunk_request in chunk_requests {
+            candles.extend(self.historical_data_single(chunk_request).await?.candles);
+        }
+
+        // Adjacent chunks can both include the boundary candle
+        candles.sort_by_key(|c| c.date);
+        candles.dedup_by_key(|c| c.date);
+
+        let metadata = HistoricalMetadata {
+            instrument_token: request.instrument_token,
+            symbol: format!("Token-{}", request.instrument_token),
+            interval: request.interval,
+            count: candles.len(),
+        };
+
+        Ok(HistoricalData { candles, metadata })
+    }
+
+    /// Fetch one historical data request that fits within the interval's limit
+    async fn historical_data_single(
+        &self,
+        request: HistoricalDataRequest,
     ) -> KiteResult<HistoricalData> {
         // Validate date range against API limits
         if let Err(validation_error) = request.validate_date_range() {
@@ -1010,8 +1882,8 @@ impl KiteConnect {
         }
 
         let mut params = Vec::new();
-        params.push(("from", request.from.format("%Y-%m-%d %H:%M:%S").to_string()));
-        params.push(("to", request.to.format("%Y-%m-%d %H:%M:%S").to_string()));
+        params.push(("from", request.from_param()));
+        params.push(("to", request.to_param()));
 
         if let Some(continuous) = request.continuous {
             params.push(("continuous", if continuous { "1" } else { "0" }.to_string()));
@@ -1044,16 +1916,27 @@ impl KiteConnect {
         // Parse the candles array directly
         let candles: Vec<crate::models::market_data::Candle> = if data["candles"].is_array() {
             // If data has a "candles" field
-            serde_json::from_value(data["candles"].clone()).map_err(KiteError::Json)?
+            KiteError::from_json_value(&data["candles"])?
         } else if data.is_array() {
             // If data is directly an array of candles
-            serde_json::from_value(data).map_err(KiteError::Json)?
+            KiteError::from_json_value(&data)?
         } else {
             return Err(KiteError::general(
                 "Invalid historical data format".to_string(),
             ));
         };
 
+        #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+        let candles = {
+            let mut candles = candles;
+            if request.adjust_for_corporate_actions {
+                if let Some(actions) = &request.corporate_actions {
+                    actions.apply(&mut candles)?;
+                }
+            }
+            candles
+        };
+
         // Create metadata from request parameters
         let metadata = crate::models::market_data::HistoricalMetadata {
             instrument_token: request.instrument_token,
@@ -1130,9 +2013,8 @@ impl KiteConnect {
     ///
     /// # Performance Notes
     ///
-    /// - Results are automatically cached when `exchange` is `None`
+    /// - Results are cached per exchange, with the full list (`None`) in its own slot
     /// - Cache duration is 1 hour by default
-    /// - Exchange-specific queries are not cached
     /// - Large instrument lists are processed efficiently
     pub async fn instruments_typed(
         &self,
@@ -1140,45 +2022,245 @@ impl KiteConnect {
     ) -> KiteResult<Vec<crate::models::market_data::Instrument>> {
         // Convert Exchange enum to string for the underlying API call
         let exchange_str = exchange.as_ref().map(|e| e.to_string());
-        let exchange_str_ref = exchange_str.as_ref().map(|s| s.as_str());
+        let exchange_str_ref = exchange_str.as_deref();
 
         // Get the JSON response using existing method
         let json_response = self
-            .instruments(exchange_str_ref)
+            .instruments_json(exchange_str_ref)
             .await
             .map_err(|e| KiteError::general(format!("Failed to get instruments: {}", e)))?;
 
-        // Parse the JSON array into typed instruments
-        if let Some(instruments_array) = json_response.as_array() {
-            let mut instruments = Vec::new();
+        let report = parse_instrument_rows(&json_response)?;
+        self.instruments_skipped_rows
+            .store(report.skipped as u64, Ordering::Relaxed);
+        Ok(report.parsed)
+    }
 
-            for instrument_json in instruments_array {
-                // Convert JSON object to Instrument struct
-                match serde_json::from_value::<crate::models::market_data::Instrument>(
-                    instrument_json.clone(),
-                ) {
-                    Ok(instrument) => instruments.push(instrument),
-                    Err(e) => {
-                        // Log the error but continue processing other instruments
-                        #[cfg(feature = "debug")]
-                        log::warn!(
-                            "Failed to parse instrument: {:?} - Error: {}",
-                            instrument_json,
-                            e
-                        );
+    /// Get instruments along with the rows that failed to parse
+    ///
+    /// Same download and caching as [`instruments_typed`](Self::instruments_typed),
+    /// which drops malformed rows (too few columns, unparseable numbers or
+    /// dates, unknown enum values) and only counts them. This returns each
+    /// dropped row's CSV line number and error as well, for checking the dump
+    /// when counts look wrong.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::Exchange;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let report = client.instruments_with_report(Some(Exchange::NSE)).await?;
+    /// println!("{} instruments, {} rows skipped", report.parsed.len(), report.skipped);
+    /// for error in report.errors.iter().take(10) {
+    ///     eprintln!("{}", error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn instruments_with_report(
+        &self,
+        exchange: Option<Exchange>,
+    ) -> KiteResult<InstrumentParseReport> {
+        let exchange_str = exchange.as_ref().map(|e| e.to_string());
 
-                        // For now, continue with other instruments rather than failing completely
-                        continue;
+        let json_response = self
+            .instruments_json(exchange_str.as_deref())
+            .await
+            .map_err(|e| KiteError::general(format!("Failed to get instruments: {}", e)))?;
+
+        let report = parse_instrument_rows(&json_response)?;
+        self.instruments_skipped_rows
+            .store(report.skipped as u64, Ordering::Relaxed);
+        Ok(report)
+    }
+
+    /// Get instruments listed on any of several exchanges
+    ///
+    /// Filters the (cached) full instruments list locally, so asking for e.g.
+    /// NSE and NFO costs one download instead of one per exchange. An empty
+    /// `exchanges` slice returns an empty list without a request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::Exchange;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let instruments = client
+    ///     .instruments_by_exchanges(&[Exchange::NSE, Exchange::NFO])
+    ///     .await?;
+    /// println!("NSE + NFO instruments: {}", instruments.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn instruments_by_exchanges(
+        &self,
+        exchanges: &[Exchange],
+    ) -> KiteResult<Vec<crate::models::market_data::Instrument>> {
+        if exchanges.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let instruments = self.instruments_typed(None).await?;
+        Ok(instruments
+            .into_iter()
+            .filter(|inst| exchanges.contains(&inst.exchange))
+            .collect())
+    }
+
+    /// Get all tradeable equities listed on an exchange
+    ///
+    /// Filters the (cached) full instruments list down to instruments of type
+    /// `EQ` on the given exchange. Indices, which Kite also reports as `EQ`
+    /// under the `INDICES` segment, are excluded since they cannot be traded.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange` - Exchange to list equities for, typically `Exchange::NSE` or `Exchange::BSE`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::Exchange;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let nse_equities = client.equities(Exchange::NSE).await?;
+    /// println!("NSE equities: {}", nse_equities.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn equities(
+        &self,
+        exchange: Exchange,
+    ) -> KiteResult<Vec<crate::models::market_data::Instrument>> {
+        // Use the full list so repeated calls are served from the cache
+        let instruments = self.instruments_typed(None).await?;
+
+        Ok(instruments
+            .into_iter()
+            .filter(|inst| {
+                inst.exchange == exchange && inst.is_equity() && inst.segment != Segment::INDICES
+            })
+            .collect())
+    }
+
+    /// Get the distinct expiries available for an underlying
+    ///
+    /// Filters the (cached) full instruments list by exchange, underlying name
+    /// and instrument type, and returns the sorted, de-duplicated expiry dates.
+    /// Useful for populating expiry pickers.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange` - Derivatives exchange, e.g. `Exchange::NFO`
+    /// * `underlying` - Underlying name as reported in the `name` column (case-insensitive), e.g. `"NIFTY"`
+    /// * `instrument_type` - `FUT`, `CE` or `PE`
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::{Exchange, InstrumentType};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let expiries = client.expiries(Exchange::NFO, "NIFTY", InstrumentType::CE).await?;
+    /// println!("Nearest NIFTY option expiry: {:?}", expiries.first());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn expiries(
+        &self,
+        exchange: Exchange,
+        underlying: &str,
+        instrument_type: InstrumentType,
+    ) -> KiteResult<Vec<NaiveDate>> {
+        let instruments = self.instruments_typed(None).await?;
+
+        let expiries: BTreeSet<NaiveDate> = instruments
+            .iter()
+            .filter(|inst| {
+                inst.exchange == exchange
+                    && inst.instrument_type == instrument_type
+                    && inst.name.eq_ignore_ascii_case(underlying)
+            })
+            .filter_map(|inst| inst.expiry)
+            .collect();
+
+        Ok(expiries.into_iter().collect())
+    }
+
+    /// Resolve an instrument token to its `EXCHANGE:TRADINGSYMBOL` string
+    ///
+    /// Useful for displaying ticker data, which only carries tokens. The
+    /// lookup table is built lazily from the full instruments list on first
+    /// use and cached alongside it, expiring with the instruments cache. When
+    /// caching is disabled the list is fetched on every call.
+    ///
+    /// Returns `Ok(None)` if no instrument has the given token.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// if let Some(symbol) = client.symbol_for_token(408065).await? {
+    ///     println!("408065 is {}", symbol); // NSE:INFY
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn symbol_for_token(&self, token: u32) -> KiteResult<Option<String>> {
+        let cached = self
+            .response_cache
+            .lock()
+            .ok()
+            .and_then(|guard| guard.as_ref().and_then(|cache| cache.get_token_symbols()));
+
+        self.metrics.record_cache_lookup(cached.is_some());
+        let symbols = match cached {
+            Some(symbols) => symbols,
+            None => {
+                let symbols: HashMap<u32, String> = self
+                    .instruments_typed(None)
+                    .await?
+                    .into_iter()
+                    .filter_map(|inst| {
+                        let token = inst.instrument_token.parse().ok()?;
+                        Some((token, format!("{}:{}", inst.exchange, inst.trading_symbol)))
+                    })
+                    .collect();
+                let symbols = Arc::new(symbols);
+
+                if let Ok(mut guard) = self.response_cache.lock() {
+                    if let Some(cache) = guard.as_mut() {
+                        cache.set_token_symbols(symbols.clone());
                     }
                 }
+                symbols
             }
+        };
 
-            Ok(instruments)
-        } else {
-            Err(KiteError::general(
-                "Invalid instruments response format".to_string(),
-            ))
-        }
+        Ok(symbols.get(&token).cloned())
     }
 
     /// Debug version of instruments_typed that shows JSON before conversion
@@ -1188,13 +2270,13 @@ impl KiteConnect {
     ) -> KiteResult<Vec<crate::models::market_data::Instrument>> {
         // Convert Exchange enum to string for the underlying API call
         let exchange_str = exchange.as_ref().map(|e| e.to_string());
-        let exchange_str_ref = exchange_str.as_ref().map(|s| s.as_str());
+        let exchange_str_ref = exchange_str.as_deref();
 
         println!("🔍 Typed Debug: Getting JSON response first...");
 
         // Get the JSON response using existing method
         let json_response = self
-            .instruments(exchange_str_ref)
+            .instruments_json(exchange_str_ref)
             .await
             .map_err(|e| KiteError::general(format!("Failed to get instruments: {}", e)))?;
 
@@ -1347,7 +2429,7 @@ impl KiteConnect {
     pub async fn mf_instruments_typed(&self) -> KiteResult<Vec<MFInstrument>> {
         // Get the JSON response using existing method
         let json_response = self
-            .mf_instruments()
+            .mf_instruments_json()
             .await
             .map_err(|e| KiteError::general(format!("Failed to get MF instruments: {}", e)))?;
 
@@ -1359,6 +2441,7 @@ impl KiteConnect {
                 // Convert JSON object to MFInstrument struct
                 match serde_json::from_value::<MFInstrument>(instrument_json.clone()) {
                     Ok(instrument) => instruments.push(instrument),
+                    #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
                     Err(e) => {
                         // Log the error but continue processing other instruments
                         #[cfg(feature = "debug")]
@@ -1485,7 +2568,7 @@ impl KiteConnect {
                 chunk_request.days_span()
             );
 
-            match self.historical_data_typed(chunk_request.clone()).await {
+            match self.historical_data_single(chunk_request.clone()).await {
                 Ok(chunk_data) => {
                     if chunk_data.candles.is_empty() {
                         #[cfg(feature = "debug")]
@@ -1538,7 +2621,7 @@ impl KiteConnect {
         }
 
         // Sort candles by date to ensure chronological order (oldest → newest)
-        all_candles.sort_by(|a, b| a.date.cmp(&b.date));
+        all_candles.sort_by_key(|c| c.date);
 
         #[cfg(feature = "debug")]
         log::info!(
@@ -1564,6 +2647,181 @@ impl KiteConnect {
         })
     }
 
+    /// Fetch a large historical range with several chunks in flight at once
+    ///
+    /// Like [`historical_data_chunked`](Self::historical_data_chunked), but up to
+    /// `concurrency` chunk requests run at the same time (clamped to
+    /// `1..=MAX_HISTORICAL_CONCURRENCY`), each still going through the rate
+    /// limiter. Candles are reassembled in date order regardless of which chunk
+    /// completes first. Useful for long ranges of daily data spanning many chunks.
+    ///
+    /// Since all chunks are requested up front there is no early termination on
+    /// an empty chunk; empty chunks simply contribute no candles.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The historical data request (can exceed API limits)
+    /// * `concurrency` - Maximum number of chunk requests in flight
+    /// * `continue_on_error` - Whether to keep the successful chunks if some fail
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failed chunk's error unless `continue_on_error` is
+    /// set, and `KiteError::General` if every chunk fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::market_data::HistoricalDataRequest;
+    /// use kiteconnect_async_wasm::models::common::Interval;
+    /// use chrono::NaiveDateTime;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let request = HistoricalDataRequest::new(
+    ///     738561,
+    ///     NaiveDateTime::parse_from_str("2023-01-01 09:15:00", "%Y-%m-%d %H:%M:%S")?,
+    ///     NaiveDateTime::parse_from_str("2023-12-31 15:30:00", "%Y-%m-%d %H:%M:%S")?,
+    ///     Interval::FifteenMinute,
+    /// );
+    ///
+    /// let data = client.historical_data_chunked_concurrent(request, 3, false).await?;
+    /// println!("Retrieved {} candles", data.candles.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn historical_data_chunked_concurrent(
+        &self,
+        request: HistoricalDataRequest,
+        concurrency: usize,
+        continue_on_error: bool,
+    ) -> KiteResult<HistoricalData> {
+        let chunk_requests = request.split_into_valid_requests_reverse();
+
+        if chunk_requests.len() == 1 {
+            return self.historical_data_typed(request).await;
+        }
+
+        let concurrency = concurrency.clamp(1, MAX_HISTORICAL_CONCURRENCY);
+
+        #[cfg(feature = "debug")]
+        log::info!(
+            "Fetching {} historical data chunks with concurrency {}",
+            chunk_requests.len(),
+            concurrency
+        );
+
+        let results: Vec<KiteResult<HistoricalData>> =
+            futures_util::stream::iter(chunk_requests.iter().cloned())
+                .map(|chunk_request| self.historical_data_single(chunk_request))
+                .buffer_unordered(concurrency)
+                .collect()
+                .await;
+
+        let mut all_candles = Vec::new();
+        let mut failed_chunks = 0;
+
+        for result in results {
+            match result {
+                Ok(chunk_data) => all_candles.extend(chunk_data.candles),
+                Err(e) => {
+                    failed_chunks += 1;
+
+                    #[cfg(feature = "debug")]
+                    log::warn!("Historical data chunk failed: {:?}", e);
+
+                    if !continue_on_error {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+
+        if all_candles.is_empty() && failed_chunks > 0 {
+            return Err(KiteError::general(format!(
+                "All {} chunks failed to retrieve data",
+                failed_chunks
+            )));
+        }
+
+        // Chunks complete in any order; restore chronological order (oldest → newest)
+        all_candles.sort_by_key(|c| c.date);
+
+        let metadata = HistoricalMetadata {
+            instrument_token: request.instrument_token,
+            symbol: format!("Token-{}", request.instrument_token),
+            interval: request.interval,
+            count: all_candles.len(),
+        };
+
+        Ok(HistoricalData {
+            candles: all_candles,
+            metadata,
+        })
+    }
+
+    /// Fetch historical data for many instruments concurrently
+    ///
+    /// Runs up to `concurrency` requests at once (clamped to
+    /// `1..=MAX_HISTORICAL_CONCURRENCY`), each through
+    /// [`historical_data_typed`](Self::historical_data_typed) and therefore
+    /// the historical rate limiter and automatic chunking. Every request gets
+    /// its own result, paired with its instrument token and returned in input
+    /// order, so one failing instrument doesn't abort the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - One request per instrument
+    /// * `concurrency` - Maximum number of requests in flight
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::market_data::HistoricalDataRequest;
+    /// use kiteconnect_async_wasm::models::common::Interval;
+    /// use chrono::NaiveDateTime;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let from = NaiveDateTime::parse_from_str("2023-01-01 09:15:00", "%Y-%m-%d %H:%M:%S")?;
+    /// let to = NaiveDateTime::parse_from_str("2023-12-31 15:30:00", "%Y-%m-%d %H:%M:%S")?;
+    /// let requests = [738561, 408065, 341249]
+    ///     .into_iter()
+    ///     .map(|token| HistoricalDataRequest::new(token, from, to, Interval::Day))
+    ///     .collect();
+    ///
+    /// for (token, result) in client.historical_data_batch(requests, 3).await {
+    ///     match result {
+    ///         Ok(data) => println!("{}: {} candles", token, data.candles.len()),
+    ///         Err(e) => eprintln!("{}: {}", token, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn historical_data_batch(
+        &self,
+        requests: Vec<HistoricalDataRequest>,
+        concurrency: usize,
+    ) -> Vec<(u32, KiteResult<HistoricalData>)> {
+        let concurrency = concurrency.clamp(1, MAX_HISTORICAL_CONCURRENCY);
+
+        futures_util::stream::iter(requests)
+            .map(|request| async move {
+                let token = request.instrument_token;
+                (token, self.historical_data_typed(request).await)
+            })
+            .buffered(concurrency)
+            .collect()
+            .await
+    }
+
     /// Simple instruments method for debugging - bypasses rate limiting and caching
     pub async fn instruments_simple(&self, exchange: Option<&str>) -> Result<JsonValue> {
         let path = if let Some(exchange) = exchange {
@@ -1572,7 +2830,7 @@ impl KiteConnect {
             "/instruments".to_string()
         };
 
-        let url = self.build_url(&path, None);
+        let url = self.build_url(&path, None)?;
 
         let resp = self.send_request(url, "GET", None).await?;
 
@@ -1612,11 +2870,12 @@ impl KiteConnect {
     pub async fn instruments_with_gzip(&self, exchange: Option<&str>) -> Result<JsonValue> {
         // Check cache first if enabled
         if let Some(ref cache_config) = self.cache_config {
-            if cache_config.enable_instruments_cache && exchange.is_none() {
-                // Only cache the full instruments list, not exchange-specific ones
+            if cache_config.enable_instruments_cache {
                 if let Ok(cache_guard) = self.response_cache.lock() {
                     if let Some(ref cache) = *cache_guard {
-                        if let Some(cached_data) = cache.get_instruments() {
+                        let cached = cache.get_instruments(exchange);
+                        self.metrics.record_cache_lookup(cached.is_some());
+                        if let Some(cached_data) = cached {
                             #[cfg(feature = "debug")]
                             log::debug!("Returning cached instruments data");
                             return Ok(cached_data);
@@ -1649,72 +2908,15 @@ impl KiteConnect {
         #[cfg(feature = "debug")]
         log::debug!("Received response with status: {}", resp.status());
 
-        // Check if response is gzipped
-        let content_encoding = resp
-            .headers()
-            .get("content-encoding")
-            .and_then(|v| v.to_str().ok())
-            .unwrap_or("")
-            .to_string();
-
-        #[cfg(feature = "debug")]
-        log::debug!("Content-Encoding: {}", content_encoding);
-
-        // Decompress if gzipped
-        let body_text = if content_encoding.contains("gzip") {
-            #[cfg(feature = "debug")]
-            log::debug!("Decompressing gzipped response");
-
-            let body_bytes = resp.bytes().await?;
-            use std::io::Read;
-            let mut decoder = flate2::read::GzDecoder::new(&body_bytes[..]);
-            let mut decompressed = String::new();
-            decoder.read_to_string(&mut decompressed)?;
-            decompressed
-        } else {
-            resp.text().await?
-        };
-
-        #[cfg(feature = "debug")]
-        log::debug!(
-            "Decompressed body length: {} bytes, first 200 chars: {}",
-            body_text.len(),
-            &body_text.chars().take(200).collect::<String>()
-        );
-
-        // Parse CSV response
-        let mut rdr = csv::ReaderBuilder::new().from_reader(body_text.as_bytes());
-        let mut result = Vec::new();
-
-        let headers = rdr.headers()?.clone();
-        #[cfg(feature = "debug")]
-        log::debug!("CSV headers: {:?}", headers);
-
-        let mut _record_count = 0;
-        for record in rdr.records() {
-            let record = record?;
-            let mut obj = serde_json::Map::new();
-
-            for (i, field) in record.iter().enumerate() {
-                if let Some(header) = headers.get(i) {
-                    obj.insert(header.to_string(), JsonValue::String(field.to_string()));
-                }
-            }
-            result.push(JsonValue::Object(obj));
-            _record_count += 1;
-        }
-
-        #[cfg(feature = "debug")]
-        log::debug!("Parsed {} records from CSV", _record_count);
-
-        let result_json = JsonValue::Array(result);
+        // Decompress if gzipped and parse the CSV as it arrives
+        let result_json = parse_csv_response(resp).await?;
 
-        // Cache the result if enabled and it's the full instruments list
+        // Cache the result per exchange filter if enabled
         if let Some(ref cache_config) = self.cache_config {
-            if cache_config.enable_instruments_cache && exchange.is_none() {
+            if cache_config.enable_instruments_cache {
                 if let Ok(mut cache_guard) = self.response_cache.lock() {
                     if let Some(ref mut cache) = *cache_guard {
-                        cache.set_instruments(result_json.clone());
+                        cache.set_instruments(exchange, result_json.clone());
                     }
                 }
             }
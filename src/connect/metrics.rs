@@ -0,0 +1,125 @@
+//! # Client Metrics
+//!
+//! Counters describing how a [`KiteConnect`](super::KiteConnect) client has
+//! behaved: requests sent, retries, rate limit waits, cache effectiveness and
+//! errors by kind. Read them with
+//! [`KiteConnect::metrics_snapshot`](super::KiteConnect::metrics_snapshot),
+//! e.g. to feed a dashboard.
+//!
+//! Counters are shared between clones of a client and only ever grow.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::models::common::KiteError;
+
+/// Point-in-time copy of a client's counters
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientMetrics {
+    /// HTTP requests sent, including retries
+    pub total_requests: u64,
+    /// Requests re-sent after a retryable failure
+    pub retries: u64,
+    /// Requests delayed by the client-side rate limiter
+    pub rate_limit_waits: u64,
+    /// Lookups answered from the response cache
+    pub cache_hits: u64,
+    /// Lookups not answered from the in-memory cache
+    pub cache_misses: u64,
+    /// Failed requests and responses keyed by [`KiteError::kind`]
+    pub errors_by_type: HashMap<String, u64>,
+}
+
+impl ClientMetrics {
+    /// Total number of recorded errors
+    pub fn total_errors(&self) -> u64 {
+        self.errors_by_type.values().sum()
+    }
+
+    /// Share of cache lookups that were hits, or `None` before any lookup
+    pub fn cache_hit_ratio(&self) -> Option<f64> {
+        let lookups = self.cache_hits + self.cache_misses;
+        (lookups > 0).then(|| self.cache_hits as f64 / lookups as f64)
+    }
+}
+
+/// Live counters behind [`ClientMetrics`]
+#[derive(Debug, Default)]
+pub(crate) struct MetricsRecorder {
+    retries: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    errors_by_type: Mutex<HashMap<&'static str, u64>>,
+}
+
+impl MetricsRecorder {
+    pub(crate) fn record_retry(&self) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count a cache lookup as a hit or a miss
+    pub(crate) fn record_cache_lookup(&self, hit: bool) {
+        let counter = if hit {
+            &self.cache_hits
+        } else {
+            &self.cache_misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_error(&self, error: &KiteError) {
+        if let Ok(mut errors) = self.errors_by_type.lock() {
+            *errors.entry(error.kind()).or_insert(0) += 1;
+        }
+    }
+
+    /// Copy the counters, combined with those kept elsewhere in the client
+    pub(crate) fn snapshot(&self, total_requests: u64, rate_limit_waits: u64) -> ClientMetrics {
+        let errors_by_type = self
+            .errors_by_type
+            .lock()
+            .map(|errors| {
+                errors
+                    .iter()
+                    .map(|(kind, count)| (kind.to_string(), *count))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        ClientMetrics {
+            total_requests,
+            retries: self.retries.load(Ordering::Relaxed),
+            rate_limit_waits,
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            cache_misses: self.cache_misses.load(Ordering::Relaxed),
+            errors_by_type,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recorder_snapshot() {
+        let recorder = MetricsRecorder::default();
+        recorder.record_retry();
+        recorder.record_cache_lookup(true);
+        recorder.record_cache_lookup(true);
+        recorder.record_cache_lookup(false);
+        recorder.record_error(&KiteError::TokenException("expired".to_string()));
+        recorder.record_error(&KiteError::TokenException("expired".to_string()));
+        recorder.record_error(&KiteError::Shutdown);
+
+        let metrics = recorder.snapshot(7, 2);
+        assert_eq!(metrics.total_requests, 7);
+        assert_eq!(metrics.retries, 1);
+        assert_eq!(metrics.rate_limit_waits, 2);
+        assert_eq!(metrics.errors_by_type["TokenException"], 2);
+        assert_eq!(metrics.total_errors(), 3);
+        assert_eq!(metrics.cache_hit_ratio(), Some(2.0 / 3.0));
+        assert_eq!(ClientMetrics::default().cache_hit_ratio(), None);
+    }
+}
@@ -46,18 +46,23 @@
 //! ```
 
 use anyhow::{anyhow, Context, Result};
-use reqwest::header::{HeaderMap, AUTHORIZATION, USER_AGENT};
+use chrono::NaiveDate;
+use reqwest::header::{HeaderMap, AUTHORIZATION, RETRY_AFTER, USER_AGENT};
 use serde::de::DeserializeOwned;
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
-use std::sync::{atomic::AtomicU64, Arc};
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 // Import our typed models
-use crate::models::common::{KiteError, KiteResult};
+use crate::models::common::{Exchange, KiteError, KiteResult};
+use crate::models::market_data::MarketStatus;
 
 // Cache imports
-use std::sync::Mutex;
+use std::sync::{Mutex, RwLock};
 use std::time::{Duration as StdDuration, SystemTime};
 
 // WASM platform imports
@@ -66,18 +71,33 @@ use web_sys::console;
 
 // Import sub-modules
 pub mod auth;
+mod builder;
+pub mod cache;
 pub mod endpoints;
 pub mod gtt;
 pub mod market_data;
+pub mod metrics;
 pub mod mutual_funds;
 pub mod orders;
 pub mod portfolio;
 pub mod rate_limiter;
+pub mod ticker;
 pub mod utils;
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+mod websocket;
 
 // Re-export commonly used utilities
+pub use auth::verify_postback;
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+pub use auth::RefreshSchedule;
+pub use builder::KiteConnectBuilder;
+pub use cache::{CacheBackend, InMemoryCache};
 pub use endpoints::{Endpoint, HttpMethod, KiteEndpoint, RateLimitCategory};
-pub use rate_limiter::{CategoryStats, RateLimiter, RateLimiterStats};
+pub use metrics::ClientMetrics;
+pub use rate_limiter::{BucketConfig, CategoryStats, RateLimiter, RateLimiterStats};
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+pub use ticker::KiteTicker;
+pub use ticker::{TickerConfig, TickerSubscriptions};
 pub use utils::{RequestHandler, URL};
 
 /// Configuration for retry behavior
@@ -87,6 +107,21 @@ pub struct RetryConfig {
     pub base_delay: Duration,
     pub max_delay: Duration,
     pub exponential_backoff: bool,
+    /// Randomization applied to each computed retry delay
+    ///
+    /// Spreads out clients that would otherwise retry in lockstep after an
+    /// outage. Server `Retry-After` hints are used as-is.
+    pub jitter: JitterMode,
+    /// Random source for `jitter`, replaceable for deterministic tests
+    pub jitter_rng: JitterRng,
+    /// Also retry POST requests such as order placement
+    ///
+    /// Off by default: a POST that timed out may still have been executed by
+    /// Kite, so retrying it can place a duplicate order. Opt in only when
+    /// duplicates are harmless or the requests carry your own dedup tag.
+    /// GET, PUT and DELETE requests are retried regardless, and so are POSTs
+    /// rejected with `429 Too Many Requests`, which Kite never executed.
+    pub retry_mutations: bool,
 }
 
 impl Default for RetryConfig {
@@ -96,16 +131,137 @@ impl Default for RetryConfig {
             base_delay: Duration::from_millis(200),
             max_delay: Duration::from_secs(5),
             exponential_backoff: true,
+            jitter: JitterMode::Equal,
+            jitter_rng: JitterRng::default(),
+            retry_mutations: false,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Number of retries allowed for a request with HTTP `method` that failed with `error`
+    ///
+    /// POST requests are only retried with `retry_mutations` set, except
+    /// after a rate-limit rejection, which Kite never executed.
+    pub fn max_retries_for(&self, method: &str, error: &KiteError) -> u32 {
+        let rate_limited = matches!(error, KiteError::RateLimited { .. });
+        if method == HttpMethod::POST.as_str() && !self.retry_mutations && !rate_limited {
+            0
+        } else {
+            self.max_retries
+        }
+    }
+
+    /// Delay before retry `attempt` (0-based), with jitter applied
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let delay = if self.exponential_backoff {
+            let factor = 2_u32.checked_pow(attempt).unwrap_or(u32::MAX);
+            std::cmp::min(self.base_delay.saturating_mul(factor), self.max_delay)
+        } else {
+            self.base_delay
+        };
+
+        match self.jitter {
+            JitterMode::None => delay,
+            JitterMode::Full => delay.mul_f64(self.jitter_rng.sample()),
+            JitterMode::Equal => delay / 2 + (delay / 2).mul_f64(self.jitter_rng.sample()),
+        }
+    }
+}
+
+/// How retry delays are randomized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JitterMode {
+    /// Use the computed backoff exactly
+    None,
+    /// Anywhere between zero and the computed backoff
+    Full,
+    /// Half the computed backoff plus a random share of the other half
+    #[default]
+    Equal,
+}
+
+/// Source of uniform random numbers in `[0, 1)` for retry jitter
+///
+/// # Example
+///
+/// ```rust
+/// use kiteconnect_async_wasm::connect::{JitterMode, JitterRng, RetryConfig};
+/// use std::time::Duration;
+///
+/// let config = RetryConfig {
+///     jitter: JitterMode::Full,
+///     jitter_rng: JitterRng::fixed(0.5),
+///     ..Default::default()
+/// };
+/// assert_eq!(config.delay_for_attempt(0), Duration::from_millis(100));
+/// ```
+#[derive(Clone)]
+pub struct JitterRng(Arc<dyn Fn() -> f64 + Send + Sync>);
+
+impl JitterRng {
+    /// Use `source` for jitter; values outside `[0, 1]` are clamped
+    pub fn new(source: impl Fn() -> f64 + Send + Sync + 'static) -> Self {
+        Self(Arc::new(source))
+    }
+
+    /// Always return `value`
+    pub fn fixed(value: f64) -> Self {
+        Self::new(move || value)
+    }
+
+    fn sample(&self) -> f64 {
+        let value = (self.0)();
+        if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
         }
     }
 }
 
+impl Default for JitterRng {
+    fn default() -> Self {
+        Self::new(random_unit)
+    }
+}
+
+impl std::fmt::Debug for JitterRng {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JitterRng").finish_non_exhaustive()
+    }
+}
+
+/// Uniform random value in `[0, 1)`
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+fn random_unit() -> f64 {
+    js_sys::Math::random()
+}
+
+/// Uniform random value in `[0, 1)`
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+fn random_unit() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    static CALLS: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u64(CALLS.fetch_add(1, Ordering::Relaxed));
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
 /// Configuration for response caching
 #[derive(Debug, Clone)]
 pub struct CacheConfig {
     pub enable_instruments_cache: bool,
     pub cache_ttl_minutes: u64,
     pub max_cache_size: usize,
+    /// Directory for persisting the full instruments list between runs
+    ///
+    /// When set, each download is written to `{cache_dir}/instruments.json`
+    /// with its fetch time, and a fresh process reuses that file until it is
+    /// older than `cache_ttl_minutes`.
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    pub cache_dir: Option<std::path::PathBuf>,
 }
 
 impl Default for CacheConfig {
@@ -114,66 +270,204 @@ impl Default for CacheConfig {
             enable_instruments_cache: true,
             cache_ttl_minutes: 60, // 1 hour
             max_cache_size: 1000,
+            #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+            cache_dir: None,
         }
     }
 }
 
-/// Simple in-memory cache for API responses
+/// Response cache with TTL on top of a [`CacheBackend`]
 #[derive(Debug)]
 pub(crate) struct ResponseCache {
-    instruments_cache: Option<(JsonValue, SystemTime)>,
+    /// Instruments keyed by `instruments[:EXCHANGE]`
+    backend: Arc<dyn CacheBackend>,
+    /// Token to symbol map derived from the full list, never shared
+    token_symbols: Option<(Arc<HashMap<u32, String>>, SystemTime)>,
     ttl_minutes: u64,
 }
 
 impl ResponseCache {
-    fn new(ttl_minutes: u64) -> Self {
+    fn new(ttl_minutes: u64, backend: Arc<dyn CacheBackend>) -> Self {
         Self {
-            instruments_cache: None,
+            backend,
+            token_symbols: None,
             ttl_minutes,
         }
     }
 
-    fn get_instruments(&self) -> Option<JsonValue> {
-        if let Some((data, timestamp)) = &self.instruments_cache {
-            let elapsed = timestamp.elapsed().ok()?;
-            if elapsed < StdDuration::from_secs(self.ttl_minutes * 60) {
-                return Some(data.clone());
-            }
+    fn is_fresh(&self, timestamp: &SystemTime) -> bool {
+        timestamp
+            .elapsed()
+            .map(|elapsed| elapsed < StdDuration::from_secs(self.ttl_minutes * 60))
+            .unwrap_or(false)
+    }
+
+    /// Backend key for instruments, `None` being the full list
+    fn instruments_key(exchange: Option<&str>) -> String {
+        match exchange {
+            Some(exchange) => format!("instruments:{}", exchange),
+            None => "instruments".to_string(),
+        }
+    }
+
+    fn get_instruments(&self, exchange: Option<&str>) -> Option<JsonValue> {
+        match self.backend.get(&Self::instruments_key(exchange)) {
+            Some((data, timestamp)) if self.is_fresh(&timestamp) => Some(data),
+            _ => None,
+        }
+    }
+
+    fn set_instruments(&mut self, exchange: Option<&str>, data: JsonValue) {
+        self.set_instruments_at(exchange, data, SystemTime::now());
+    }
+
+    /// Store instruments fetched at `fetched_at`, e.g. when loaded from disk
+    fn set_instruments_at(
+        &mut self,
+        exchange: Option<&str>,
+        data: JsonValue,
+        fetched_at: SystemTime,
+    ) {
+        if exchange.is_none() {
+            // Rebuilt from the new full list on next lookup
+            self.token_symbols = None;
         }
-        None
+        self.backend
+            .set_at(&Self::instruments_key(exchange), data, fetched_at);
     }
 
-    fn set_instruments(&mut self, data: JsonValue) {
-        self.instruments_cache = Some((data, SystemTime::now()));
+    fn get_token_symbols(&self) -> Option<Arc<HashMap<u32, String>>> {
+        match &self.token_symbols {
+            Some((symbols, timestamp)) if self.is_fresh(timestamp) => Some(symbols.clone()),
+            _ => None,
+        }
+    }
+
+    fn set_token_symbols(&mut self, symbols: Arc<HashMap<u32, String>>) {
+        self.token_symbols = Some((symbols, SystemTime::now()));
+    }
+
+    fn clear(&mut self) {
+        self.backend.clear();
+        self.token_symbols = None;
     }
 }
 
 /// Configuration for KiteConnect client
+///
+/// ## Connection Pooling
+///
+/// All requests share one HTTP client whose idle connections are kept alive
+/// and reused, avoiding a TLS handshake per request. A connection is only
+/// reused if it was returned to the pool and has not been idle longer than
+/// `idle_timeout`, so for high-frequency polling (e.g. quotes every second):
+///
+/// - keep `idle_timeout` comfortably above the polling interval (the default
+///   30s is fine for intervals up to ~10s)
+/// - set `max_idle_connections` to at least the number of concurrent requests
+///   you issue, so bursts don't close connections on return to the pool
+/// - keep `tcp_keepalive` enabled so intermediaries don't silently drop idle
+///   connections between polls
 #[derive(Debug, Clone)]
 pub struct KiteConnectConfig {
     pub base_url: String,
     pub timeout: u64,
     pub retry_config: RetryConfig,
     pub cache_config: Option<CacheConfig>,
+    /// Maximum idle connections kept per host
     pub max_idle_connections: usize,
+    /// Seconds an idle pooled connection is kept before being closed
     pub idle_timeout: u64,
+    /// TCP keep-alive interval in seconds (`None` disables keep-alive probes)
+    pub tcp_keepalive: Option<u64>,
     pub enable_rate_limiting: bool,
+    /// Token-bucket overrides per rate limit category; categories not listed
+    /// use [`BucketConfig::for_category`]
+    pub rate_limits: HashMap<RateLimitCategory, BucketConfig>,
+    /// Base URL overrides per rate limit category, e.g. to send historical
+    /// data requests to a mirror; categories not listed use `base_url`
+    pub endpoint_overrides: HashMap<RateLimitCategory, String>,
+    /// Keep the headers of the most recent response for
+    /// [`KiteConnect::last_response_headers`]
+    pub capture_response_headers: bool,
+    /// Tag quotes fetched while their exchange is closed with
+    /// `Quote::is_after_hours` (see [`KiteConnect::market_status`])
+    pub tag_after_hours_quotes: bool,
+    /// Storage for cached responses; `None` keeps them in process memory
+    ///
+    /// Plug in a shared store to reuse e.g. the instruments list across
+    /// processes. Only used while `cache_config` is set.
+    pub cache_backend: Option<Arc<dyn CacheBackend>>,
+    /// Fall back to a null-tolerant parse when a typed response fails strict
+    /// deserialization (see [`KiteConnect::with_lenient_parsing`])
+    pub lenient_parsing: bool,
+    /// Instruments per quote request, at most Kite's limit of
+    /// [`MAX_QUOTE_BATCH_SIZE`](market_data::MAX_QUOTE_BATCH_SIZE); larger
+    /// quote calls are split into sequential batches of this size. Values
+    /// outside `1..=MAX_QUOTE_BATCH_SIZE` make quote calls fail
+    pub quote_batch_size: usize,
+    /// Most sub-requests `historical_data_typed` may split one range into;
+    /// longer ranges fail instead of issuing unbounded requests. `1`
+    /// disables automatic chunking; `0` makes every call fail
+    pub historical_max_chunks: usize,
+    /// WebSocket ticker endpoint used by [`KiteConnect::ticker`] and
+    /// [`KiteConnect::subscribe`]
+    pub ticker_url: String,
+    /// Ticker reconnection behaviour
+    pub ticker_config: TickerConfig,
 }
 
 impl Default for KiteConnectConfig {
     fn default() -> Self {
         Self {
-            base_url: "https://api.kite.trade".to_string(),
+            base_url: URL.to_string(),
             timeout: 30,
             retry_config: RetryConfig::default(),
             cache_config: Some(CacheConfig::default()),
             max_idle_connections: 10,
             idle_timeout: 30,
+            tcp_keepalive: Some(60),
             enable_rate_limiting: true,
+            rate_limits: HashMap::new(),
+            endpoint_overrides: HashMap::new(),
+            capture_response_headers: false,
+            tag_after_hours_quotes: false,
+            cache_backend: None,
+            lenient_parsing: false,
+            quote_batch_size: market_data::MAX_QUOTE_BATCH_SIZE,
+            historical_max_chunks: market_data::DEFAULT_HISTORICAL_MAX_CHUNKS,
+            ticker_url: ticker::TICKER_URL.to_string(),
+            ticker_config: TickerConfig::default(),
         }
     }
 }
 
+/// Async session renewal callback, see [`KiteConnect::set_async_session_hook`]
+///
+/// Resolves to `Some(access_token)` when a fresh session was obtained, or
+/// `None` when renewal is not possible.
+pub type AsyncSessionHook =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Option<String>> + Send>> + Send + Sync>;
+
+/// Holder for an [`AsyncSessionHook`] so `KiteConnect` can keep deriving `Debug`
+#[derive(Clone)]
+pub(crate) struct AsyncSessionHookHandle(AsyncSessionHook);
+
+impl std::fmt::Debug for AsyncSessionHookHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AsyncSessionHook")
+    }
+}
+
+/// Body of a request sent through the retry path
+#[derive(Debug, Clone)]
+pub(crate) enum RequestBody<'a> {
+    /// Form fields, as most endpoints take
+    Form(Option<HashMap<&'a str, &'a str>>),
+    /// A JSON document, as the margin calculation endpoints take
+    Json(&'a JsonValue),
+}
+
 /// Main client for interacting with the KiteConnect API
 ///
 /// This struct provides async methods for all KiteConnect REST API endpoints.
@@ -230,15 +524,19 @@ impl Default for KiteConnectConfig {
 pub struct KiteConnect {
     /// API key for authentication
     pub(crate) api_key: String,
-    /// Access token for authenticated requests
-    pub(crate) access_token: String,
+    /// Access token for authenticated requests, shared between clones so a
+    /// background refresher can update it for every handle
+    pub(crate) access_token: Arc<RwLock<String>>,
     /// Base URL for API requests
     pub(crate) root: String,
+    /// Base URL overrides per rate limit category
+    pub(crate) endpoint_overrides: HashMap<RateLimitCategory, String>,
     /// Request timeout in seconds
-    #[allow(dead_code)]
     pub(crate) timeout: u64,
     /// Optional callback for session expiry handling
     pub(crate) session_expiry_hook: Option<fn() -> ()>,
+    /// Optional async callback that renews an expired session
+    pub(crate) async_session_hook: Option<AsyncSessionHookHandle>,
     /// HTTP client for making requests (shared and reusable)
     pub(crate) client: reqwest::Client,
 
@@ -249,44 +547,101 @@ pub struct KiteConnect {
     pub(crate) cache_config: Option<CacheConfig>,
     /// Request counter for debugging and monitoring
     pub(crate) request_counter: Arc<AtomicU64>,
+    /// Retry, cache and error counters, shared between clones
+    pub(crate) metrics: Arc<metrics::MetricsRecorder>,
     /// Response cache for performance optimization
     pub(crate) response_cache: Arc<Mutex<Option<ResponseCache>>>,
     /// Rate limiter for API compliance
     pub(crate) rate_limiter: rate_limiter::RateLimiter,
+    /// Maximum idle pooled connections per host
+    pub(crate) max_idle_connections: usize,
+    /// Idle timeout for pooled connections in seconds
+    pub(crate) idle_timeout: u64,
+    /// Whether quotes are tagged with `is_after_hours`
+    pub(crate) tag_after_hours_quotes: bool,
+    /// Whether typed responses fall back to a null-tolerant parse
+    pub(crate) lenient_parsing: bool,
+    /// Instruments per quote request, checked against 1..=MAX_QUOTE_BATCH_SIZE when used
+    pub(crate) quote_batch_size: usize,
+    /// Cap on automatic historical data sub-requests, checked to be at least 1 when used
+    pub(crate) historical_max_chunks: usize,
+    /// WebSocket ticker endpoint
+    pub(crate) ticker_url: String,
+    /// Ticker reconnection behaviour
+    pub(crate) ticker_config: TickerConfig,
+    /// Market status per exchange with the time it was determined
+    pub(crate) market_status_cache: Arc<Mutex<HashMap<Exchange, (MarketStatus, SystemTime)>>>,
+    /// Exchange holidays seeded with `set_market_holidays`, shared between clones
+    pub(crate) market_holidays: Arc<Mutex<HashMap<Exchange, BTreeSet<NaiveDate>>>>,
+    /// Whether response headers are captured into `last_response_headers`
+    pub(crate) capture_response_headers: bool,
+    /// Headers of the most recent response, shared between clones
+    pub(crate) last_response_headers: Arc<Mutex<Option<HeaderMap>>>,
+    /// Rows dropped while parsing the last `instruments_typed` fetch
+    pub(crate) instruments_skipped_rows: Arc<AtomicU64>,
+    /// Set once `shutdown` has been called, shared between clones
+    pub(crate) shut_down: Arc<AtomicBool>,
+    /// Background tasks cancelled on shutdown, shared between clones
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    pub(crate) background_tasks: Arc<Mutex<Vec<tokio::task::AbortHandle>>>,
 }
 
 impl Default for KiteConnect {
     fn default() -> Self {
-        KiteConnect {
-            api_key: "<API-KEY>".to_string(),
-            access_token: "<ACCESS-TOKEN>".to_string(),
-            root: URL.to_string(),
-            timeout: 30,
-            session_expiry_hook: None,
-            client: reqwest::Client::new(),
-            retry_config: RetryConfig::default(),
-            cache_config: Some(CacheConfig::default()),
-            request_counter: Arc::new(AtomicU64::new(0)),
-            response_cache: Arc::new(Mutex::new(None)),
-            rate_limiter: rate_limiter::RateLimiter::new(true),
-        }
+        KiteConnect::new("<API-KEY>", "<ACCESS-TOKEN>")
     }
 }
 
 impl KiteConnect {
     /// Constructs url for the given path and query params
-    pub(crate) fn build_url(&self, path: &str, param: Option<Vec<(&str, &str)>>) -> reqwest::Url {
-        let url: &str = &format!("{}/{}", self.root, &path[1..]);
-        let mut url = reqwest::Url::parse(url).unwrap();
+    ///
+    /// Fails with `KiteError::InvalidParameter` if the configured base URL
+    /// does not form a valid URL, rather than panicking.
+    pub(crate) fn build_url(
+        &self,
+        path: &str,
+        param: Option<Vec<(&str, &str)>>,
+    ) -> KiteResult<reqwest::Url> {
+        Self::build_url_on(&self.root, path, param)
+    }
+
+    /// Constructs url for a path of `endpoint`, using the base URL override
+    /// for its rate limit category when one is configured
+    pub(crate) fn build_endpoint_url(
+        &self,
+        endpoint: &KiteEndpoint,
+        path: &str,
+        param: Option<Vec<(&str, &str)>>,
+    ) -> KiteResult<reqwest::Url> {
+        let root = self
+            .endpoint_overrides
+            .get(&endpoint.config().rate_limit_category)
+            .unwrap_or(&self.root);
+        Self::build_url_on(root, path, param)
+    }
+
+    fn build_url_on(
+        root: &str,
+        path: &str,
+        param: Option<Vec<(&str, &str)>>,
+    ) -> KiteResult<reqwest::Url> {
+        let url: &str = &format!("{}/{}", root, path.trim_start_matches('/'));
+        let mut url = reqwest::Url::parse(url)
+            .map_err(|e| KiteError::invalid_param(format!("Invalid base URL {:?}: {}", root, e)))?;
 
         if let Some(data) = param {
             url.query_pairs_mut().extend_pairs(data.iter());
         }
-        url
+        Ok(url)
     }
 
     /// Creates a new KiteConnect client instance
     ///
+    /// Uses the same defaults as [`KiteConnectConfig::default()`], including
+    /// connection pooling, request timeout and user agent, except that the
+    /// response cache stays off. Use [`KiteConnect::new_with_config`] to tune
+    /// them or turn the cache on.
+    ///
     /// # Arguments
     ///
     /// * `api_key` - Your KiteConnect API key
@@ -304,19 +659,10 @@ impl KiteConnect {
     /// let client = KiteConnect::new("your_api_key", "your_access_token");
     /// ```
     pub fn new(api_key: &str, access_token: &str) -> Self {
-        Self {
-            api_key: api_key.to_string(),
-            access_token: access_token.to_string(),
-            root: URL.to_string(),
-            timeout: 30,
-            session_expiry_hook: None,
-            client: reqwest::Client::new(),
-            retry_config: RetryConfig::default(),
-            cache_config: Some(CacheConfig::default()),
-            request_counter: Arc::new(AtomicU64::new(0)),
-            response_cache: Arc::new(Mutex::new(None)),
-            rate_limiter: rate_limiter::RateLimiter::new(true),
-        }
+        let mut client = Self::new_with_config(api_key, KiteConnectConfig::default());
+        client.response_cache = Arc::new(Mutex::new(None));
+        client.set_access_token(access_token);
+        client
     }
 
     /// Creates a new KiteConnect client with custom configuration
@@ -349,27 +695,51 @@ impl KiteConnect {
             .timeout(Duration::from_secs(config.timeout))
             .pool_max_idle_per_host(config.max_idle_connections)
             .pool_idle_timeout(Duration::from_secs(config.idle_timeout))
+            .tcp_keepalive(config.tcp_keepalive.map(Duration::from_secs))
             .user_agent(format!("kiteconnect-rust/{}", env!("CARGO_PKG_VERSION")))
             .build()
             .expect("Failed to create HTTP client");
 
         Self {
             api_key: api_key.to_string(),
-            access_token: String::new(),
+            access_token: Arc::new(RwLock::new(String::new())),
             root: config.base_url,
+            endpoint_overrides: config.endpoint_overrides,
             timeout: config.timeout,
             session_expiry_hook: None,
+            async_session_hook: None,
             client,
             retry_config: config.retry_config,
             cache_config: config.cache_config.clone(),
             request_counter: Arc::new(AtomicU64::new(0)),
-            response_cache: Arc::new(Mutex::new(
-                config
-                    .cache_config
-                    .as_ref()
-                    .map(|c| ResponseCache::new(c.cache_ttl_minutes)),
-            )),
-            rate_limiter: rate_limiter::RateLimiter::new(config.enable_rate_limiting),
+            metrics: Arc::new(metrics::MetricsRecorder::default()),
+            response_cache: Arc::new(Mutex::new(config.cache_config.as_ref().map(|c| {
+                let backend = config
+                    .cache_backend
+                    .clone()
+                    .unwrap_or_else(|| Arc::new(InMemoryCache::new()));
+                ResponseCache::new(c.cache_ttl_minutes, backend)
+            }))),
+            rate_limiter: rate_limiter::RateLimiter::with_buckets(
+                config.enable_rate_limiting,
+                &config.rate_limits,
+            ),
+            max_idle_connections: config.max_idle_connections,
+            idle_timeout: config.idle_timeout,
+            tag_after_hours_quotes: config.tag_after_hours_quotes,
+            lenient_parsing: config.lenient_parsing,
+            ticker_url: config.ticker_url,
+            ticker_config: config.ticker_config,
+            quote_batch_size: config.quote_batch_size,
+            historical_max_chunks: config.historical_max_chunks,
+            market_status_cache: Arc::new(Mutex::new(HashMap::new())),
+            market_holidays: Arc::new(Mutex::new(HashMap::new())),
+            capture_response_headers: config.capture_response_headers,
+            last_response_headers: Arc::new(Mutex::new(None)),
+            instruments_skipped_rows: Arc::new(AtomicU64::new(0)),
+            shut_down: Arc::new(AtomicBool::new(false)),
+            #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+            background_tasks: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -379,26 +749,30 @@ impl KiteConnect {
             let jsn: JsonValue = resp.json().await.with_context(|| "Serialization failed")?;
             Ok(jsn)
         } else {
+            let path = resp.url().path().to_string();
             let status_code = resp.status().as_u16();
             let status = status_code.to_string();
+            let retry_after = parse_retry_after(resp.headers());
             let error_text = resp.text().await?;
 
             // Try to parse as JSON to extract error details
-            if let Ok(error_json) = serde_json::from_str::<JsonValue>(&error_text) {
+            let kite_error = if let Ok(error_json) = serde_json::from_str::<JsonValue>(&error_text)
+            {
                 let message = error_json["message"]
                     .as_str()
                     .unwrap_or(&error_text)
                     .to_string();
                 let error_type = error_json["error_type"].as_str().map(|s| s.to_string());
 
-                let kite_error =
-                    KiteError::from_api_response(status_code, status, message, error_type);
-                Err(anyhow::Error::new(kite_error))
+                KiteError::from_api_response(status_code, status, message, error_type)
+                    .with_retry_after(retry_after)
             } else {
-                let kite_error =
-                    KiteError::from_api_response(status_code, status, error_text, None);
-                Err(anyhow::Error::new(kite_error))
-            }
+                KiteError::from_api_response(status_code, status, error_text, None)
+                    .with_retry_after(retry_after)
+            };
+            self.metrics.record_error(&kite_error);
+            self.notify_session_expiry(&path, &kite_error);
+            Err(anyhow::Error::new(kite_error))
         }
     }
 
@@ -407,46 +781,149 @@ impl KiteConnect {
         &self,
         url: reqwest::Url,
         method: &str,
-        data: Option<HashMap<&str, &str>>,
+        body: RequestBody<'_>,
+    ) -> KiteResult<reqwest::Response> {
+        let max_retries = self.retry_config.max_retries;
+        let response = self
+            .send_request_with_retry_limit(url.clone(), method, body.clone(), max_retries)
+            .await?;
+
+        let Some(hook) = self.async_session_hook.clone() else {
+            return Ok(response);
+        };
+        if response.status() != reqwest::StatusCode::FORBIDDEN
+            || url.path().ends_with(KiteEndpoint::GenerateSession.path())
+        {
+            return Ok(response);
+        }
+
+        let path = url.path().to_string();
+        let error = self.error_from_response(response).await;
+        if matches!(error, KiteError::TokenException(_)) {
+            if let Some(access_token) = (hook.0)().await {
+                #[cfg(feature = "debug")]
+                log::debug!("Session renewed by async hook, retrying request once");
+
+                self.store_access_token(&access_token);
+                return self
+                    .send_request_with_retry_limit(url, method, body, max_retries)
+                    .await;
+            }
+        }
+
+        self.metrics.record_error(&error);
+        self.notify_session_expiry(&path, &error);
+        Err(error)
+    }
+
+    /// Send one attempt of a request, traced as a `kite_request` span under
+    /// the `tracing` feature
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "kite_request",
+            skip_all,
+            fields(
+                endpoint = %url.path(),
+                method = %method,
+                attempt = attempt,
+                status = tracing::field::Empty,
+                elapsed_ms = tracing::field::Empty,
+            )
+        )
+    )]
+    async fn send_request_attempt(
+        &self,
+        url: reqwest::Url,
+        method: &str,
+        body: RequestBody<'_>,
+        attempt: u32,
+    ) -> Result<reqwest::Response> {
+        #[cfg(not(feature = "tracing"))]
+        let _ = attempt;
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        let result = match body {
+            RequestBody::Form(data) => self.send_request(url, method, data).await,
+            RequestBody::Json(body) => self.send_json_request(url, body).await,
+        };
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+            if let Ok(response) = &result {
+                span.record("status", response.status().as_u16());
+            }
+        }
+
+        result
+    }
+
+    /// Send request retrying at most `max_retries` times, and only as often
+    /// as [`RetryConfig::max_retries_for`] allows for each failure
+    async fn send_request_with_retry_limit(
+        &self,
+        url: reqwest::Url,
+        method: &str,
+        body: RequestBody<'_>,
+        max_retries: u32,
     ) -> KiteResult<reqwest::Response> {
         let mut last_error = None;
 
-        for attempt in 0..=self.retry_config.max_retries {
+        for attempt in 0..=max_retries {
+            self.ensure_running()?;
+
             // Increment request counter
-            self.request_counter
-                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            self.request_counter.fetch_add(1, Ordering::Relaxed);
 
-            match self.send_request(url.clone(), method, data.clone()).await {
+            match self
+                .send_request_attempt(url.clone(), method, body.clone(), attempt)
+                .await
+            {
                 Ok(response) => {
                     // Check if response indicates an error that should be retried
                     if response.status().is_server_error() || response.status() == 429 {
                         let status = response.status().as_u16().to_string();
+                        let retry_after = parse_retry_after(response.headers());
                         let error_text = response
                             .text()
                             .await
                             .unwrap_or_else(|_| "Unknown server error".to_string());
 
-                        let error = KiteError::Api {
-                            status,
-                            message: error_text,
-                            error_type: Some("ServerError".to_string()),
+                        let error = if status == "429" {
+                            KiteError::RateLimited {
+                                message: error_text,
+                                retry_after,
+                            }
+                        } else {
+                            KiteError::Api {
+                                status,
+                                message: error_text,
+                                error_type: Some("ServerError".to_string()),
+                            }
                         };
 
-                        if attempt < self.retry_config.max_retries && self.should_retry(&error) {
+                        if attempt < self.retry_limit(method, &error, max_retries)
+                            && self.should_retry(&error)
+                        {
+                            let delay = self.retry_delay_for(attempt, &error);
                             last_error = Some(error);
-                            let delay = self.calculate_retry_delay(attempt);
+                            self.metrics.record_retry();
 
                             #[cfg(feature = "debug")]
                             log::debug!(
                                 "Request failed, retrying in {:?}. Attempt {}/{}",
                                 delay,
                                 attempt + 1,
-                                self.retry_config.max_retries
+                                max_retries
                             );
 
                             tokio::time::sleep(delay).await;
                             continue;
                         } else {
+                            self.metrics.record_error(&error);
                             return Err(error);
                         }
                     }
@@ -454,23 +931,31 @@ impl KiteConnect {
                     return Ok(response);
                 }
                 Err(e) => {
-                    let kite_error = KiteError::Legacy(e);
-
-                    if attempt < self.retry_config.max_retries && self.should_retry(&kite_error) {
+                    // Surface transport failures (including timeouts) as typed errors
+                    let kite_error = match e.downcast::<reqwest::Error>() {
+                        Ok(error) => KiteError::from(error),
+                        Err(e) => KiteError::Legacy(e),
+                    };
+
+                    if attempt < self.retry_limit(method, &kite_error, max_retries)
+                        && self.should_retry(&kite_error)
+                    {
                         last_error = Some(kite_error);
                         let delay = self.calculate_retry_delay(attempt);
+                        self.metrics.record_retry();
 
                         #[cfg(feature = "debug")]
                         log::debug!(
                             "Request failed, retrying in {:?}. Attempt {}/{}",
                             delay,
                             attempt + 1,
-                            self.retry_config.max_retries
+                            max_retries
                         );
 
                         tokio::time::sleep(delay).await;
                         continue;
                     } else {
+                        self.metrics.record_error(&kite_error);
                         return Err(kite_error);
                     }
                 }
@@ -482,47 +967,72 @@ impl KiteConnect {
             .unwrap_or_else(|| KiteError::General("All retry attempts failed".to_string())))
     }
 
+    /// Retries allowed after `error`, capped at `max_retries`
+    fn retry_limit(&self, method: &str, error: &KiteError, max_retries: u32) -> u32 {
+        max_retries.min(self.retry_config.max_retries_for(method, error))
+    }
+
     /// Enhanced JSON response handler with better error handling
     pub(crate) async fn raise_or_return_json_typed(
         &self,
         resp: reqwest::Response,
     ) -> KiteResult<JsonValue> {
         if resp.status().is_success() {
-            resp.json().await.map_err(KiteError::Http)
+            resp.json().await.map_err(KiteError::from)
         } else {
-            let status_code = resp.status().as_u16();
-            let status = status_code.to_string();
-            let error_text = resp.text().await.map_err(KiteError::Http)?;
+            let path = resp.url().path().to_string();
+            let kite_error = self.error_from_response(resp).await;
+            self.metrics.record_error(&kite_error);
+            self.notify_session_expiry(&path, &kite_error);
+            Err(kite_error)
+        }
+    }
 
-            // Try to parse as JSON to extract error details
-            if let Ok(error_json) = serde_json::from_str::<JsonValue>(&error_text) {
-                let message = error_json["message"]
-                    .as_str()
-                    .unwrap_or(&error_text)
-                    .to_string();
-                let error_type = error_json["error_type"].as_str().map(|s| s.to_string());
+    /// Build the `KiteError` described by an unsuccessful response
+    async fn error_from_response(&self, resp: reqwest::Response) -> KiteError {
+        let status_code = resp.status().as_u16();
+        let status = status_code.to_string();
+        let retry_after = parse_retry_after(resp.headers());
+        let error_text = match resp.text().await {
+            Ok(text) => text,
+            Err(e) => return KiteError::from(e),
+        };
 
-                Err(KiteError::from_api_response(
-                    status_code,
-                    status,
-                    message,
-                    error_type,
-                ))
-            } else {
-                Err(KiteError::from_api_response(
-                    status_code,
-                    status,
-                    error_text,
-                    None,
-                ))
-            }
+        // Try to parse as JSON to extract error details
+        let kite_error = if let Ok(error_json) = serde_json::from_str::<JsonValue>(&error_text) {
+            let message = error_json["message"]
+                .as_str()
+                .unwrap_or(&error_text)
+                .to_string();
+            let error_type = error_json["error_type"].as_str().map(|s| s.to_string());
+
+            KiteError::from_api_response(status_code, status, message, error_type)
+        } else {
+            KiteError::from_api_response(status_code, status, error_text, None)
+        };
+        kite_error.with_retry_after(retry_after)
+    }
+
+    /// Run the session expiry hook if `error` means the session has expired
+    ///
+    /// A `TokenException` from session generation means the request token was
+    /// rejected rather than that a live session expired, so it is not reported.
+    fn notify_session_expiry(&self, path: &str, error: &KiteError) {
+        if !matches!(error, KiteError::TokenException(_))
+            || path.ends_with(KiteEndpoint::GenerateSession.path())
+        {
+            return;
+        }
+        if let Some(hook) = self.session_expiry_hook {
+            hook();
         }
     }
 
     /// Sets a session expiry callback hook for this instance
     ///
     /// This hook will be called when a session expires, allowing you to handle
-    /// re-authentication or cleanup logic.
+    /// re-authentication or cleanup logic. It runs whenever a request fails
+    /// with a `TokenException`, before the error is returned to the caller.
     ///
     /// # Arguments
     ///
@@ -555,6 +1065,41 @@ impl KiteConnect {
         self.session_expiry_hook
     }
 
+    /// Sets an async hook that renews the session when it expires
+    ///
+    /// When a request fails with a `TokenException`, the hook is awaited. If
+    /// it resolves to `Some(access_token)`, the token is stored for this
+    /// client and all its clones and the failed request is sent once more;
+    /// if it resolves to `None`, the error is returned as usual. Requests
+    /// failing concurrently may each invoke the hook.
+    ///
+    /// The returned future must be `Send` so client calls can still be
+    /// spawned onto multi-threaded runtimes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use std::sync::Arc;
+    ///
+    /// let mut client = KiteConnect::new("api_key", "access_token");
+    /// client.set_async_session_hook(Arc::new(|| {
+    ///     Box::pin(async {
+    ///         // e.g. renew with a stored refresh token
+    ///         Some("fresh_access_token".to_string())
+    ///     })
+    /// }));
+    /// assert!(client.has_async_session_hook());
+    /// ```
+    pub fn set_async_session_hook(&mut self, hook: AsyncSessionHook) {
+        self.async_session_hook = Some(AsyncSessionHookHandle(hook));
+    }
+
+    /// Check whether an async session hook has been set
+    pub fn has_async_session_hook(&self) -> bool {
+        self.async_session_hook.is_some()
+    }
+
     /// Sets the access token for authenticated API requests
     ///
     /// This is typically called automatically by `generate_session`, but can
@@ -572,13 +1117,52 @@ impl KiteConnect {
     /// let mut client = KiteConnect::new("api_key", "");
     /// client.set_access_token("your_access_token");
     /// ```
+    ///
+    /// Clones of a client share one token behind an `Arc<RwLock<String>>`,
+    /// so setting it here also changes it for every clone, and any clone
+    /// renewing the session updates this one.
     pub fn set_access_token(&mut self, access_token: &str) {
-        self.access_token = access_token.to_string();
+        self.store_access_token(access_token);
     }
 
-    /// Gets the access token for this instance
-    pub fn access_token(&self) -> &str {
-        &self.access_token
+    /// Replace the shared access token without requiring `&mut self`
+    fn store_access_token(&self, access_token: &str) {
+        *self
+            .access_token
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = access_token.to_string();
+    }
+
+    /// Gets the current access token
+    ///
+    /// Returns an owned copy, since the token is shared with every clone of
+    /// this client and may be replaced at any time by
+    /// [`set_access_token`](Self::set_access_token) or a session renewal.
+    pub fn access_token(&self) -> String {
+        self.access_token
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Enable or disable lenient parsing of typed responses
+    ///
+    /// When enabled and a typed response fails strict deserialization, the
+    /// error is logged (with the `debug` feature) and the response is parsed
+    /// again with `null` fields falling back to their serde defaults, see
+    /// [`KiteError::from_json_value_lenient`]. This keeps calls working
+    /// through minor API drift at the cost of silently defaulted values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// let client = KiteConnect::new("api_key", "access_token").with_lenient_parsing(true);
+    /// ```
+    pub fn with_lenient_parsing(mut self, enabled: bool) -> Self {
+        self.lenient_parsing = enabled;
+        self
     }
 
     /// Internal helper method for parsing JSON responses to typed models
@@ -586,7 +1170,19 @@ impl KiteConnect {
     /// This method converts JsonValue responses from legacy API methods
     /// into strongly typed model structs for the new typed API methods.
     fn parse_response<T: DeserializeOwned>(&self, response: JsonValue) -> KiteResult<T> {
-        serde_json::from_value(response).map_err(KiteError::Json)
+        let parsed = match KiteError::from_json_value(&response) {
+            Err(_error) if self.lenient_parsing => {
+                #[cfg(feature = "debug")]
+                log::warn!("Strict parsing failed, retrying leniently: {}", _error);
+
+                KiteError::from_json_value_lenient(&response)
+            }
+            parsed => parsed,
+        };
+        if let Err(error) = &parsed {
+            self.metrics.record_error(error);
+        }
+        parsed
     }
 
     /// Determines if a request should be retried based on the error type
@@ -594,20 +1190,114 @@ impl KiteConnect {
         error.is_retryable()
     }
 
-    /// Calculates retry delay using exponential backoff or fixed delay
-    fn calculate_retry_delay(&self, attempt: u32) -> Duration {
-        if self.retry_config.exponential_backoff {
-            let delay = self.retry_config.base_delay * 2_u32.pow(attempt);
-            std::cmp::min(delay, self.retry_config.max_delay)
-        } else {
-            self.retry_config.base_delay
+    /// Delay before retrying after `error`
+    ///
+    /// Honours the server's `Retry-After` hint when present, capped at
+    /// `retry_config.max_delay`; otherwise falls back to the computed backoff.
+    fn retry_delay_for(&self, attempt: u32, error: &KiteError) -> Duration {
+        match error.retry_after() {
+            Some(hint) => hint.min(self.retry_config.max_delay),
+            None => self.calculate_retry_delay(attempt),
         }
     }
 
+    /// Calculates retry delay using exponential backoff or fixed delay, plus jitter
+    fn calculate_retry_delay(&self, attempt: u32) -> Duration {
+        self.retry_config.delay_for_attempt(attempt)
+    }
+
+    /// Request timeout applied to every HTTP request
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+
+    /// Maximum number of idle connections kept per host in the connection pool
+    pub fn max_idle_connections(&self) -> usize {
+        self.max_idle_connections
+    }
+
+    /// How long an idle pooled connection is kept alive before being closed
+    pub fn idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.idle_timeout)
+    }
+
+    /// Number of instruments sent per quote request
+    ///
+    /// Quote calls fail with `KiteError::InputException` if this is outside
+    /// `1..=MAX_QUOTE_BATCH_SIZE`.
+    pub fn quote_batch_size(&self) -> usize {
+        self.quote_batch_size
+    }
+
+    /// Most sub-requests `historical_data_typed` splits a single range into
+    pub fn historical_max_chunks(&self) -> usize {
+        self.historical_max_chunks
+    }
+
     /// Gets the current request count for monitoring
     pub fn request_count(&self) -> u64 {
-        self.request_counter
-            .load(std::sync::atomic::Ordering::Relaxed)
+        self.request_counter.load(Ordering::Relaxed)
+    }
+
+    /// Snapshot of the client's request, retry, cache and error counters
+    ///
+    /// Counters are shared between clones and accumulate for the lifetime of
+    /// the client.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    /// let _ = client.holdings_typed().await;
+    ///
+    /// let metrics = client.metrics_snapshot();
+    /// println!(
+    ///     "{} requests, {} retries, {} errors",
+    ///     metrics.total_requests,
+    ///     metrics.retries,
+    ///     metrics.total_errors()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn metrics_snapshot(&self) -> ClientMetrics {
+        self.metrics
+            .snapshot(self.request_count(), self.rate_limiter.wait_count())
+    }
+
+    /// Headers of the most recent API response
+    ///
+    /// Only recorded when `capture_response_headers` is enabled in
+    /// [`KiteConnectConfig`]; returns `None` otherwise or before the first
+    /// response. Useful for inspecting rate limit metadata sent by Kite.
+    pub fn last_response_headers(&self) -> Option<HeaderMap> {
+        self.last_response_headers
+            .lock()
+            .ok()
+            .and_then(|headers| headers.clone())
+    }
+
+    /// Record the headers of `response` if capturing is enabled
+    fn record_response_headers(&self, response: &reqwest::Response) {
+        if !self.capture_response_headers {
+            return;
+        }
+        if let Ok(mut headers) = self.last_response_headers.lock() {
+            *headers = Some(response.headers().clone());
+        }
+    }
+
+    /// Number of instrument rows skipped during the last `instruments_typed` fetch
+    ///
+    /// Rows are skipped when they have too few columns or a field fails to
+    /// parse. A sudden rise usually means Kite changed the CSV format, so
+    /// this is worth alerting on; `instruments_with_report` lists the rows.
+    pub fn instruments_parse_skipped_rows(&self) -> u64 {
+        self.instruments_skipped_rows.load(Ordering::Relaxed)
     }
 
     /// Get rate limiter statistics for monitoring
@@ -640,6 +1330,76 @@ impl KiteConnect {
         self.rate_limiter.wait_for_request(endpoint).await
     }
 
+    /// Shut the client down for graceful termination
+    ///
+    /// Cancels background tasks started from this client or any of its
+    /// clones (such as the token refresher), drops its cached responses and marks
+    /// the client unusable: every later API call on it or its clones fails
+    /// with [`KiteError::Shutdown`]. Calling this more than once is harmless.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    /// let _refresher = client.spawn_token_refresher(
+    ///     "refresh_token".to_string(),
+    ///     "api_secret".to_string(),
+    /// );
+    ///
+    /// // ... on SIGTERM ...
+    /// client.shutdown().await;
+    /// assert!(client.is_shut_down());
+    /// # }
+    /// ```
+    pub async fn shutdown(&self) {
+        self.shut_down.store(true, Ordering::SeqCst);
+
+        #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+        if let Ok(mut tasks) = self.background_tasks.lock() {
+            for task in tasks.drain(..) {
+                task.abort();
+            }
+        }
+
+        // Drop our handle only; a shared cache backend keeps its entries
+        if let Ok(mut cache) = self.response_cache.lock() {
+            cache.take();
+        }
+    }
+
+    /// Check if [`shutdown`](Self::shutdown) has been called
+    pub fn is_shut_down(&self) -> bool {
+        self.shut_down.load(Ordering::SeqCst)
+    }
+
+    /// Fail with [`KiteError::Shutdown`] once the client has been shut down
+    fn ensure_running(&self) -> KiteResult<()> {
+        if self.is_shut_down() {
+            Err(KiteError::Shutdown)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Register a background task to be aborted on shutdown
+    ///
+    /// A task registered after shutdown is aborted immediately.
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    pub(crate) fn track_background_task(&self, task: tokio::task::AbortHandle) {
+        if self.is_shut_down() {
+            task.abort();
+            return;
+        }
+        if let Ok(mut tasks) = self.background_tasks.lock() {
+            tasks.retain(|task| !task.is_finished());
+            tasks.push(task);
+        }
+    }
+
     /// Send request with rate limiting and retry logic
     async fn send_request_with_rate_limiting_and_retry(
         &self,
@@ -659,12 +1419,106 @@ impl KiteConnect {
             format!("{}/{}", config.path, path_segments.join("/"))
         };
 
-        let url = self.build_url(&full_path, query_params);
+        let url = self.build_endpoint_url(&endpoint, &full_path, query_params)?;
 
         // Use existing retry logic
-        self.send_request_with_retry(url, config.method.as_str(), data)
+        self.send_request_with_retry(url, config.method.as_str(), RequestBody::Form(data))
+            .await
+    }
+
+    /// Send request with rate limiting but without any automatic retries
+    ///
+    /// Used for calls that must never be submitted twice, such as exchanging a
+    /// single-use request token for a session.
+    async fn send_request_with_rate_limiting_once(
+        &self,
+        endpoint: KiteEndpoint,
+        path_segments: &[&str],
+        query_params: Option<Vec<(&str, &str)>>,
+        data: Option<HashMap<&str, &str>>,
+    ) -> KiteResult<reqwest::Response> {
+        self.rate_limiter.wait_for_request(&endpoint).await;
+
+        let config = endpoint.config();
+        let full_path = if path_segments.is_empty() {
+            config.path.to_string()
+        } else {
+            format!("{}/{}", config.path, path_segments.join("/"))
+        };
+
+        let url = self.build_endpoint_url(&endpoint, &full_path, query_params)?;
+
+        self.send_request_with_retry_limit(url, config.method.as_str(), RequestBody::Form(data), 0)
             .await
     }
+
+    /// Send a JSON body with rate limiting and retry logic
+    ///
+    /// Used by endpoints such as margin calculation that take a JSON array
+    /// rather than form fields. Retries, the async session hook and tracing
+    /// apply as for form requests.
+    async fn send_json_request_with_rate_limiting(
+        &self,
+        endpoint: KiteEndpoint,
+        query_params: Option<Vec<(&str, &str)>>,
+        body: &JsonValue,
+    ) -> KiteResult<reqwest::Response> {
+        self.rate_limiter.wait_for_request(&endpoint).await;
+
+        let config = endpoint.config();
+        let url = self.build_endpoint_url(&endpoint, config.path, query_params)?;
+        self.send_request_with_retry(url, config.method.as_str(), RequestBody::Json(body))
+            .await
+    }
+
+    /// POST a JSON body once
+    async fn send_json_request(
+        &self,
+        url: reqwest::Url,
+        body: &JsonValue,
+    ) -> Result<reqwest::Response> {
+        self.ensure_running()?;
+
+        #[cfg(feature = "debug")]
+        log::debug!("Sending POST request with JSON body to: {}", url);
+
+        let response = self
+            .client
+            .post(url)
+            .headers(self.request_headers())
+            .json(body)
+            .send()
+            .await?;
+        self.record_response_headers(&response);
+        Ok(response)
+    }
+
+    /// Headers sent with every authenticated request
+    fn request_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("XKiteVersion", "3".parse().unwrap());
+        headers.insert(
+            AUTHORIZATION,
+            format!("token {}:{}", self.api_key, self.access_token())
+                .parse()
+                .unwrap(),
+        );
+        headers.insert(USER_AGENT, "Rust".parse().unwrap());
+        headers
+    }
+}
+
+/// Parse a `Retry-After` header given either as delta-seconds or an HTTP date
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = at.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
 }
 
 /// Implement the async request handler for KiteConnect struct
@@ -675,21 +1529,15 @@ impl RequestHandler for KiteConnect {
         method: &str,
         data: Option<HashMap<&str, &str>>,
     ) -> Result<reqwest::Response> {
+        self.ensure_running()?;
+
         #[cfg(feature = "debug")]
         log::debug!("Sending {} request to: {}", method, url);
 
         #[cfg(all(feature = "debug", feature = "wasm", target_arch = "wasm32"))]
         console::log_1(&format!("KiteConnect: {} {}", method, url).into());
 
-        let mut headers = HeaderMap::new();
-        headers.insert("XKiteVersion", "3".parse().unwrap());
-        headers.insert(
-            AUTHORIZATION,
-            format!("token {}:{}", self.api_key, self.access_token)
-                .parse()
-                .unwrap(),
-        );
-        headers.insert(USER_AGENT, "Rust".parse().unwrap());
+        let headers = self.request_headers();
 
         let response = match method {
             "GET" => self.client.get(url).headers(headers).send().await?,
@@ -723,6 +1571,8 @@ impl RequestHandler for KiteConnect {
         #[cfg(feature = "debug")]
         log::debug!("Response status: {}", response.status());
 
+        self.record_response_headers(&response);
+
         Ok(response)
     }
 }
@@ -734,14 +1584,79 @@ mod tests {
     #[tokio::test]
     async fn test_build_url() {
         let kiteconnect = KiteConnect::new("key", "token");
-        let url = kiteconnect.build_url("/my-holdings", None);
+        let url = kiteconnect.build_url("/my-holdings", None).unwrap();
         assert_eq!(url.as_str(), format!("{}/my-holdings", URL).as_str());
 
         let params: Vec<(&str, &str)> = vec![("one", "1")];
-        let url = kiteconnect.build_url("/my-holdings", Some(params));
+        let url = kiteconnect.build_url("/my-holdings", Some(params)).unwrap();
         assert_eq!(url.as_str(), format!("{}/my-holdings?one=1", URL).as_str());
     }
 
+    #[test]
+    fn test_new_leaves_response_cache_off() {
+        let client = KiteConnect::new("key", "token");
+        assert!(client.response_cache.lock().unwrap().is_none());
+
+        let client = KiteConnect::new_with_config("key", KiteConnectConfig::default());
+        assert!(client.response_cache.lock().unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_build_url_rejects_invalid_base_url() {
+        let config = KiteConnectConfig {
+            base_url: "not a url".to_string(),
+            ..Default::default()
+        };
+        let kiteconnect = KiteConnect::new_with_config("key", config);
+
+        let err = kiteconnect.build_url("/my-holdings", None).unwrap_err();
+        assert!(matches!(err, KiteError::InvalidParameter(_)));
+        assert!(err.to_string().contains("Invalid base URL"));
+
+        // Surfaces through the request path instead of panicking
+        let err = kiteconnect.holdings_typed().await.unwrap_err();
+        assert!(matches!(err, KiteError::InvalidParameter(_)));
+    }
+
+    #[test]
+    fn test_retry_delay_jitter() {
+        let config = RetryConfig {
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: JitterMode::None,
+            ..Default::default()
+        };
+        assert_eq!(config.delay_for_attempt(2), Duration::from_millis(800));
+        assert_eq!(config.delay_for_attempt(40), Duration::from_secs(5));
+
+        let full = RetryConfig {
+            jitter: JitterMode::Full,
+            jitter_rng: JitterRng::fixed(0.25),
+            ..config.clone()
+        };
+        assert_eq!(full.delay_for_attempt(2), Duration::from_millis(200));
+
+        let equal = RetryConfig {
+            jitter: JitterMode::Equal,
+            jitter_rng: JitterRng::fixed(0.0),
+            ..config.clone()
+        };
+        assert_eq!(equal.delay_for_attempt(2), Duration::from_millis(400));
+        let equal = RetryConfig {
+            jitter_rng: JitterRng::fixed(1.0),
+            ..equal
+        };
+        assert_eq!(equal.delay_for_attempt(2), Duration::from_millis(800));
+
+        // Default jitter stays within [delay / 2, delay]
+        let default = RetryConfig::default();
+        assert_eq!(default.jitter, JitterMode::Equal);
+        for _ in 0..100 {
+            let delay = default.delay_for_attempt(1);
+            assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+        }
+    }
+
     #[tokio::test]
     async fn test_set_access_token() {
         let mut kiteconnect = KiteConnect::new("key", "token");
@@ -753,14 +1668,14 @@ mod tests {
     #[tokio::test]
     async fn test_session_expiry_hook() {
         let mut kiteconnect = KiteConnect::new("key", "token");
-        assert_eq!(kiteconnect.session_expiry_hook(), None);
+        assert!(kiteconnect.session_expiry_hook().is_none());
 
         fn mock_hook() {
             println!("Session expired");
         }
 
         kiteconnect.set_session_expiry_hook(mock_hook);
-        assert_ne!(kiteconnect.session_expiry_hook(), None);
+        assert!(kiteconnect.session_expiry_hook().is_some());
     }
 
     #[tokio::test]
@@ -772,6 +1687,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_login_url_with_params() {
+        let kiteconnect = KiteConnect::new("key", "token");
+        assert_eq!(
+            kiteconnect.login_url_with_params(&[]),
+            kiteconnect.login_url()
+        );
+        assert_eq!(
+            kiteconnect.login_url_with_params(&[("state", "x&y=z"), ("next", "/orders")]),
+            "https://kite.trade/connect/login?api_key=key&v3\
+             &redirect_params=state%3Dx%2526y%253Dz%26next%3D%252Forders"
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, "7".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(7)));
+
+        // Dates in the past mean "retry now"
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(parse_retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_retry_delay_prefers_retry_after() {
+        let config = KiteConnectConfig {
+            retry_config: RetryConfig {
+                base_delay: Duration::from_millis(200),
+                max_delay: Duration::from_secs(5),
+                jitter: JitterMode::None,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let kiteconnect = KiteConnect::new_with_config("key", config);
+
+        let throttled = |secs| KiteError::RateLimited {
+            message: "Too many requests".to_string(),
+            retry_after: Some(Duration::from_secs(secs)),
+        };
+        // Shorter than the backoff for this attempt: still wins
+        assert_eq!(
+            kiteconnect.retry_delay_for(3, &throttled(1)),
+            Duration::from_secs(1)
+        );
+        // Capped at max_delay
+        assert_eq!(
+            kiteconnect.retry_delay_for(0, &throttled(60)),
+            Duration::from_secs(5)
+        );
+
+        let without_hint = KiteError::RateLimited {
+            message: "Too many requests".to_string(),
+            retry_after: None,
+        };
+        assert_eq!(
+            kiteconnect.retry_delay_for(1, &without_hint),
+            Duration::from_millis(400)
+        );
+    }
+
     // Test implementations for the various modules can be added here
     // For now, keeping it minimal to focus on the module structure
 }
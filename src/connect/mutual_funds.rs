@@ -487,9 +487,10 @@ use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 
 // Import typed models for dual API support
-use crate::models::common::KiteResult;
+use crate::models::common::{KiteError, KiteResult};
 use crate::models::mutual_funds::{
-    MFHolding, MFOrder, MFOrderParams, MFOrderResponse, SIPParams, SIPResponse, SIP,
+    MFHolding, MFOrder, MFOrderParams, MFOrderResponse, SIPModifyParams, SIPParams, SIPResponse,
+    SIP,
 };
 
 use crate::connect::KiteConnect;
@@ -498,6 +499,7 @@ impl KiteConnect {
     // === LEGACY API METHODS (JSON responses) ===
 
     /// Get all mutual fund orders or individual order info
+    #[cfg(feature = "legacy")]
     pub async fn mf_orders(&self, order_id: Option<&str>) -> Result<JsonValue> {
         let resp = if let Some(order_id) = order_id {
             self.send_request_with_rate_limiting_and_retry(
@@ -560,6 +562,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn place_mf_order(
         &self,
         tradingsymbol: &str,
@@ -672,6 +675,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn mf_sips(&self, sip_id: Option<&str>) -> Result<JsonValue> {
         let resp = if let Some(sip_id) = sip_id {
             self.send_request_with_rate_limiting_and_retry(
@@ -730,6 +734,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     #[allow(clippy::too_many_arguments)]
     pub async fn place_mf_sip(
         &self,
@@ -916,6 +921,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn mf_holdings(&self) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(KiteEndpoint::MFHoldings, &[], None, None)
@@ -961,18 +967,14 @@ impl KiteConnect {
     /// # }
     /// ```
     pub async fn mf_orders_typed(&self, order_id: Option<&str>) -> KiteResult<Vec<MFOrder>> {
-        let resp = if let Some(order_id) = order_id {
-            self.send_request_with_rate_limiting_and_retry(
-                KiteEndpoint::MFOrderInfo,
-                &[order_id],
-                None,
-                None,
-            )
-            .await?
-        } else {
-            self.send_request_with_rate_limiting_and_retry(KiteEndpoint::MFOrders, &[], None, None)
-                .await?
-        };
+        // A single order comes back as an object rather than a list
+        if let Some(order_id) = order_id {
+            return Ok(vec![self.mf_order_typed(order_id).await?]);
+        }
+
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(KiteEndpoint::MFOrders, &[], None, None)
+            .await?;
 
         let json_response = self.raise_or_return_json_typed(resp).await?;
 
@@ -1013,6 +1015,12 @@ impl KiteConnect {
     ///
     /// Places a mutual fund order and returns typed response.
     ///
+    /// The parameters are checked with [`MFOrderParams::validate`] before
+    /// anything is sent: purchases need an amount or a quantity, redemptions
+    /// need a quantity, and the two are mutually exclusive. Fund minimums are
+    /// not known here; check them with [`MFOrderParams::validate_for`] using
+    /// the fund from `mf_instruments_typed()`.
+    ///
     /// # Arguments
     ///
     /// * `order_params` - Typed order parameters
@@ -1021,6 +1029,11 @@ impl KiteConnect {
     ///
     /// A `KiteResult<MFOrderResponse>` containing order confirmation
     ///
+    /// # Errors
+    ///
+    /// Returns `KiteError::InvalidParameter` if the parameters break the
+    /// rules above.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -1049,6 +1062,10 @@ impl KiteConnect {
         &self,
         order_params: &MFOrderParams,
     ) -> KiteResult<MFOrderResponse> {
+        order_params
+            .validate()
+            .map_err(KiteError::InvalidParameter)?;
+
         // Create all string conversions upfront to avoid lifetime issues
         let transaction_type_str = order_params.transaction_type.to_string();
         let amount_str = order_params.amount.map(|a| a.to_string());
@@ -1114,18 +1131,14 @@ impl KiteConnect {
     /// # }
     /// ```
     pub async fn mf_sips_typed(&self, sip_id: Option<&str>) -> KiteResult<Vec<SIP>> {
-        let resp = if let Some(sip_id) = sip_id {
-            self.send_request_with_rate_limiting_and_retry(
-                KiteEndpoint::SIPInfo,
-                &[sip_id],
-                None,
-                None,
-            )
-            .await?
-        } else {
-            self.send_request_with_rate_limiting_and_retry(KiteEndpoint::SIPs, &[], None, None)
-                .await?
-        };
+        // A single SIP comes back as an object rather than a list
+        if let Some(sip_id) = sip_id {
+            return Ok(vec![self.mf_sip_typed(sip_id).await?]);
+        }
+
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(KiteEndpoint::SIPs, &[], None, None)
+            .await?;
 
         let json_response = self.raise_or_return_json_typed(resp).await?;
 
@@ -1173,20 +1186,18 @@ impl KiteConnect {
     ///
     /// ```rust,no_run
     /// use kiteconnect_async_wasm::connect::KiteConnect;
-    /// use kiteconnect_async_wasm::models::mutual_funds::{SIPParams, SIPFrequency};
+    /// use kiteconnect_async_wasm::models::mutual_funds::{SIPParams, SIPStepUp};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = KiteConnect::new("api_key", "access_token");
     ///
-    /// let sip_params = SIPParams {
-    ///     trading_symbol: "INF846K01DP8".to_string(),
-    ///     amount: 1000.0,
-    ///     instalments: Some(12),
-    ///     frequency: SIPFrequency::Monthly,
-    ///     initial_amount: Some(5000.0),
-    ///     tag: Some("retirement_sip".to_string()),
-    /// };
+    /// let sip_params = SIPParams::monthly("INF846K01DP8".to_string(), 1000.0)
+    ///     .instalments(12)
+    ///     .initial_amount(5000.0)
+    ///     .instalment_day(15)
+    ///     .step_up(SIPStepUp::new("15-02", 10.0))
+    ///     .tag("retirement_sip");
     ///
     /// let response = client.place_mf_sip_typed(&sip_params).await?;
     /// println!("SIP created with ID: {}", response.sip_id);
@@ -1194,11 +1205,15 @@ impl KiteConnect {
     /// # }
     /// ```
     pub async fn place_mf_sip_typed(&self, sip_params: &SIPParams) -> KiteResult<SIPResponse> {
+        sip_params.validate().map_err(KiteError::InvalidParameter)?;
+
         // Create all string conversions upfront to avoid lifetime issues
         let amount_str = sip_params.amount.to_string();
         let instalments_str = sip_params.instalments.map(|i| i.to_string());
         let frequency_str = sip_params.frequency.to_string(); // Convert enum to string using Display trait
         let initial_amount_str = sip_params.initial_amount.map(|a| a.to_string());
+        let instalment_day_str = sip_params.instalment_day.map(|d| d.to_string());
+        let step_up_str = sip_params.step_up.as_ref().map(|s| s.to_string());
 
         let mut params = HashMap::new();
         params.insert("tradingsymbol", sip_params.trading_symbol.as_str());
@@ -1211,6 +1226,12 @@ impl KiteConnect {
         if let Some(ref initial_amount) = initial_amount_str {
             params.insert("initial_amount", initial_amount.as_str());
         }
+        if let Some(ref instalment_day) = instalment_day_str {
+            params.insert("instalment_day", instalment_day.as_str());
+        }
+        if let Some(ref step_up) = step_up_str {
+            params.insert("step_up", step_up.as_str());
+        }
         if let Some(ref tag) = sip_params.tag {
             params.insert("tag", tag.as_str());
         }
@@ -1231,6 +1252,87 @@ impl KiteConnect {
         self.parse_response(data)
     }
 
+    /// Modify a mutual fund SIP with typed parameters
+    ///
+    /// Only the fields set on `modify_params` are sent. The parameters are
+    /// checked with [`SIPModifyParams::validate`] first; when the frequency is
+    /// not being changed an instalment day must fall within 1-28.
+    ///
+    /// # Arguments
+    ///
+    /// * `modify_params` - SIP to modify (`sip_id`) and the fields to change
+    ///
+    /// # Returns
+    ///
+    /// A `KiteResult<SIPResponse>` containing the modified SIP's ID
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::mutual_funds::{SIPModifyParams, SIPStatus, SIPStepUp};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let changes = SIPModifyParams::new("123456")
+    ///     .amount(1500.0)
+    ///     .instalment_day(20)
+    ///     .step_up(SIPStepUp::new("01-04", 10.0))
+    ///     .status(SIPStatus::Active);
+    ///
+    /// let response = client.modify_mf_sip_typed(&changes).await?;
+    /// println!("Modified SIP {}", response.sip_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn modify_mf_sip_typed(
+        &self,
+        modify_params: &SIPModifyParams,
+    ) -> KiteResult<SIPResponse> {
+        modify_params
+            .validate(None)
+            .map_err(KiteError::InvalidParameter)?;
+
+        let amount_str = modify_params.amount.map(|a| a.to_string());
+        let frequency_str = modify_params.frequency.as_ref().map(|f| f.to_string());
+        let instalments_str = modify_params.instalments.map(|i| i.to_string());
+        let instalment_day_str = modify_params.instalment_day.map(|d| d.to_string());
+        let step_up_str = modify_params.step_up.as_ref().map(|s| s.to_string());
+        let status_str = modify_params.status.as_ref().map(|s| s.to_string());
+
+        let mut params = HashMap::new();
+        let fields = [
+            ("amount", &amount_str),
+            ("frequency", &frequency_str),
+            ("instalments", &instalments_str),
+            ("instalment_day", &instalment_day_str),
+            ("step_up", &step_up_str),
+            ("status", &status_str),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                params.insert(key, value.as_str());
+            }
+        }
+
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::ModifySIP,
+                &[&modify_params.sip_id],
+                None,
+                Some(params),
+            )
+            .await?;
+
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
     /// Get mutual fund holdings with typed response
     ///
     /// Returns strongly typed MF holdings data instead of JsonValue.
@@ -39,11 +39,14 @@
 //! - [`place_order()`](KiteConnect::place_order) / [`place_order_typed()`](KiteConnect::place_order_typed) - Place new orders
 //! - [`modify_order()`](KiteConnect::modify_order) - Modify existing orders
 //! - [`cancel_order()`](KiteConnect::cancel_order) - Cancel pending orders
+//! - [`cancel_all_orders()`](KiteConnect::cancel_all_orders) - Cancel every pending order
 //!
 //! ### Order Information
 //! - [`orders()`](KiteConnect::orders) / [`orders_typed()`](KiteConnect::orders_typed) - Get all orders
 //! - [`order_history()`](KiteConnect::order_history) - Get order execution history
 //! - [`trades()`](KiteConnect::trades) / [`trades_typed()`](KiteConnect::trades_typed) - Get trade book
+//! - [`order_margins()`](KiteConnect::order_margins) - Margin required for a basket of orders
+//! - [`basket_margins()`](KiteConnect::basket_margins) - Combined basket margin after hedge offsets
 //!
 //! ### Position Management
 //! - [`convert_position()`](KiteConnect::convert_position) - Convert product types
@@ -81,9 +84,11 @@
 //!     iceberg_legs: None,
 //!     iceberg_quantity: None,
 //!     auction_number: None,
+//!     variety: None,
+//!     validity_ttl: None,
 //! };
 //!
-//! let response = client.place_order_typed("regular", &order_params).await?;
+//! let response = client.place_order_typed(&order_params).await?;
 //! println!("🎯 Order placed successfully!");
 //! println!("   Order ID: {}", response.order_id);
 //! # Ok(())
@@ -112,7 +117,7 @@
 //!     .tag("QuickBuy")
 //!     .build()?;
 //!
-//! let response = client.place_order_typed("regular", &order).await?;
+//! let response = client.place_order_typed(&order).await?;
 //! println!("🚀 Market order executed!");
 //! println!("   Order ID: {}", response.order_id);
 //! # Ok(())
@@ -122,7 +127,7 @@
 //! ### Advanced Order Types
 //! ```rust,no_run
 //! use kiteconnect_async_wasm::connect::KiteConnect;
-//! use kiteconnect_async_wasm::models::orders::{OrderBuilder, BracketOrderBuilder};
+//! use kiteconnect_async_wasm::models::orders::OrderBuilder;
 //! use kiteconnect_async_wasm::models::common::{Exchange, TransactionType, OrderType, Product};
 //!
 //! # #[tokio::main]
@@ -141,24 +146,9 @@
 //!     .trigger_price(1440.0)
 //!     .build()?;
 //!
-//! let sl_response = client.place_order_typed("regular", &sl_order).await?;
+//! let sl_response = client.place_order_typed(&sl_order).await?;
 //! println!("🛡️ Stop-loss order placed: {}", sl_response.order_id);
 //!
-//! // Bracket Order (Buy with auto profit booking and stop-loss)
-//! let bracket_order = BracketOrderBuilder::new()
-//!     .trading_symbol("HDFC")
-//!     .exchange(Exchange::NSE)
-//!     .transaction_type(TransactionType::BUY)
-//!     .quantity(10)
-//!     .price(1600.0)
-//!     .squareoff(1650.0)    // Take profit at +50
-//!     .stoploss(1580.0)     // Stop loss at -20
-//!     .trailing_stoploss(5.0) // Trail by 5 points
-//!     .build()?;
-//!
-//! let bo_response = client.place_order_typed("bo", &bracket_order.order_params).await?;
-//! println!("🎯 Bracket order placed: {}", bo_response.order_id);
-//!
 //! // Iceberg Order (Large order in small chunks)
 //! let iceberg_order = OrderBuilder::new()
 //!     .trading_symbol("SBIN")
@@ -171,7 +161,7 @@
 //!     .iceberg(10, 100)      // 10 legs of 100 shares each
 //!     .build()?;
 //!
-//! let iceberg_response = client.place_order_typed("iceberg", &iceberg_order).await?;
+//! let iceberg_response = client.place_order_typed(&iceberg_order).await?;
 //! println!("🧊 Iceberg order placed: {}", iceberg_response.order_id);
 //! # Ok(())
 //! # }
@@ -352,9 +342,9 @@
 //! #     price: None, validity: None, disclosed_quantity: None, trigger_price: None,
 //! #     tag: None, squareoff: None, stoploss: None, trailing_stoploss: None,
 //! #     market_protection: None, iceberg_legs: None, iceberg_quantity: None,
-//! #     auction_number: None,
+//! #     auction_number: None, variety: None, validity_ttl: None,
 //! # };
-//! match client.place_order_typed("regular", &order_params).await {
+//! match client.place_order_typed(&order_params).await {
 //!     Ok(response) => {
 //!         println!("✅ Order placed: {}", response.order_id);
 //!     }
@@ -362,11 +352,11 @@
 //!         eprintln!("🔐 Authentication failed: {}", msg);
 //!         // Handle re-authentication
 //!     }
+//!     Err(KiteError::RateLimited { retry_after, .. }) => {
+//!         eprintln!("⏱️ Rate limited - retry after {:?}", retry_after);
+//!     }
 //!     Err(KiteError::Api { status, message, .. }) => {
 //!         eprintln!("🚫 Order rejected: {} - {}", status, message);
-//!         if status == "429" {
-//!             eprintln!("⏱️ Rate limited - please wait before retrying");
-//!         }
 //!         // Handle order rejection (insufficient margin, invalid params, etc.)
 //!     }
 //!     Err(e) => eprintln!("❌ Other error: {}", e),
@@ -382,7 +372,7 @@
 //! use kiteconnect_async_wasm::models::orders::OrderBuilder;
 //! use kiteconnect_async_wasm::models::common::{Exchange, TransactionType, OrderType, Product};
 //!
-//! # fn example() -> Result<(), String> {
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! // This will fail validation - no price for LIMIT order
 //! let invalid_order = OrderBuilder::new()
 //!     .trading_symbol("RELIANCE")
@@ -391,7 +381,7 @@
 //!     .quantity(10)
 //!     .order_type(OrderType::LIMIT)  // LIMIT order requires price
 //!     .product(Product::CNC)
-//!     .build(); // This returns Err("Price is required for LIMIT orders")
+//!     .build(); // Err(KiteError::InputException("Price is required for LIMIT orders"))
 //!
 //! match invalid_order {
 //!     Ok(_) => println!("Order validated successfully"),
@@ -464,19 +454,30 @@
 
 use crate::connect::endpoints::KiteEndpoint;
 use anyhow::Result;
+use chrono::NaiveTime;
 use serde_json::Value as JsonValue;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 // Import typed models for dual API support
-use crate::models::common::KiteResult;
-use crate::models::orders::{Order, OrderParams, OrderResponse, Trade};
+use crate::models::common::timestamps::IST;
+use crate::models::common::{KiteError, KiteResult, Variety};
+use crate::models::market_data::Instrument;
+use crate::models::orders::{
+    BasketMargins, Order, OrderCancellation, OrderHistoryEntry, OrderMarginParam, OrderMargins,
+    OrderParams, OrderResponse, Trade,
+};
 
 use crate::connect::KiteConnect;
 
+/// Delay between order status checks made by [`KiteConnect::wait_for_order`]
+pub const DEFAULT_ORDER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 impl KiteConnect {
     // === LEGACY API METHODS (JSON responses) ===
 
     /// Place an order
+    #[cfg(feature = "legacy")]
     #[allow(clippy::too_many_arguments)]
     pub async fn place_order(
         &self,
@@ -678,6 +679,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn orders(&self) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(KiteEndpoint::Orders, &[], None, None)
@@ -702,6 +704,7 @@ impl KiteConnect {
     }
 
     /// Get all trades
+    #[cfg(feature = "legacy")]
     pub async fn trades(&self) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(KiteEndpoint::Trades, &[], None, None)
@@ -711,6 +714,7 @@ impl KiteConnect {
     }
 
     /// Get all trades for a specific order
+    #[cfg(feature = "legacy")]
     pub async fn order_trades(&self, order_id: &str) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(
@@ -725,6 +729,7 @@ impl KiteConnect {
     }
 
     /// Modify an open position product type
+    #[cfg(feature = "legacy")]
     #[allow(clippy::too_many_arguments)]
     pub async fn convert_position(
         &self,
@@ -764,9 +769,13 @@ impl KiteConnect {
     /// Returns strongly typed order response instead of JsonValue.
     /// This is the preferred method for new applications.
     ///
+    /// The order goes to the variety set on the builder (e.g. with
+    /// [`OrderBuilder::amo`](crate::models::orders::OrderBuilder::amo) or
+    /// [`OrderBuilder::iceberg`](crate::models::orders::OrderBuilder::iceberg)),
+    /// and to `regular` when none was set.
+    ///
     /// # Arguments
     ///
-    /// * `variety` - Order variety ("regular", "bo", "co", etc.)
     /// * `order_params` - Typed order parameters struct
     ///
     /// # Returns
@@ -777,85 +786,42 @@ impl KiteConnect {
     ///
     /// ```rust,no_run
     /// use kiteconnect_async_wasm::connect::KiteConnect;
-    /// use kiteconnect_async_wasm::models::orders::OrderParams;
-    /// use kiteconnect_async_wasm::models::common::{Exchange, Product, OrderType, TransactionType, Validity};
+    /// use kiteconnect_async_wasm::models::orders::OrderBuilder;
+    /// use kiteconnect_async_wasm::models::common::{Exchange, Product, TransactionType};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = KiteConnect::new("api_key", "access_token");
     ///
-    /// let params = OrderParams {
-    ///     trading_symbol: "INFY".to_string(),
-    ///     exchange: Exchange::NSE,
-    ///     transaction_type: TransactionType::BUY,
-    ///     quantity: 1,
-    ///     order_type: OrderType::LIMIT,
-    ///     product: Product::CNC,
-    ///     price: Some(1500.0),
-    ///     validity: Some(Validity::DAY),
-    ///     disclosed_quantity: None,
-    ///     trigger_price: None,
-    ///     squareoff: None,
-    ///     stoploss: None,
-    ///     trailing_stoploss: None,
-    ///     market_protection: None,
-    ///     iceberg_legs: None,
-    ///     iceberg_quantity: None,
-    ///     auction_number: None,
-    ///     tag: None,
-    /// };
+    /// let params = OrderBuilder::new()
+    ///     .trading_symbol("INFY")
+    ///     .exchange(Exchange::NSE)
+    ///     .transaction_type(TransactionType::BUY)
+    ///     .product(Product::CNC)
+    ///     .quantity(1)
+    ///     .price(1500.0)
+    ///     .build()?;
     ///
-    /// let order_response = client.place_order_typed("regular", &params).await?;
+    /// let order_response = client.place_order_typed(&params).await?;
     /// println!("Order ID: {}", order_response.order_id);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn place_order_typed(
-        &self,
-        variety: &str,
-        order_params: &OrderParams,
-    ) -> KiteResult<OrderResponse> {
-        // Create all string conversions upfront to avoid lifetime issues
-        let exchange_str = order_params.exchange.to_string();
-        let transaction_type_str = order_params.transaction_type.to_string();
-        let quantity_str = order_params.quantity.to_string();
-        let product_str = order_params.product.to_string();
-        let order_type_str = order_params.order_type.to_string();
-
-        let price_str = order_params.price.map(|p| p.to_string());
-        let validity_str = order_params.validity.as_ref().map(|v| v.to_string());
-        let disclosed_str = order_params.disclosed_quantity.map(|d| d.to_string());
-        let trigger_str = order_params.trigger_price.map(|t| t.to_string());
+    pub async fn place_order_typed(&self, order_params: &OrderParams) -> KiteResult<OrderResponse> {
+        let variety = order_params.variety.unwrap_or(Variety::Regular).to_string();
 
-        let mut params = HashMap::new();
-        params.insert("variety", variety);
-        params.insert("exchange", exchange_str.as_str());
-        params.insert("tradingsymbol", order_params.trading_symbol.as_str());
-        params.insert("transaction_type", transaction_type_str.as_str());
-        params.insert("quantity", quantity_str.as_str());
-        params.insert("product", product_str.as_str());
-        params.insert("order_type", order_type_str.as_str());
-
-        if let Some(ref price) = price_str {
-            params.insert("price", price.as_str());
-        }
-        if let Some(ref validity) = validity_str {
-            params.insert("validity", validity.as_str());
-        }
-        if let Some(ref disclosed) = disclosed_str {
-            params.insert("disclosed_quantity", disclosed.as_str());
-        }
-        if let Some(ref trigger) = trigger_str {
-            params.insert("trigger_price", trigger.as_str());
-        }
-        if let Some(ref tag) = order_params.tag {
-            params.insert("tag", tag.as_str());
-        }
+        // Every set field becomes a form field, enums as their API strings
+        let form = order_form_fields(order_params);
+        let mut params: HashMap<&str, &str> = form
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect();
+        params.insert("variety", &variety);
 
         let resp = self
             .send_request_with_rate_limiting_and_retry(
                 KiteEndpoint::PlaceOrder,
-                &[variety],
+                &[&variety],
                 None,
                 Some(params),
             )
@@ -867,6 +833,56 @@ impl KiteConnect {
         self.parse_response(data)
     }
 
+    /// Place an order with prices snapped to the instrument's tick size
+    ///
+    /// Same as [`place_order_typed`](Self::place_order_typed), but `price`
+    /// and `trigger_price` are first rounded to the nearest valid tick of
+    /// `instrument`, so e.g. `100.03` goes out as `100.05` for a 0.05 tick.
+    /// Use this when the instrument is known and computed prices may not be
+    /// tick-aligned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::{Exchange, TransactionType};
+    /// use kiteconnect_async_wasm::models::orders::OrderBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let instruments = client.instruments_typed(Some(Exchange::NSE)).await?;
+    /// let infy = instruments
+    ///     .iter()
+    ///     .find(|i| i.trading_symbol == "INFY")
+    ///     .expect("INFY is listed");
+    ///
+    /// let params = OrderBuilder::new()
+    ///     .trading_symbol("INFY")
+    ///     .exchange(Exchange::NSE)
+    ///     .transaction_type(TransactionType::BUY)
+    ///     .quantity(1)
+    ///     .price(1500.03)
+    ///     .build()?;
+    ///
+    /// let response = client
+    ///     .place_order_typed_for_instrument(&params, infy)
+    ///     .await?;
+    /// println!("Order ID: {}", response.order_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn place_order_typed_for_instrument(
+        &self,
+        order_params: &OrderParams,
+        instrument: &Instrument,
+    ) -> KiteResult<OrderResponse> {
+        let mut order_params = order_params.clone();
+        order_params.snap_to_tick(instrument);
+        self.place_order_typed(&order_params).await
+    }
+
     /// Get all orders with typed response
     ///
     /// Returns strongly typed list of orders instead of JsonValue.
@@ -905,6 +921,97 @@ impl KiteConnect {
         self.parse_response(data)
     }
 
+    /// Cancel every pending order
+    ///
+    /// Fetches the order book and cancels each order that is `OPEN` or
+    /// `TRIGGER PENDING`, one at a time through the order rate limit. Legs of
+    /// a cover order are sent with their `parent_order_id`; a leg whose
+    /// parent is itself being cancelled is skipped, since cancelling the
+    /// parent removes it.
+    ///
+    /// One [`OrderCancellation`] is returned per cancellation attempted. A
+    /// failed cancellation does not stop the rest; it is reported with
+    /// `error` set instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// for result in client.cancel_all_orders().await? {
+    ///     match result.error {
+    ///         None => println!("Cancelled {}", result.order_id),
+    ///         Some(error) => println!("Could not cancel {}: {}", result.order_id, error),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel_all_orders(&self) -> KiteResult<Vec<OrderCancellation>> {
+        let orders = self.orders_typed().await?;
+        let pending: Vec<&Order> = orders.iter().filter(|o| o.is_cancellable()).collect();
+        let pending_ids: HashSet<&str> = pending.iter().map(|o| o.order_id.as_str()).collect();
+
+        let mut results = Vec::with_capacity(pending.len());
+        for order in pending {
+            let parent_order_id = order
+                .parent_order_id
+                .as_deref()
+                .filter(|parent| !parent.is_empty());
+            if parent_order_id.is_some_and(|parent| pending_ids.contains(parent)) {
+                continue;
+            }
+
+            let variety = order.variety.as_deref().unwrap_or("regular");
+            let result = match self
+                .cancel_order_typed(&order.order_id, variety, parent_order_id)
+                .await
+            {
+                Ok(cancellation) => cancellation,
+                Err(e) => OrderCancellation {
+                    order_id: order.order_id.clone(),
+                    error: Some(e.to_string()),
+                },
+            };
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    /// Cancel a single order, returning the typed cancellation response
+    async fn cancel_order_typed(
+        &self,
+        order_id: &str,
+        variety: &str,
+        parent_order_id: Option<&str>,
+    ) -> KiteResult<OrderCancellation> {
+        let mut params = HashMap::new();
+        params.insert("order_id", order_id);
+        params.insert("variety", variety);
+        if let Some(parent_order_id) = parent_order_id {
+            params.insert("parent_order_id", parent_order_id);
+        }
+
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::CancelOrder,
+                &[variety, order_id],
+                None,
+                Some(params),
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
     /// Get all trades with typed response
     ///
     /// Returns strongly typed list of trades instead of JsonValue.
@@ -943,6 +1050,51 @@ impl KiteConnect {
         self.parse_response(data)
     }
 
+    /// Get today's trades filled within a time window
+    ///
+    /// Filters [`trades_typed`](Self::trades_typed) by `fill_timestamp`,
+    /// comparing against IST wall-clock times since that is how the trading
+    /// session is scheduled. The window is half-open: trades at `from` are
+    /// included, trades at `to` are not.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Start of the window (IST)
+    /// * `to` - End of the window (IST)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use chrono::NaiveTime;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// // Trades from the open until lunch
+    /// let morning = client
+    ///     .trades_between(
+    ///         NaiveTime::from_hms_opt(9, 15, 0).unwrap(),
+    ///         NaiveTime::from_hms_opt(12, 30, 0).unwrap(),
+    ///     )
+    ///     .await?;
+    /// println!("{} pre-lunch trades", morning.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn trades_between(&self, from: NaiveTime, to: NaiveTime) -> KiteResult<Vec<Trade>> {
+        Ok(self
+            .trades_typed()
+            .await?
+            .into_iter()
+            .filter(|trade| {
+                let time = trade.fill_timestamp.with_timezone(&IST).time();
+                from <= time && time < to
+            })
+            .collect())
+    }
+
     /// Get trades for specific order with typed response
     ///
     /// Returns strongly typed list of trades for a specific order instead of JsonValue.
@@ -986,4 +1138,332 @@ impl KiteConnect {
         let data = json_response["data"].clone();
         self.parse_response(data)
     }
+
+    /// Get the state transitions of an order with typed response
+    ///
+    /// Entries are in the order Kite reports them, oldest first, including
+    /// transient states such as `PUT ORDER REQ RECEIVED` and
+    /// `VALIDATION PENDING`. `exchange_timestamp` is `None` for states the
+    /// exchange has not yet seen.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - The order ID to get history for
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// for entry in client.order_history_typed("order_id").await? {
+    ///     println!("{} {:?}", entry.order_timestamp, entry.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn order_history_typed(&self, order_id: &str) -> KiteResult<Vec<OrderHistoryEntry>> {
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::OrderHistory,
+                &[order_id],
+                None,
+                None,
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
+    /// Get the latest state of a single order
+    ///
+    /// Fetches the order's history and returns its last entry, which is
+    /// the current state. More convenient than `order_history` when only the
+    /// outcome matters, and cheaper than filtering `orders_typed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - The order ID to look up
+    ///
+    /// # Errors
+    ///
+    /// Returns `KiteError::OrderException` if Kite has no history for the order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let order = client.order("order_id").await?;
+    /// println!("Order {} is {:?}", order.order_id, order.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn order(&self, order_id: &str) -> KiteResult<Order> {
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::OrderHistory,
+                &[order_id],
+                None,
+                None,
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        let history: Vec<Order> = self.parse_response(json_response["data"].clone())?;
+        history
+            .into_iter()
+            .last()
+            .ok_or_else(|| KiteError::order_exception(format!("Order {} not found", order_id)))
+    }
+
+    /// Wait until an order is complete, rejected or cancelled
+    ///
+    /// Polls [`order`](Self::order) every
+    /// [`DEFAULT_ORDER_POLL_INTERVAL`] until the order's status is final and
+    /// returns the order in that state. Use
+    /// [`wait_for_order_with_interval`](Self::wait_for_order_with_interval)
+    /// to poll at a different rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - The order ID to wait for
+    /// * `timeout` - Longest time to keep polling
+    ///
+    /// # Errors
+    ///
+    /// Returns `KiteError::OrderException` if the order is still pending when
+    /// `timeout` elapses, or any error from fetching the order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let order = client.wait_for_order("order_id", Duration::from_secs(30)).await?;
+    /// if order.is_complete() {
+    ///     println!("Filled at {}", order.average_price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_order(&self, order_id: &str, timeout: Duration) -> KiteResult<Order> {
+        self.wait_for_order_with_interval(order_id, timeout, DEFAULT_ORDER_POLL_INTERVAL)
+            .await
+    }
+
+    /// Wait until an order reaches a final state, polling every `interval`
+    ///
+    /// Same as [`wait_for_order`](Self::wait_for_order) with a custom
+    /// polling interval. When rate limiting is enabled, an `interval` shorter
+    /// than the order history endpoint's minimum delay is clamped up to it.
+    pub async fn wait_for_order_with_interval(
+        &self,
+        order_id: &str,
+        timeout: Duration,
+        interval: Duration,
+    ) -> KiteResult<Order> {
+        let interval = if self.is_rate_limiting_enabled() {
+            interval.max(KiteEndpoint::OrderHistory.rate_limit_category().min_delay())
+        } else {
+            interval
+        };
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let order = self.order(order_id).await?;
+            if order.status.is_final() {
+                return Ok(order);
+            }
+
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero() {
+                return Err(KiteError::order_exception(format!(
+                    "Order {} still {:?} after {:?}",
+                    order_id, order.status, timeout
+                )));
+            }
+            tokio::time::sleep(interval.min(remaining)).await;
+        }
+    }
+
+    /// Calculate the margin required for a basket of orders
+    ///
+    /// Sends the orders as a JSON array to `/margins/orders` and returns one
+    /// [`OrderMargins`] per order, in the same order, with the SPAN, exposure,
+    /// option premium and total margin each would block. Nothing is placed,
+    /// which makes this suitable for pre-trade risk checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `orders` - Orders to evaluate, built with [`OrderMarginParamBuilder`](crate::models::orders::OrderMarginParamBuilder)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::{Exchange, OrderType, Product, TransactionType};
+    /// use kiteconnect_async_wasm::models::orders::OrderMarginParamBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let order = OrderMarginParamBuilder::new()
+    ///     .exchange(Exchange::NFO)
+    ///     .trading_symbol("NIFTY24JANFUT")
+    ///     .transaction_type(TransactionType::SELL)
+    ///     .product(Product::NRML)
+    ///     .order_type(OrderType::MARKET)
+    ///     .quantity(50)
+    ///     .build()?;
+    ///
+    /// for margin in client.order_margins(vec![order]).await? {
+    ///     println!("{}: SPAN {} + exposure {} = {}",
+    ///         margin.trading_symbol, margin.span, margin.exposure, margin.total);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn order_margins(
+        &self,
+        orders: Vec<OrderMarginParam>,
+    ) -> KiteResult<Vec<OrderMargins>> {
+        let body = serde_json::to_value(&orders).map_err(KiteError::Json)?;
+        let resp = self
+            .send_json_request_with_rate_limiting(KiteEndpoint::OrderMargins, None, &body)
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
+    /// Calculate the combined margin for a basket of orders
+    ///
+    /// Unlike [`order_margins`](Self::order_margins), Kite evaluates the
+    /// orders together, so hedged legs such as spreads offset each other.
+    /// The response holds the margin before (`initial`) and after
+    /// (`final_margin`) offsetting along with the per-leg breakdown; see
+    /// [`BasketMargins::hedge_benefit`].
+    ///
+    /// # Arguments
+    ///
+    /// * `orders` - Legs of the basket
+    /// * `consider_positions` - Also offset against existing open positions
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::common::{Exchange, OrderType, Product, TransactionType};
+    /// use kiteconnect_async_wasm::models::orders::OrderMarginParamBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let leg = |symbol: &str, side| {
+    ///     OrderMarginParamBuilder::new()
+    ///         .exchange(Exchange::NFO)
+    ///         .trading_symbol(symbol)
+    ///         .transaction_type(side)
+    ///         .product(Product::NRML)
+    ///         .order_type(OrderType::MARKET)
+    ///         .quantity(50)
+    ///         .build()
+    /// };
+    /// let spread = vec![
+    ///     leg("NIFTY24JAN21000CE", TransactionType::BUY)?,
+    ///     leg("NIFTY24JAN21200CE", TransactionType::SELL)?,
+    /// ];
+    ///
+    /// let basket = client.basket_margins(spread, true).await?;
+    /// println!("Net margin {} (saves {})", basket.final_margin.total, basket.hedge_benefit());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn basket_margins(
+        &self,
+        orders: Vec<OrderMarginParam>,
+        consider_positions: bool,
+    ) -> KiteResult<BasketMargins> {
+        let body = serde_json::to_value(&orders).map_err(KiteError::Json)?;
+        let consider_positions = consider_positions.to_string();
+        let resp = self
+            .send_json_request_with_rate_limiting(
+                KiteEndpoint::BasketMargins,
+                Some(vec![("consider_positions", consider_positions.as_str())]),
+                &body,
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+}
+
+/// Encode order parameters as Kite's order form fields
+fn order_form_fields(order: &OrderParams) -> Vec<(&'static str, String)> {
+    let mut fields = vec![
+        ("exchange", order.exchange.to_string()),
+        ("tradingsymbol", order.trading_symbol.clone()),
+        ("transaction_type", order.transaction_type.to_string()),
+        ("quantity", order.quantity.to_string()),
+        ("product", order.product.to_string()),
+        ("order_type", order.order_type.to_string()),
+    ];
+
+    let optional = [
+        ("price", order.price.map(|v| v.to_string())),
+        ("trigger_price", order.trigger_price.map(|v| v.to_string())),
+        ("validity", order.validity.as_ref().map(|v| v.to_string())),
+        ("validity_ttl", order.validity_ttl.map(|v| v.to_string())),
+        (
+            "disclosed_quantity",
+            order.disclosed_quantity.map(|v| v.to_string()),
+        ),
+        ("tag", order.tag.clone()),
+        ("squareoff", order.squareoff.map(|v| v.to_string())),
+        ("stoploss", order.stoploss.map(|v| v.to_string())),
+        (
+            "trailing_stoploss",
+            order.trailing_stoploss.map(|v| v.to_string()),
+        ),
+        (
+            "market_protection",
+            order.market_protection.map(|v| v.to_string()),
+        ),
+        ("iceberg_legs", order.iceberg_legs.map(|v| v.to_string())),
+        (
+            "iceberg_quantity",
+            order.iceberg_quantity.map(|v| v.to_string()),
+        ),
+        ("auction_number", order.auction_number.clone()),
+    ];
+    fields.extend(
+        optional
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key, value))),
+    );
+
+    fields
 }
@@ -31,6 +31,7 @@
 //!
 //! ### Holdings Management
 //! - [`holdings()`](KiteConnect::holdings) / [`holdings_typed()`](KiteConnect::holdings_typed) - Get all stock holdings
+//! - [`auction_instruments()`](KiteConnect::auction_instruments) - Holdings eligible for the auction market
 //! - Portfolio analysis and P&L tracking
 //! - T+1 quantity and sellable quantity calculations
 //!
@@ -346,12 +347,21 @@
 //! - Enhanced helper methods on all model structs for better analytics
 
 use crate::connect::endpoints::KiteEndpoint;
+#[cfg(feature = "legacy")]
 use anyhow::Result;
+use chrono::{NaiveTime, Utc};
+#[cfg(feature = "legacy")]
 use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
 // Import typed models for dual API support
-use crate::models::auth::MarginData;
-use crate::models::common::KiteResult;
-use crate::models::portfolio::{ConversionRequest, Holding, Position};
+use crate::models::auth::{MarginData, SegmentMargin, TradingSegment};
+use crate::models::common::timestamps::IST;
+use crate::models::common::{round_price, KiteError, KiteResult, TransactionType, PRICE_DECIMALS};
+use crate::models::portfolio::{
+    pnl, AuctionInstrument, BulkConversionRequest, BulkConversionResponse, ConversionResult,
+    Holding, Position, PositionConversionRequest,
+};
 
 use crate::connect::KiteConnect;
 
@@ -399,6 +409,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn margins(&self, segment: Option<String>) -> Result<JsonValue> {
         if let Some(segment) = segment {
             let resp = self
@@ -421,6 +432,7 @@ impl KiteConnect {
     }
 
     /// Get user profile details
+    #[cfg(feature = "legacy")]
     pub async fn profile(&self) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(KiteEndpoint::Profile, &[], None, None)
@@ -471,6 +483,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn holdings(&self) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(KiteEndpoint::Holdings, &[], None, None)
@@ -524,6 +537,7 @@ impl KiteConnect {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "legacy")]
     pub async fn positions(&self) -> Result<JsonValue> {
         let resp = self
             .send_request_with_rate_limiting_and_retry(KiteEndpoint::Positions, &[], None, None)
@@ -540,16 +554,19 @@ impl KiteConnect {
     ///
     /// # Arguments
     ///
-    /// * `segment` - Optional trading segment ("equity" or "commodity")
+    /// * `segment` - Optional trading segment; `None` fetches equity and
+    ///   commodity together
     ///
     /// # Returns
     ///
-    /// A `KiteResult<MarginData>` containing typed margin information
+    /// A `KiteResult<MarginData>` containing typed margin information. When a
+    /// segment is given, only that segment's field is populated.
     ///
     /// # Example
     ///
     /// ```rust,no_run
     /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::auth::TradingSegment;
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -557,30 +574,118 @@ impl KiteConnect {
     ///
     /// let margins = client.margins_typed(None).await?;
     /// println!("Available equity margin: {}", margins.equity.unwrap().available.cash);
+    ///
+    /// let commodity = client.margins_typed(Some(TradingSegment::Commodity)).await?;
+    /// println!("Commodity net margin: {}", commodity.commodity.unwrap().net);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn margins_typed(&self, segment: Option<&str>) -> KiteResult<MarginData> {
+    pub async fn margins_typed(&self, segment: Option<TradingSegment>) -> KiteResult<MarginData> {
         if let Some(segment) = segment {
-            let resp = self
-                .send_request_with_rate_limiting_and_retry(
-                    KiteEndpoint::MarginsSegment,
-                    &[segment],
-                    None,
-                    None,
-                )
-                .await?;
-            let json_response = self.raise_or_return_json_typed(resp).await?;
-            self.parse_response(json_response)
+            let margin = self.segment_margins_typed(segment).await?;
+            Ok(match segment {
+                TradingSegment::Equity => MarginData {
+                    equity: Some(margin),
+                    commodity: None,
+                },
+                TradingSegment::Commodity => MarginData {
+                    equity: None,
+                    commodity: Some(margin),
+                },
+            })
         } else {
             let resp = self
                 .send_request_with_rate_limiting_and_retry(KiteEndpoint::Margins, &[], None, None)
                 .await?;
             let json_response = self.raise_or_return_json_typed(resp).await?;
-            self.parse_response(json_response)
+
+            // Extract the data field from response
+            let data = json_response["data"].clone();
+            self.parse_response(data)
         }
     }
 
+    /// Get margins for every segment, keyed by segment
+    ///
+    /// Kite returns all segments from a single `/user/margins` call, so this
+    /// makes one request and splits the response into one [`SegmentMargin`]
+    /// per [`TradingSegment`]. Segments missing from the response (e.g. when
+    /// commodity trading is not enabled) are absent from the map.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::auth::TradingSegment;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let margins = client.all_margins().await?;
+    /// if let Some(equity) = margins.get(&TradingSegment::Equity) {
+    ///     println!("Equity net margin: {}", equity.net);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all_margins(&self) -> KiteResult<HashMap<TradingSegment, SegmentMargin>> {
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(KiteEndpoint::Margins, &[], None, None)
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        let margins: MarginData = self.parse_response(data)?;
+        Ok(margins.into_segments())
+    }
+
+    /// Get margins for a single segment with typed response
+    ///
+    /// Calls `/user/margins/{segment}` and parses the `available`, `utilised`
+    /// and `net` figures into a [`SegmentMargin`]. Prefer this over the JSON
+    /// returned by `margins(Some(segment))` when only one segment is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment` - Trading segment to fetch
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::auth::TradingSegment;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let equity = client.segment_margins_typed(TradingSegment::Equity).await?;
+    /// println!("Net equity margin: {}", equity.net);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn segment_margins_typed(
+        &self,
+        segment: TradingSegment,
+    ) -> KiteResult<SegmentMargin> {
+        let segment = segment.to_string();
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::MarginsSegment,
+                &[&segment],
+                None,
+                None,
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
     /// Get user holdings with typed response
     ///
     /// Returns a vector of strongly typed holding objects instead of JsonValue.
@@ -621,6 +726,45 @@ impl KiteConnect {
         }
     }
 
+    /// Get holdings eligible for the auction market
+    ///
+    /// Lists the holdings that can be offered in the exchange's settlement
+    /// auction. Each entry carries the `auction_number` to send with an
+    /// `auction` variety sell order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// for instrument in client.auction_instruments().await? {
+    ///     println!("{} auction {}: {} @ {}",
+    ///         instrument.trading_symbol, instrument.auction_number,
+    ///         instrument.quantity, instrument.last_price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn auction_instruments(&self) -> KiteResult<Vec<AuctionInstrument>> {
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(
+                KiteEndpoint::HoldingsAuctions,
+                &[],
+                None,
+                None,
+            )
+            .await?;
+        let json_response = self.raise_or_return_json_typed(resp).await?;
+
+        // Extract the data field from response
+        let data = json_response["data"].clone();
+        self.parse_response(data)
+    }
+
     /// Get user positions with typed response
     ///
     /// Returns structured position data instead of JsonValue.
@@ -679,9 +823,93 @@ impl KiteConnect {
         Ok(all_positions)
     }
 
+    /// Net positions only, without the day positions
+    async fn net_positions(&self) -> KiteResult<Vec<Position>> {
+        let resp = self
+            .send_request_with_rate_limiting_and_retry(KiteEndpoint::Positions, &[], None, None)
+            .await?;
+        let mut json_response = self.raise_or_return_json_typed(resp).await?;
+
+        match json_response["data"].get_mut("net") {
+            Some(net) => self.parse_response(net.take()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Get today's realized P&L per instrument
+    ///
+    /// Fetches today's executed trades and the net positions, and pairs the
+    /// trades in FIFO order with [`pnl::match_fifo_from`], summing the
+    /// realized lots per `EXCHANGE:TRADINGSYMBOL`. Each symbol's queue starts
+    /// with its overnight quantity at the position's average price, so a
+    /// trade closing an overnight position realizes P&L against it. Quantity
+    /// still open at the end of the day is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let pnl = client.realized_pnl_today().await?;
+    /// for (symbol, amount) in &pnl {
+    ///     println!("{}: ₹{:.2}", symbol, amount);
+    /// }
+    /// println!("Total: ₹{:.2}", pnl.values().sum::<f64>());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn realized_pnl_today(&self) -> KiteResult<HashMap<String, f64>> {
+        let trades = self.trades_typed().await?;
+
+        let day_start = Utc::now()
+            .with_timezone(&IST)
+            .date_naive()
+            .and_time(NaiveTime::MIN)
+            .and_local_timezone(IST)
+            .single()
+            .map(|start| start.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+        let overnight: Vec<pnl::OpeningLot> = self
+            .net_positions()
+            .await?
+            .into_iter()
+            .filter(|position| position.overnight_quantity != 0)
+            .map(|position| pnl::OpeningLot {
+                side: if position.overnight_quantity > 0 {
+                    TransactionType::BUY
+                } else {
+                    TransactionType::SELL
+                },
+                quantity: position.overnight_quantity.unsigned_abs(),
+                price: position.average_price,
+                opened_at: day_start,
+                exchange: position.exchange,
+                trading_symbol: position.trading_symbol,
+            })
+            .collect();
+
+        let mut realized = HashMap::new();
+        for lot in pnl::match_fifo_from(&overnight, &trades) {
+            *realized
+                .entry(format!("{}:{}", lot.exchange, lot.trading_symbol))
+                .or_insert(0.0) += lot.pnl;
+        }
+        for pnl in realized.values_mut() {
+            *pnl = round_price(*pnl, PRICE_DECIMALS);
+        }
+
+        Ok(realized)
+    }
+
     /// Convert positions between product types (typed)
     ///
     /// Converts a position from one product type to another (e.g., MIS to CNC).
+    /// The request is validated locally first; an invalid request fails with
+    /// `KiteError::InputException` without being sent.
     ///
     /// # Arguments
     ///
@@ -689,47 +917,52 @@ impl KiteConnect {
     ///
     /// # Returns
     ///
-    /// A `KiteResult<bool>` indicating success
+    /// A `KiteResult<bool>` with the `data` flag Kite returns for the conversion
     ///
     /// # Example
     ///
     /// ```rust,no_run
     /// use kiteconnect_async_wasm::connect::KiteConnect;
-    /// use kiteconnect_async_wasm::models::portfolio::ConversionRequest;
-    /// use kiteconnect_async_wasm::models::common::{Exchange, Product, TransactionType};
+    /// use kiteconnect_async_wasm::models::portfolio::PositionConversionRequest;
+    /// use kiteconnect_async_wasm::models::common::{Exchange, TransactionType};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = KiteConnect::new("api_key", "access_token");
     ///
-    /// let conversion = ConversionRequest {
-    ///     exchange: Exchange::NSE,
-    ///     trading_symbol: "RELIANCE".to_string(),
-    ///     transaction_type: TransactionType::BUY,
-    ///     quantity: 10,
-    ///     from_product: Product::MIS,
-    ///     to_product: Product::CNC,
-    /// };
+    /// let conversion = PositionConversionRequest::mis_to_cnc(
+    ///     Exchange::NSE,
+    ///     "RELIANCE".to_string(),
+    ///     TransactionType::BUY,
+    ///     10,
+    /// );
     ///
-    /// let success = client.convert_position_typed(&conversion).await?;
+    /// let success = client.convert_position_typed(conversion).await?;
     /// println!("Conversion successful: {}", success);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn convert_position_typed(&self, request: &ConversionRequest) -> KiteResult<bool> {
-        let mut params = std::collections::HashMap::new();
+    pub async fn convert_position_typed(
+        &self,
+        request: PositionConversionRequest,
+    ) -> KiteResult<bool> {
+        request.validate().map_err(KiteError::input_exception)?;
+
         let exchange_str = request.exchange.to_string();
         let transaction_str = request.transaction_type.to_string();
+        let position_type_str = request.position_type.to_string();
         let quantity_str = request.quantity.to_string();
-        let from_product_str = request.from_product.to_string();
-        let to_product_str = request.to_product.to_string();
+        let old_product_str = request.old_product.to_string();
+        let new_product_str = request.new_product.to_string();
 
+        let mut params = HashMap::new();
         params.insert("exchange", exchange_str.as_str());
         params.insert("tradingsymbol", request.trading_symbol.as_str());
         params.insert("transaction_type", transaction_str.as_str());
+        params.insert("position_type", position_type_str.as_str());
         params.insert("quantity", quantity_str.as_str());
-        params.insert("old_product", from_product_str.as_str());
-        params.insert("new_product", to_product_str.as_str());
+        params.insert("old_product", old_product_str.as_str());
+        params.insert("new_product", new_product_str.as_str());
 
         let resp = self
             .send_request_with_rate_limiting_and_retry(
@@ -741,7 +974,97 @@ impl KiteConnect {
             .await?;
         let json_response = self.raise_or_return_json_typed(resp).await?;
 
-        // Check if conversion was successful
-        Ok(json_response.get("status").and_then(|v| v.as_str()) == Some("success"))
+        // Kite answers with `"data": true`; fall back to the envelope status
+        Ok(match json_response.get("data").and_then(|v| v.as_bool()) {
+            Some(converted) => converted,
+            None => json_response.get("status").and_then(|v| v.as_str()) == Some("success"),
+        })
+    }
+
+    /// Convert several positions one after another
+    ///
+    /// Every conversion is validated before anything is sent; if any is
+    /// invalid the call fails with `KiteError::InputException` listing all
+    /// problems. Otherwise each conversion is sent in order and its outcome
+    /// recorded in the returned [`BulkConversionResponse`], so a rejected
+    /// conversion does not stop the rest. Each
+    /// [`ConversionRequest`](crate::models::portfolio::ConversionRequest) is sent
+    /// as a `day` position when converting from MIS and as `overnight`
+    /// otherwise.
+    ///
+    /// The overall `status` is `"success"` when every conversion went
+    /// through, `"partial"` when some did and `"error"` when none did.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::portfolio::{BulkConversionRequest, ConversionRequest};
+    /// use kiteconnect_async_wasm::models::common::{Exchange, TransactionType};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let bulk = BulkConversionRequest::new()
+    ///     .add_conversion(ConversionRequest::mis_to_cnc(
+    ///         Exchange::NSE, "INFY".to_string(), TransactionType::BUY, 5))
+    ///     .add_conversion(ConversionRequest::mis_to_cnc(
+    ///         Exchange::NSE, "TCS".to_string(), TransactionType::BUY, 2));
+    ///
+    /// let response = client.convert_positions_bulk(&bulk).await?;
+    /// for failed in response.failed_conversions() {
+    ///     println!("{}: {:?}", failed.trading_symbol, failed.error_message());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_positions_bulk(
+        &self,
+        request: &BulkConversionRequest,
+    ) -> KiteResult<BulkConversionResponse> {
+        request
+            .validate()
+            .map_err(|errors| KiteError::input_exception(errors.join("; ")))?;
+
+        let mut results = Vec::with_capacity(request.count());
+        for conversion in &request.conversions {
+            let trading_symbol = conversion.trading_symbol.clone();
+            let exchange = conversion.exchange;
+
+            let (status, error) = match self.convert_position_typed(conversion.clone().into()).await
+            {
+                Ok(true) => ("success", None),
+                Ok(false) => ("error", Some("Conversion was not applied".to_string())),
+                Err(e) => ("error", Some(e.to_string())),
+            };
+
+            results.push(ConversionResult {
+                trading_symbol,
+                exchange,
+                status: status.to_string(),
+                message: None,
+                error,
+            });
+        }
+
+        let succeeded = results.iter().filter(|r| r.is_success()).count();
+        let status = if succeeded == results.len() {
+            "success"
+        } else if succeeded > 0 {
+            "partial"
+        } else {
+            "error"
+        };
+
+        Ok(BulkConversionResponse {
+            status: status.to_string(),
+            message: Some(format!(
+                "{} of {} conversions succeeded",
+                succeeded,
+                results.len()
+            )),
+            results,
+        })
     }
 }
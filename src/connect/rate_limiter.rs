@@ -14,9 +14,10 @@
 //! ## Implementation Details
 //!
 //! The rate limiter uses a token bucket algorithm with per-category tracking:
-//! 1. Each category has its own rate limit and timing
-//! 2. Requests are tracked with precise timing to ensure compliance
-//! 3. Automatic delays are inserted when limits would be exceeded
+//! 1. Each category has its own bucket with a burst capacity and refill rate
+//!    (see [`BucketConfig`])
+//! 2. Bursts up to the bucket size are sent immediately
+//! 3. Sustained load is delayed until the bucket refills
 //! 4. Thread-safe implementation supports concurrent operations
 //!
 //! ## Usage
@@ -48,24 +49,63 @@
 //! - Auto-cleanup: Unused categories are automatically cleaned up
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 
 use super::endpoints::{KiteEndpoint, RateLimitCategory};
 
-/// Per-category rate limiter state
+/// Token-bucket settings for a rate limit category
+///
+/// A bucket holds up to `capacity` tokens and regains `refill_per_second`
+/// tokens every second. Each request spends one token, so bursts of up to
+/// `capacity` requests go out immediately while sustained load is held to
+/// the refill rate.
+///
+/// # Example
 ///
-/// Tracks timing and request counts for a specific rate limit category.
-/// Each category (Quote, Historical, Orders, Standard) has its own limiter
-/// instance with category-specific limits and timing requirements.
+/// ```rust
+/// use kiteconnect_async_wasm::connect::endpoints::RateLimitCategory;
+/// use kiteconnect_async_wasm::connect::rate_limiter::BucketConfig;
 ///
-/// # Fields
+/// // Allow bursts of 3 quote requests, refilled at 1 per second
+/// let quotes = BucketConfig::new(3, 1.0);
+/// assert_eq!(quotes.capacity, 3);
+///
+/// let defaults = BucketConfig::for_category(RateLimitCategory::Historical);
+/// assert_eq!(defaults.capacity, 3);
+/// assert_eq!(defaults.refill_per_second, 3.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BucketConfig {
+    /// Maximum number of requests that may be sent back-to-back
+    pub capacity: u32,
+    /// Tokens regained per second (the sustained request rate)
+    pub refill_per_second: f64,
+}
+
+impl BucketConfig {
+    /// Create a bucket with the given burst capacity and refill rate
+    pub fn new(capacity: u32, refill_per_second: f64) -> Self {
+        Self {
+            capacity,
+            refill_per_second,
+        }
+    }
+
+    /// Default bucket for a category: a one-second burst at its published limit
+    pub fn for_category(category: RateLimitCategory) -> Self {
+        let rate = category.requests_per_second();
+        Self::new(rate, rate as f64)
+    }
+}
+
+/// Per-category rate limiter state
 ///
-/// - `last_request`: Timestamp of the most recent request in this category
-/// - `min_delay`: Minimum time that must pass between requests
-/// - `request_count`: Number of requests made in the current time window
-/// - `requests_per_second`: Maximum allowed requests per second for this category
+/// A token bucket for a single rate limit category. Tokens are refilled
+/// lazily from the elapsed time whenever the bucket is inspected, so no
+/// background task is needed.
 ///
 /// # Thread Safety
 ///
@@ -73,116 +113,74 @@ use super::endpoints::{KiteEndpoint, RateLimitCategory};
 /// across multiple concurrent requests.
 #[derive(Debug)]
 struct CategoryLimiter {
+    /// Maximum number of stored tokens
+    capacity: f64,
+    /// Tokens regained per second
+    refill_per_second: f64,
+    /// Tokens available as of `last_refill`; negative while requests are queued
+    tokens: f64,
+    /// When `tokens` was last brought up to date
+    last_refill: Instant,
     /// Last request time for this category
     last_request: Option<Instant>,
-    /// Minimum delay between requests
-    min_delay: Duration,
-    /// Number of requests in current window
+    /// Total number of requests made in this category
     request_count: u32,
-    /// Requests per second limit
+    /// Published requests per second limit
     requests_per_second: u32,
 }
 
 impl CategoryLimiter {
-    /// Create a new category limiter with the specified rate limit category
-    ///
-    /// # Arguments
-    ///
-    /// * `category` - The rate limit category which determines the limits
-    ///
-    /// # Returns
-    ///
-    /// A new `CategoryLimiter` configured for the specified category
-    ///
-    /// # Example
-    ///
-    /// ```rust,ignore
-    /// # use kiteconnect_async_wasm::connect::endpoints::RateLimitCategory;
-    /// # use kiteconnect_async_wasm::connect::rate_limiter::CategoryLimiter;
-    /// let limiter = CategoryLimiter::new(RateLimitCategory::Quote);
-    /// // Quote category: 1 request/second
-    /// ```
-    fn new(category: RateLimitCategory) -> Self {
+    /// Create a full bucket for `category` using the given settings
+    fn new(category: RateLimitCategory, config: BucketConfig) -> Self {
+        let capacity = config.capacity.max(1) as f64;
         Self {
+            capacity,
+            // Guard against a zero rate, which would never refill
+            refill_per_second: config.refill_per_second.max(0.001),
+            tokens: capacity,
+            last_refill: Instant::now(),
             last_request: None,
-            min_delay: category.min_delay(),
             request_count: 0,
             requests_per_second: category.requests_per_second(),
         }
     }
 
+    /// Tokens available right now, including those refilled since `last_refill`
+    fn available_tokens(&self) -> f64 {
+        let refilled = self.last_refill.elapsed().as_secs_f64() * self.refill_per_second;
+        (self.tokens + refilled).min(self.capacity)
+    }
+
     /// Check if a request can be made immediately without delay
-    ///
-    /// # Returns
-    ///
-    /// `true` if enough time has passed since the last request, `false` otherwise
-    ///
-    /// # Example
-    ///
-    /// ```rust,ignore
-    /// # use kiteconnect_async_wasm::connect::endpoints::RateLimitCategory;
-    /// # use kiteconnect_async_wasm::connect::rate_limiter::CategoryLimiter;
-    /// let mut limiter = CategoryLimiter::new(RateLimitCategory::Quote);
-    ///
-    /// if limiter.can_request_now() {
-    ///     // Make request immediately
-    /// } else {
-    ///     // Need to wait before making request
-    /// }
-    /// ```
     fn can_request_now(&self) -> bool {
-        if let Some(last) = self.last_request {
-            last.elapsed() >= self.min_delay
-        } else {
-            true
-        }
+        self.available_tokens() >= 1.0
     }
 
     /// Calculate the delay needed before the next request can be made
     ///
-    /// # Returns
-    ///
-    /// `Duration` representing how long to wait before the next request.
-    /// Returns `Duration::ZERO` if no delay is needed.
-    ///
-    /// # Example
-    ///
-    /// ```rust,ignore
-    /// # use kiteconnect_async_wasm::connect::endpoints::RateLimitCategory;
-    /// # use kiteconnect_async_wasm::connect::rate_limiter::CategoryLimiter;
-    /// let mut limiter = CategoryLimiter::new(RateLimitCategory::Quote);
-    ///
-    /// let delay = limiter.delay_until_next_request();
-    /// if !delay.is_zero() {
-    ///     tokio::time::sleep(delay).await;
-    /// }
-    /// ```
+    /// Returns `Duration::ZERO` if a token is available.
     fn delay_until_next_request(&self) -> Duration {
-        if let Some(last) = self.last_request {
-            let elapsed = last.elapsed();
-            if elapsed < self.min_delay {
-                self.min_delay - elapsed
-            } else {
-                Duration::ZERO
-            }
+        let missing = 1.0 - self.available_tokens();
+        if missing > 0.0 {
+            Duration::from_secs_f64(missing / self.refill_per_second)
         } else {
             Duration::ZERO
         }
     }
 
-    /// Record a request
-    fn record_request(&mut self) {
-        self.last_request = Some(Instant::now());
-        self.request_count += 1;
-    }
-
-    /// Reset request count (called every second)
+    /// Take a token for a request and return how long it must wait
     ///
-    /// This method is used internally for cleaning up request counters
-    /// and may be used in future rate limiting algorithms.
-    #[allow(dead_code)]
-    fn reset_count(&mut self) {
-        self.request_count = 0;
+    /// The token is reserved up front, so concurrent callers queue behind
+    /// each other instead of all waking at the same instant.
+    fn reserve(&mut self) -> Duration {
+        self.tokens = self.available_tokens();
+        self.last_refill = Instant::now();
+
+        let delay = self.delay_until_next_request();
+        self.tokens -= 1.0;
+        self.last_request = Some(self.last_refill + delay);
+        self.request_count += 1;
+        delay
     }
 }
 
@@ -247,34 +245,54 @@ pub struct RateLimiter {
     limiters: Arc<Mutex<HashMap<RateLimitCategory, CategoryLimiter>>>,
     /// Whether rate limiting is enabled
     enabled: bool,
+    /// Number of requests that had to wait for a token, shared between clones
+    waits: Arc<AtomicU64>,
 }
 
 impl RateLimiter {
-    /// Create a new rate limiter
+    /// Create a new rate limiter using each category's default bucket
     pub fn new(enabled: bool) -> Self {
-        let mut limiters = HashMap::new();
+        Self::with_buckets(enabled, &HashMap::new())
+    }
 
-        // Initialize limiters for all categories
-        limiters.insert(
+    /// Create a rate limiter with custom token buckets
+    ///
+    /// Categories missing from `buckets` use [`BucketConfig::for_category`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use kiteconnect_async_wasm::connect::endpoints::RateLimitCategory;
+    /// use kiteconnect_async_wasm::connect::rate_limiter::{BucketConfig, RateLimiter};
+    ///
+    /// let mut buckets = HashMap::new();
+    /// buckets.insert(RateLimitCategory::Quote, BucketConfig::new(3, 1.0));
+    ///
+    /// let rate_limiter = RateLimiter::with_buckets(true, &buckets);
+    /// assert!(rate_limiter.is_enabled());
+    /// ```
+    pub fn with_buckets(enabled: bool, buckets: &HashMap<RateLimitCategory, BucketConfig>) -> Self {
+        let limiters = [
             RateLimitCategory::Quote,
-            CategoryLimiter::new(RateLimitCategory::Quote),
-        );
-        limiters.insert(
             RateLimitCategory::Historical,
-            CategoryLimiter::new(RateLimitCategory::Historical),
-        );
-        limiters.insert(
             RateLimitCategory::Orders,
-            CategoryLimiter::new(RateLimitCategory::Orders),
-        );
-        limiters.insert(
             RateLimitCategory::Standard,
-            CategoryLimiter::new(RateLimitCategory::Standard),
-        );
+        ]
+        .into_iter()
+        .map(|category| {
+            let config = buckets
+                .get(&category)
+                .copied()
+                .unwrap_or_else(|| BucketConfig::for_category(category.clone()));
+            (category.clone(), CategoryLimiter::new(category, config))
+        })
+        .collect();
 
         Self {
             limiters: Arc::new(Mutex::new(limiters)),
             enabled,
+            waits: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -310,15 +328,17 @@ impl RateLimiter {
 
         let category = endpoint.rate_limit_category();
         let delay = {
-            let limiters = self.limiters.lock().await;
-            if let Some(limiter) = limiters.get(&category) {
-                limiter.delay_until_next_request()
+            let mut limiters = self.limiters.lock().await;
+            if let Some(limiter) = limiters.get_mut(&category) {
+                limiter.reserve()
             } else {
                 Duration::ZERO
             }
         };
 
         if delay > Duration::ZERO {
+            self.waits.fetch_add(1, Ordering::Relaxed);
+
             #[cfg(feature = "debug")]
             log::debug!(
                 "Rate limiting: waiting {:?} for {:?} category",
@@ -328,12 +348,6 @@ impl RateLimiter {
 
             tokio::time::sleep(delay).await;
         }
-
-        // Record the request
-        let mut limiters = self.limiters.lock().await;
-        if let Some(limiter) = limiters.get_mut(&category) {
-            limiter.record_request();
-        }
     }
 
     /// Check if a request can be made without waiting
@@ -380,14 +394,19 @@ impl RateLimiter {
         let limiters = self.limiters.lock().await;
         let mut categories = HashMap::new();
 
+        let now = Instant::now();
         for (category, limiter) in limiters.iter() {
             categories.insert(
                 category.clone(),
                 CategoryStats {
                     request_count: limiter.request_count,
                     requests_per_second: limiter.requests_per_second,
+                    burst_capacity: limiter.capacity as u32,
+                    available_tokens: limiter.available_tokens().max(0.0) as u32,
                     last_request: limiter.last_request,
-                    next_available: limiter.last_request.map(|last| last + limiter.min_delay),
+                    next_available: limiter
+                        .last_request
+                        .map(|_| now + limiter.delay_until_next_request()),
                 },
             );
         }
@@ -407,6 +426,11 @@ impl RateLimiter {
     pub fn is_enabled(&self) -> bool {
         self.enabled
     }
+
+    /// Number of requests so far that were delayed to stay within the limits
+    pub fn wait_count(&self) -> u64 {
+        self.waits.load(Ordering::Relaxed)
+    }
 }
 
 impl Default for RateLimiter {
@@ -427,10 +451,14 @@ pub struct RateLimiterStats {
 /// Statistics for a specific rate limit category
 #[derive(Debug, Clone)]
 pub struct CategoryStats {
-    /// Current request count in this second
+    /// Total number of requests made in this category
     pub request_count: u32,
     /// Maximum requests per second for this category
     pub requests_per_second: u32,
+    /// Size of the category's token bucket (largest immediate burst)
+    pub burst_capacity: u32,
+    /// Whole tokens currently available in the bucket
+    pub available_tokens: u32,
     /// When the last request was made
     pub last_request: Option<Instant>,
     /// When the next request can be made
@@ -448,8 +476,10 @@ impl CategoryStats {
     }
 
     /// Get remaining capacity for this category
+    ///
+    /// The number of requests that can currently be sent without waiting.
     pub fn remaining_capacity(&self) -> u32 {
-        self.requests_per_second.saturating_sub(self.request_count)
+        self.available_tokens
     }
 }
 
@@ -548,6 +578,19 @@ mod tests {
         let standard_stats = &stats.categories[&RateLimitCategory::Standard];
         assert_eq!(standard_stats.request_count, 1);
         assert_eq!(standard_stats.requests_per_second, 10);
+
+        // Published per-category limits are exposed alongside the counters
+        let limits = [
+            (RateLimitCategory::Quote, 1),
+            (RateLimitCategory::Historical, 3),
+            (RateLimitCategory::Orders, 10),
+            (RateLimitCategory::Standard, 10),
+        ];
+        for (category, limit) in limits {
+            let category_stats = &stats.categories[&category];
+            assert_eq!(category_stats.requests_per_second, limit);
+            assert_eq!(category_stats.burst_capacity, limit);
+        }
     }
 
     #[tokio::test]
@@ -555,6 +598,8 @@ mod tests {
         let stats = CategoryStats {
             request_count: 5,
             requests_per_second: 10,
+            burst_capacity: 10,
+            available_tokens: 5,
             last_request: Some(Instant::now()),
             next_available: Some(Instant::now() + Duration::from_millis(100)),
         };
@@ -565,6 +610,8 @@ mod tests {
         let stats_not_at_limit = CategoryStats {
             request_count: 3,
             requests_per_second: 10,
+            burst_capacity: 10,
+            available_tokens: 7,
             last_request: Some(Instant::now() - Duration::from_secs(1)),
             next_available: Some(Instant::now() - Duration::from_millis(100)),
         };
@@ -572,4 +619,54 @@ mod tests {
         assert!(!stats_not_at_limit.is_at_limit());
         assert_eq!(stats_not_at_limit.remaining_capacity(), 7);
     }
+
+    #[tokio::test]
+    async fn test_bursts_up_to_capacity_are_immediate() {
+        let mut buckets = HashMap::new();
+        buckets.insert(RateLimitCategory::Quote, BucketConfig::new(3, 1.0));
+        let rate_limiter = RateLimiter::with_buckets(true, &buckets);
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            rate_limiter.wait_for_request(&KiteEndpoint::Quote).await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        // The bucket is empty; the next request waits for one refill
+        assert!(
+            !rate_limiter
+                .can_request_immediately(&KiteEndpoint::Quote)
+                .await
+        );
+        let delay = rate_limiter
+            .get_delay_for_request(&KiteEndpoint::Quote)
+            .await;
+        assert!(delay > Duration::from_millis(900) && delay <= Duration::from_secs(1));
+
+        let stats = rate_limiter.get_stats().await;
+        let quote_stats = &stats.categories[&RateLimitCategory::Quote];
+        assert_eq!(quote_stats.burst_capacity, 3);
+        assert_eq!(quote_stats.remaining_capacity(), 0);
+        assert!(quote_stats.is_at_limit());
+    }
+
+    #[tokio::test]
+    async fn test_sustained_load_is_throttled_to_refill_rate() {
+        let mut buckets = HashMap::new();
+        buckets.insert(RateLimitCategory::Historical, BucketConfig::new(2, 20.0));
+        let rate_limiter = RateLimiter::with_buckets(true, &buckets);
+
+        // Two burst tokens, then two more at 50ms each
+        let start = Instant::now();
+        for _ in 0..4 {
+            rate_limiter
+                .wait_for_request(&KiteEndpoint::HistoricalData)
+                .await;
+        }
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(95), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(500), "{:?}", elapsed);
+        // Only the two requests beyond the burst waited
+        assert_eq!(rate_limiter.wait_count(), 2);
+    }
 }
@@ -0,0 +1,669 @@
+//! # Ticker Module
+//!
+//! Streaming client, reconnection settings and subscription bookkeeping for
+//! the KiteConnect WebSocket ticker.
+//!
+//! The ticker protocol is driven by small JSON text messages sent to the
+//! server (`{"a": "subscribe", "v": [tokens]}`, `{"a": "mode", "v": [mode,
+//! [tokens]]}`) while ticks arrive as binary frames. This module keeps track
+//! of what is subscribed in which mode so that, after a dropped connection,
+//! the same messages can be replayed on the new socket.
+//!
+//! On native targets [`KiteTicker`] wraps a live connection: it exposes the
+//! decoded ticks as a `Stream` and accepts subscription changes while running.
+//!
+//! ## Reconnection
+//!
+//! [`TickerConfig`] controls whether a dropped connection is re-established,
+//! how many attempts are made, the delay between attempts and whether
+//! subscriptions are restored afterwards. By default the ticker reconnects up
+//! to 50 times with exponential backoff capped at 60 seconds and resubscribes
+//! every token in its previous mode.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use kiteconnect_async_wasm::connect::ticker::{TickerConfig, TickerSubscriptions};
+//! use kiteconnect_async_wasm::models::market_data::TickMode;
+//!
+//! let config = TickerConfig::default();
+//! let mut subscriptions = TickerSubscriptions::new();
+//!
+//! // Messages to send on the socket
+//! let messages = subscriptions.subscribe(&[408065, 884737], TickMode::Full);
+//! assert_eq!(messages.len(), 2);
+//!
+//! // After a drop: wait, reconnect, then replay the subscriptions
+//! if let Some(delay) = config.reconnect_delay(0) {
+//!     # let _ = delay;
+//!     let replay = config.messages_after_reconnect(&subscriptions);
+//!     assert_eq!(replay, messages);
+//! }
+//! ```
+
+use crate::connect::{JitterMode, JitterRng, RetryConfig};
+use crate::models::market_data::TickMode;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+use crate::connect::websocket::{Message, WebSocket};
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+use crate::connect::KiteConnect;
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+use crate::models::common::{KiteError, KiteResult};
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+use crate::models::market_data::Tick;
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+use futures_util::Stream;
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+use std::sync::{Arc, Mutex};
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+use tokio::sync::mpsc;
+
+/// Default WebSocket ticker endpoint
+pub const TICKER_URL: &str = "wss://ws.kite.trade";
+
+/// Ticks buffered between the socket reader and a slow consumer
+pub const TICK_BUFFER_SIZE: usize = 4096;
+
+/// Reconnection behaviour of the WebSocket ticker
+#[derive(Debug, Clone)]
+pub struct TickerConfig {
+    /// Re-establish the connection after it drops
+    pub reconnect: bool,
+    /// Give up after this many consecutive failed attempts (`None` retries forever)
+    pub max_reconnect_attempts: Option<u32>,
+    /// Delay between reconnection attempts; `max_retries` is ignored in
+    /// favour of `max_reconnect_attempts`. Uses full jitter by default so
+    /// clients dropped by the same outage don't reconnect in lockstep
+    pub reconnect_backoff: RetryConfig,
+    /// Replay subscriptions and modes once reconnected
+    pub resubscribe_on_reconnect: bool,
+}
+
+impl Default for TickerConfig {
+    fn default() -> Self {
+        Self {
+            reconnect: true,
+            max_reconnect_attempts: Some(50),
+            reconnect_backoff: RetryConfig {
+                max_retries: 50,
+                base_delay: Duration::from_secs(1),
+                max_delay: Duration::from_secs(60),
+                exponential_backoff: true,
+                jitter: JitterMode::Full,
+                jitter_rng: JitterRng::default(),
+                retry_mutations: false,
+            },
+            resubscribe_on_reconnect: true,
+        }
+    }
+}
+
+impl TickerConfig {
+    /// Delay before reconnection attempt `attempt` (0-based)
+    ///
+    /// Returns `None` when reconnection is disabled or the attempts are
+    /// exhausted, in which case the ticker should report the disconnect.
+    pub fn reconnect_delay(&self, attempt: u32) -> Option<Duration> {
+        if !self.reconnect {
+            return None;
+        }
+        if let Some(max) = self.max_reconnect_attempts {
+            if attempt >= max {
+                return None;
+            }
+        }
+
+        Some(self.reconnect_backoff.delay_for_attempt(attempt))
+    }
+
+    /// Messages to send on a freshly re-established connection
+    ///
+    /// Empty when `resubscribe_on_reconnect` is off.
+    pub fn messages_after_reconnect(&self, subscriptions: &TickerSubscriptions) -> Vec<String> {
+        if self.resubscribe_on_reconnect {
+            subscriptions.resubscribe_messages()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// Tokens currently subscribed on the ticker and their modes
+#[derive(Debug, Clone, Default)]
+pub struct TickerSubscriptions {
+    modes: BTreeMap<u32, TickMode>,
+}
+
+impl TickerSubscriptions {
+    /// Create an empty subscription set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe `tokens` in `mode`, returning the messages to send
+    ///
+    /// Already-subscribed tokens switch to the new mode.
+    pub fn subscribe(&mut self, tokens: &[u32], mode: TickMode) -> Vec<String> {
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+        for &token in tokens {
+            self.modes.insert(token, mode);
+        }
+        vec![subscribe_message(tokens), mode_message(mode, tokens)]
+    }
+
+    /// Switch `tokens` to `mode`, returning the messages to send
+    ///
+    /// Only the mode message is needed for tokens that are already
+    /// subscribed; any others are subscribed first.
+    pub fn set_mode(&mut self, tokens: &[u32], mode: TickMode) -> Vec<String> {
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+        let new: Vec<u32> = tokens
+            .iter()
+            .copied()
+            .filter(|token| !self.modes.contains_key(token))
+            .collect();
+        for &token in tokens {
+            self.modes.insert(token, mode);
+        }
+
+        let mut messages = Vec::new();
+        if !new.is_empty() {
+            messages.push(subscribe_message(&new));
+        }
+        messages.push(mode_message(mode, tokens));
+        messages
+    }
+
+    /// Unsubscribe `tokens`, returning the message to send
+    pub fn unsubscribe(&mut self, tokens: &[u32]) -> Vec<String> {
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+        for token in tokens {
+            self.modes.remove(token);
+        }
+        vec![json!({"a": "unsubscribe", "v": tokens}).to_string()]
+    }
+
+    /// Mode a token is subscribed in, if any
+    pub fn mode(&self, token: u32) -> Option<TickMode> {
+        self.modes.get(&token).copied()
+    }
+
+    /// Subscribed tokens in ascending order
+    pub fn tokens(&self) -> Vec<u32> {
+        self.modes.keys().copied().collect()
+    }
+
+    /// Number of subscribed tokens
+    pub fn len(&self) -> usize {
+        self.modes.len()
+    }
+
+    /// Whether nothing is subscribed
+    pub fn is_empty(&self) -> bool {
+        self.modes.is_empty()
+    }
+
+    /// Messages restoring every subscription, one subscribe/mode pair per mode
+    pub fn resubscribe_messages(&self) -> Vec<String> {
+        let mut by_mode: BTreeMap<TickMode, Vec<u32>> = BTreeMap::new();
+        for (&token, &mode) in &self.modes {
+            by_mode.entry(mode).or_default().push(token);
+        }
+
+        by_mode
+            .into_iter()
+            .flat_map(|(mode, tokens)| [subscribe_message(&tokens), mode_message(mode, &tokens)])
+            .collect()
+    }
+}
+
+/// Supplies the access token for each connection attempt
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+type TokenSource = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Live connection to the Kite WebSocket ticker
+///
+/// Yields decoded [`Tick`]s as a [`Stream`] while subscriptions can be
+/// changed at any time with [`subscribe`](Self::subscribe),
+/// [`set_mode`](Self::set_mode) and [`unsubscribe`](Self::unsubscribe).
+/// These queue the messages for the connection task and return at once.
+/// Dropped connections are re-established in the background according to the
+/// [`TickerConfig`], replaying the current subscriptions. The stream ends when
+/// reconnection is disabled or exhausted; dropping the ticker closes the
+/// connection.
+///
+/// # Backpressure
+///
+/// Up to [`TICK_BUFFER_SIZE`] ticks are buffered. When the buffer is full the
+/// reader stops reading from the socket until the consumer catches up, so
+/// ticks are never dropped by the client; instead they queue in the TCP
+/// buffers. A consumer that stays behind for long may be disconnected by
+/// Kite, after which the ticker reconnects and resumes with current ticks.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kiteconnect_async_wasm::connect::ticker::KiteTicker;
+/// use kiteconnect_async_wasm::models::market_data::TickMode;
+/// use futures_util::StreamExt;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut ticker = KiteTicker::connect("api_key", "access_token").await?;
+///
+/// ticker.subscribe(&[408065, 738561])?;
+/// ticker.set_mode(TickMode::Full, &[408065])?;
+///
+/// while let Some(tick) = ticker.next().await {
+///     println!("{}: {}", tick.instrument_token, tick.last_price);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+#[derive(Debug)]
+pub struct KiteTicker {
+    subscriptions: Arc<Mutex<TickerSubscriptions>>,
+    commands: mpsc::UnboundedSender<String>,
+    ticks: mpsc::Receiver<Tick>,
+    task: tokio::task::AbortHandle,
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+impl KiteTicker {
+    /// Connect to [`TICKER_URL`] with the default [`TickerConfig`]
+    pub async fn connect(api_key: &str, access_token: &str) -> KiteResult<Self> {
+        Self::connect_with_config(TICKER_URL, api_key, access_token, TickerConfig::default()).await
+    }
+
+    /// Connect to `url` with custom reconnection settings
+    ///
+    /// Errors from the first connection attempt are returned; later drops
+    /// are retried in the background.
+    pub async fn connect_with_config(
+        url: &str,
+        api_key: &str,
+        access_token: &str,
+        config: TickerConfig,
+    ) -> KiteResult<Self> {
+        let access_token = access_token.to_string();
+        Self::connect_with_token_source(
+            url,
+            api_key,
+            Arc::new(move || access_token.clone()),
+            config,
+        )
+        .await
+    }
+
+    /// Connect, asking `access_token` for the token on every (re)connection
+    async fn connect_with_token_source(
+        url: &str,
+        api_key: &str,
+        access_token: TokenSource,
+        config: TickerConfig,
+    ) -> KiteResult<Self> {
+        let base_url = reqwest::Url::parse(url).map_err(|e| {
+            KiteError::invalid_param(format!("Invalid ticker URL {:?}: {}", url, e))
+        })?;
+        let connection = TickerConnection {
+            base_url,
+            api_key: api_key.to_string(),
+            access_token,
+        };
+
+        let socket = WebSocket::connect(&connection.url()).await?;
+        let subscriptions = Arc::new(Mutex::new(TickerSubscriptions::new()));
+        let (commands, command_receiver) = mpsc::unbounded_channel();
+        let (sender, ticks) = mpsc::channel(TICK_BUFFER_SIZE);
+
+        let task = tokio::spawn(run_ticker(
+            socket,
+            connection,
+            config,
+            subscriptions.clone(),
+            command_receiver,
+            sender,
+        ));
+
+        Ok(Self {
+            subscriptions,
+            commands,
+            ticks,
+            task: task.abort_handle(),
+        })
+    }
+
+    /// Subscribe `tokens`; new subscriptions start in [`TickMode::Quote`]
+    ///
+    /// Tokens that are already subscribed keep their current mode.
+    pub fn subscribe(&self, tokens: &[u32]) -> KiteResult<()> {
+        let messages = {
+            let mut subscriptions = self.lock_subscriptions();
+            let new: Vec<u32> = tokens
+                .iter()
+                .copied()
+                .filter(|&token| subscriptions.mode(token).is_none())
+                .collect();
+            subscriptions.subscribe(&new, TickMode::Quote)
+        };
+        self.send(messages)
+    }
+
+    /// Switch `tokens` to `mode`
+    ///
+    /// Tokens that are not yet subscribed are subscribed as well.
+    pub fn set_mode(&self, mode: TickMode, tokens: &[u32]) -> KiteResult<()> {
+        let messages = self.lock_subscriptions().set_mode(tokens, mode);
+        self.send(messages)
+    }
+
+    /// Stop receiving ticks for `tokens`
+    pub fn unsubscribe(&self, tokens: &[u32]) -> KiteResult<()> {
+        let messages = self.lock_subscriptions().unsubscribe(tokens);
+        self.send(messages)
+    }
+
+    /// Snapshot of the current subscriptions
+    pub fn subscriptions(&self) -> TickerSubscriptions {
+        self.lock_subscriptions().clone()
+    }
+
+    fn lock_subscriptions(&self) -> std::sync::MutexGuard<'_, TickerSubscriptions> {
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn send(&self, messages: Vec<String>) -> KiteResult<()> {
+        for message in messages {
+            self.commands
+                .send(message)
+                .map_err(|_| KiteError::network_exception("Ticker connection closed"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+impl Stream for KiteTicker {
+    type Item = Tick;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Tick>> {
+        self.get_mut().ticks.poll_recv(cx)
+    }
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+impl Drop for KiteTicker {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+impl KiteConnect {
+    /// Open a [`KiteTicker`] with this client's credentials
+    ///
+    /// Connects to `KiteConnectConfig::ticker_url` using
+    /// `KiteConnectConfig::ticker_config`. Reconnections use the client's
+    /// access token at that time, so a token renewed meanwhile (e.g. by
+    /// [`spawn_token_refresher`](Self::spawn_token_refresher)) is picked up.
+    /// The connection is closed when the client is shut down.
+    pub async fn ticker(&self) -> KiteResult<KiteTicker> {
+        self.ensure_running()?;
+
+        let access_token = self.access_token.clone();
+        let ticker = KiteTicker::connect_with_token_source(
+            &self.ticker_url,
+            &self.api_key,
+            Arc::new(move || {
+                access_token
+                    .read()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .clone()
+            }),
+            self.ticker_config.clone(),
+        )
+        .await?;
+        self.track_background_task(ticker.task.clone());
+        Ok(ticker)
+    }
+
+    /// Stream live ticks for `tokens` over the WebSocket ticker
+    ///
+    /// Shorthand for [`ticker`](Self::ticker) followed by subscribing the
+    /// tokens in `mode`; see [`KiteTicker`] for reconnection and backpressure
+    /// behaviour. Dropping the stream closes the connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::connect::KiteConnect;
+    /// use kiteconnect_async_wasm::models::market_data::TickMode;
+    /// use futures_util::StreamExt;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = KiteConnect::new("api_key", "access_token");
+    ///
+    /// let ticks = client.subscribe(vec![408065, 884737], TickMode::Full).await?;
+    /// futures_util::pin_mut!(ticks);
+    ///
+    /// while let Some(tick) = ticks.next().await {
+    ///     println!("{}: {}", tick.instrument_token, tick.last_price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe(
+        &self,
+        tokens: Vec<u32>,
+        mode: TickMode,
+    ) -> KiteResult<impl Stream<Item = Tick> + Send + 'static> {
+        let ticker = self.ticker().await?;
+        ticker.set_mode(mode, &tokens)?;
+        Ok(ticker)
+    }
+}
+
+/// Where and as whom the ticker connects
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+struct TickerConnection {
+    base_url: reqwest::Url,
+    api_key: String,
+    access_token: TokenSource,
+}
+
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+impl TickerConnection {
+    /// Ticker URL carrying the current access token
+    fn url(&self) -> reqwest::Url {
+        let mut url = self.base_url.clone();
+        url.query_pairs_mut()
+            .append_pair("api_key", &self.api_key)
+            .append_pair("access_token", &(self.access_token)());
+        url
+    }
+}
+
+/// Forward ticks from the socket and subscription changes to it,
+/// reconnecting after drops
+#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+async fn run_ticker(
+    mut socket: WebSocket,
+    connection: TickerConnection,
+    config: TickerConfig,
+    subscriptions: Arc<Mutex<TickerSubscriptions>>,
+    mut commands: mpsc::UnboundedReceiver<String>,
+    sender: mpsc::Sender<Tick>,
+) {
+    loop {
+        loop {
+            tokio::select! {
+                _ = sender.closed() => return,
+                command = commands.recv() => match command {
+                    Some(message) => {
+                        if socket.send_text(&message).await.is_err() {
+                            break;
+                        }
+                    }
+                    None => return,
+                },
+                message = socket.recv() => match message {
+                    Ok(Message::Binary(frame)) => match Tick::parse_frame(&frame) {
+                        Ok(ticks) => {
+                            for tick in ticks {
+                                if sender.send(tick).await.is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                        #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+                        Err(e) => {
+                            #[cfg(feature = "debug")]
+                            log::warn!("Skipping undecodable tick frame: {}", e);
+                        }
+                    },
+                    // Order updates and error messages are not part of the tick stream
+                    #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
+                    Ok(Message::Text(text)) => {
+                        #[cfg(feature = "debug")]
+                        log::debug!("Ticker message: {}", text);
+                    }
+                    Ok(Message::Close) | Err(_) => break,
+                },
+            }
+        }
+
+        let mut attempt = 0;
+        socket = loop {
+            let Some(delay) = config.reconnect_delay(attempt) else {
+                return;
+            };
+            tokio::time::sleep(delay).await;
+            if sender.is_closed() {
+                return;
+            }
+
+            if let Ok(mut socket) = WebSocket::connect(&connection.url()).await {
+                // Changes made while disconnected are covered by the replay
+                if config.resubscribe_on_reconnect {
+                    while commands.try_recv().is_ok() {}
+                }
+                let messages = {
+                    let subscriptions = subscriptions
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                    config.messages_after_reconnect(&subscriptions)
+                };
+
+                let mut restored = true;
+                for message in messages {
+                    if socket.send_text(&message).await.is_err() {
+                        restored = false;
+                        break;
+                    }
+                }
+                if restored {
+                    break socket;
+                }
+            }
+            attempt += 1;
+        };
+    }
+}
+
+fn subscribe_message(tokens: &[u32]) -> String {
+    json!({"a": "subscribe", "v": tokens}).to_string()
+}
+
+fn mode_message(mode: TickMode, tokens: &[u32]) -> String {
+    json!({"a": "mode", "v": [mode.to_string(), tokens]}).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_backoff_is_capped_exponential() {
+        let mut config = TickerConfig::default();
+        assert_eq!(config.reconnect_backoff.jitter, JitterMode::Full);
+        assert!(config.reconnect_delay(3).unwrap() <= Duration::from_secs(8));
+        assert!(config.reconnect_delay(20).unwrap() <= Duration::from_secs(60));
+
+        // The upper bound of the jittered delay doubles up to the cap
+        config.reconnect_backoff.jitter_rng = JitterRng::fixed(1.0);
+        assert_eq!(config.reconnect_delay(0), Some(Duration::from_secs(1)));
+        assert_eq!(config.reconnect_delay(3), Some(Duration::from_secs(8)));
+        assert_eq!(config.reconnect_delay(20), Some(Duration::from_secs(60)));
+        assert_eq!(config.reconnect_delay(50), None);
+
+        let disabled = TickerConfig {
+            reconnect: false,
+            ..Default::default()
+        };
+        assert_eq!(disabled.reconnect_delay(0), None);
+    }
+
+    #[test]
+    fn test_subscriptions_are_resent_after_drop() {
+        let config = TickerConfig::default();
+        let mut subscriptions = TickerSubscriptions::new();
+        subscriptions.subscribe(&[408065, 884737], TickMode::Full);
+        subscriptions.subscribe(&[256265], TickMode::Ltp);
+        subscriptions.unsubscribe(&[884737]);
+
+        // Connection drops; the first retry is allowed and replays state
+        assert!(config.reconnect_delay(0).is_some());
+        let replay = config.messages_after_reconnect(&subscriptions);
+        assert_eq!(
+            replay,
+            vec![
+                r#"{"a":"subscribe","v":[256265]}"#,
+                r#"{"a":"mode","v":["ltp",[256265]]}"#,
+                r#"{"a":"subscribe","v":[408065]}"#,
+                r#"{"a":"mode","v":["full",[408065]]}"#,
+            ]
+        );
+
+        let no_replay = TickerConfig {
+            resubscribe_on_reconnect: false,
+            ..Default::default()
+        };
+        assert!(no_replay
+            .messages_after_reconnect(&subscriptions)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_set_mode_only_subscribes_new_tokens() {
+        let mut subscriptions = TickerSubscriptions::new();
+        subscriptions.subscribe(&[408065], TickMode::Quote);
+
+        let messages = subscriptions.set_mode(&[408065, 738561], TickMode::Full);
+        assert_eq!(
+            messages,
+            vec![
+                r#"{"a":"subscribe","v":[738561]}"#,
+                r#"{"a":"mode","v":["full",[408065,738561]]}"#,
+            ]
+        );
+        assert_eq!(subscriptions.mode(408065), Some(TickMode::Full));
+        assert_eq!(subscriptions.mode(738561), Some(TickMode::Full));
+    }
+}
@@ -0,0 +1,99 @@
+//! WebSocket connection used by the ticker
+//!
+//! A thin wrapper over `tokio-tungstenite`, which validates the opening
+//! handshake (including `Sec-WebSocket-Accept`), reassembles fragmented
+//! messages, answers pings and echoes close frames. This module only maps its
+//! messages and errors onto what the ticker needs.
+
+use crate::models::common::{KiteError, KiteResult};
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::tungstenite::{self, client::IntoClientRequest};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+/// Largest message accepted from the server, after reassembling fragments
+const MAX_MESSAGE_LEN: usize = 16 * 1024 * 1024;
+
+/// Largest single frame accepted from the server
+const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// A complete message received from the server
+#[derive(Debug)]
+pub(crate) enum Message {
+    /// Text message; the ticker only sends these for order updates and errors
+    Text(String),
+    Binary(Vec<u8>),
+    /// The server closed the connection; the close has been echoed
+    Close,
+}
+
+/// An open WebSocket connection
+#[derive(Debug)]
+pub(crate) struct WebSocket {
+    inner: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl WebSocket {
+    /// Connect and perform the opening handshake
+    pub(crate) async fn connect(url: &reqwest::Url) -> KiteResult<Self> {
+        let request = url
+            .as_str()
+            .into_client_request()
+            .map_err(|e| KiteError::invalid_param(format!("Invalid ticker URL {}: {}", url, e)))?;
+        let config = WebSocketConfig::default()
+            .max_message_size(Some(MAX_MESSAGE_LEN))
+            .max_frame_size(Some(MAX_FRAME_LEN));
+
+        let (inner, _response) =
+            tokio_tungstenite::connect_async_with_config(request, Some(config), false)
+                .await
+                .map_err(|e| match e {
+                    tungstenite::Error::Url(e) => {
+                        KiteError::invalid_param(format!("Invalid ticker URL {}: {}", url, e))
+                    }
+                    e => KiteError::network_exception(format!("Ticker connect failed: {}", e)),
+                })?;
+        Ok(Self { inner })
+    }
+
+    /// Send a text message
+    pub(crate) async fn send_text(&mut self, text: &str) -> KiteResult<()> {
+        self.inner
+            .send(tungstenite::Message::text(text))
+            .await
+            .map_err(ws_error)
+    }
+
+    /// Receive the next message
+    ///
+    /// Cancel-safe, so it can be raced in `tokio::select!` against outgoing
+    /// writes without losing data. Pings are answered internally.
+    pub(crate) async fn recv(&mut self) -> KiteResult<Message> {
+        loop {
+            let message = match self.inner.next().await {
+                Some(message) => message.map_err(ws_error)?,
+                None => return Err(KiteError::network_exception("Ticker connection closed")),
+            };
+
+            match message {
+                tungstenite::Message::Binary(data) => return Ok(Message::Binary(data.into())),
+                tungstenite::Message::Text(text) => {
+                    return Ok(Message::Text(text.as_str().to_string()))
+                }
+                tungstenite::Message::Close(_) => {
+                    // Sends the close reply queued by tungstenite
+                    let _ = self.inner.flush().await;
+                    return Ok(Message::Close);
+                }
+                tungstenite::Message::Ping(_)
+                | tungstenite::Message::Pong(_)
+                | tungstenite::Message::Frame(_) => {}
+            }
+        }
+    }
+}
+
+fn ws_error(e: tungstenite::Error) -> KiteError {
+    KiteError::network_exception(format!("Ticker connection error: {}", e))
+}
@@ -116,6 +116,11 @@
 //!   - Includes: Enhanced `log` output and debugging utilities
 //!   - Best for: Development and troubleshooting
 //!
+//! - **`legacy`** (default): Keeps the JSON-returning methods that have typed equivalents
+//!   (`holdings()`, `quote()`, `generate_session()`, ...)
+//!   - Disable with `default-features = false, features = ["native"]` for a typed-only API
+//!   - See `MIGRATION_GUIDE.md` for the method mapping
+//!
 //! ## Basic Usage
 //!
 //! ```rust,no_run
@@ -172,9 +177,34 @@
 //! - `mf_orders()` - Get mutual fund orders
 //! - `mf_instruments()` - Get mutual fund instruments
 //!
+//! ### Ticker (native)
+//! - `ticker()` - Open a `KiteTicker` to subscribe, change modes and stream ticks
+//! - `subscribe()` - Stream decoded ticks over the WebSocket ticker
+//!
 //! ## Error Handling
 //!
-//! The library uses `anyhow::Result` for comprehensive error handling:
+//! Strongly-typed methods return [`KiteResult<T>`], an alias for
+//! `Result<T, KiteError>`. Both are re-exported at the crate root and from
+//! [`prelude`], so callers can match on specific failures:
+//!
+//! ```rust,no_run
+//! use kiteconnect_async_wasm::prelude::*;
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! # let kiteconnect = KiteConnect::new("", "");
+//! let holdings: KiteResult<Vec<Holding>> = kiteconnect.holdings_typed().await;
+//! match holdings {
+//!     Ok(holdings) => println!("{} holdings", holdings.len()),
+//!     Err(KiteError::TokenException(_)) => eprintln!("Session expired, log in again"),
+//!     Err(e) => eprintln!("Error fetching holdings: {}", e),
+//! }
+//! # }
+//! ```
+//!
+//! The legacy JSON methods keep returning `anyhow::Result` for backward
+//! compatibility; a `KiteError` raised inside them can be recovered with
+//! `anyhow::Error::downcast_ref::<KiteError>()`:
 //!
 //! ```rust,no_run
 //! # use kiteconnect_async_wasm::connect::KiteConnect;
@@ -238,3 +268,25 @@ extern crate mockito;
 
 pub mod connect;
 pub mod models;
+
+pub use models::common::{KiteError, KiteResult};
+
+/// Prelude module for convenient imports
+///
+/// Brings the client, its configuration types, the error alias and the
+/// commonly used models into scope with a single `use` statement
+///
+/// ```rust
+/// use kiteconnect_async_wasm::prelude::*;
+///
+/// fn check() -> KiteResult<()> {
+///     Err(KiteError::Shutdown)
+/// }
+/// # assert!(check().is_err());
+/// ```
+pub mod prelude {
+    pub use crate::connect::{
+        CacheConfig, JitterMode, KiteConnect, KiteConnectBuilder, KiteConnectConfig, RetryConfig,
+    };
+    pub use crate::models::prelude::*;
+}
@@ -5,6 +5,7 @@ Handles user margins, segment-wise balances, and fund information.
 */
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Complete margin data from the `margins` API
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -160,6 +161,18 @@ impl MarginData {
         }
     }
 
+    /// Split into a map of the segments that are present
+    pub fn into_segments(self) -> HashMap<TradingSegment, SegmentMargin> {
+        let mut segments = HashMap::new();
+        if let Some(equity) = self.equity {
+            segments.insert(TradingSegment::Equity, equity);
+        }
+        if let Some(commodity) = self.commodity {
+            segments.insert(TradingSegment::Commodity, commodity);
+        }
+        segments
+    }
+
     /// Get total available cash across all segments
     pub fn total_cash(&self) -> f64 {
         let mut total = 0.0;
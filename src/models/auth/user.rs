@@ -5,7 +5,7 @@ Handles user details, account types, and user preferences.
 */
 
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// User profile information from the `profile` API
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,13 +47,21 @@ pub struct UserProfile {
 }
 
 /// Additional user metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UserMeta {
-    /// Demat consent status
-    #[serde(default)]
+    /// Demat consent status; empty for accounts that don't report it
+    #[serde(default, deserialize_with = "deserialize_string_or_empty")]
     pub demat_consent: String,
 }
 
+/// Treat a missing or null string as empty (some accounts omit demat consent)
+fn deserialize_string_or_empty<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 impl UserProfile {
     /// Check if user has access to a specific exchange
     pub fn has_exchange(&self, exchange: &str) -> bool {
@@ -213,6 +221,27 @@ mod tests {
         assert_eq!(profile.display_name(), "Test User");
     }
 
+    #[test]
+    fn test_user_profile_without_demat_consent() {
+        let base = r#""user_id": "AB1234", "user_name": "Test User", "user_shortname": "Test",
+            "user_type": "individual", "email": "test@example.com", "avatar_url": null,
+            "broker": "ZERODHA", "exchanges": ["NSE"], "products": ["CNC"],
+            "order_types": ["LIMIT"]"#;
+
+        let no_meta: UserProfile = serde_json::from_str(&format!("{{{}}}", base)).unwrap();
+        assert!(no_meta.meta.is_none());
+
+        for meta in [r#"{}"#, r#"{"demat_consent": null}"#] {
+            let json = format!(r#"{{{}, "meta": {}}}"#, base, meta);
+            let profile: UserProfile = serde_json::from_str(&json).unwrap();
+            assert_eq!(profile.meta.unwrap().demat_consent, "");
+        }
+
+        let json = format!(r#"{{{}, "meta": {{"demat_consent": "physical"}}}}"#, base);
+        let profile: UserProfile = serde_json::from_str(&json).unwrap();
+        assert_eq!(profile.meta.unwrap().demat_consent, "physical");
+    }
+
     #[test]
     fn test_user_type() {
         let individual = UserType::Individual;
@@ -8,7 +8,7 @@ characteristics such as trading hours, instrument types, and settlement processe
 # Example
 
 ```rust
-use kiteconnect_async_wasm::models::common::Exchange;
+use kiteconnect_async_wasm::models::common::{Exchange, ExchangeCode};
 
 // Check exchange types
 assert!(Exchange::NSE.is_equity());
@@ -18,10 +18,19 @@ assert!(Exchange::MCX.is_commodity());
 // Get all available exchanges
 let all_exchanges = Exchange::all();
 println!("Available exchanges: {}", all_exchanges.len());
+
+// Parsing never fails; venues this crate doesn't know yet are kept as-is
+assert_eq!("BCD".parse::<Exchange>().unwrap(), Exchange::BCD);
+assert_eq!(
+    "NEWX".parse::<Exchange>().unwrap(),
+    Exchange::Unknown(ExchangeCode::new("NEWX"))
+);
 ```
 */
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::str::FromStr;
 
 /// Stock exchanges supported by KiteConnect
 ///
@@ -33,8 +42,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// - **Equity**: NSE, BSE, NSEIX - Cash market trading in stocks
 /// - **Derivatives**: NFO, BFO - Futures and options trading
-/// - **Commodity**: MCX, CDS, NCO - Commodity futures and options
+/// - **Commodity**: MCX, CDS, BCD, NCO - Commodity and currency futures and options
 /// - **Global**: GLOBAL - International markets and instruments
+/// - **Mutual funds**: MF - Mutual fund orders
+///
+/// Venues not listed here are parsed into [`Exchange::Unknown`] so new
+/// exchanges introduced by Zerodha don't break deserialization.
 ///
 /// # Trading Hours
 ///
@@ -53,7 +66,7 @@ use serde::{Deserialize, Serialize};
 ///     println!("This is an equity exchange");
 /// }
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Exchange {
     /// National Stock Exchange of India (NSE)
     ///
@@ -61,7 +74,6 @@ pub enum Exchange {
     /// - Equity cash market
     /// - ETFs and mutual funds
     /// - Government securities
-    #[serde(rename = "NSE")]
     NSE,
 
     /// Bombay Stock Exchange (BSE)
@@ -70,7 +82,6 @@ pub enum Exchange {
     /// - Equity cash market  
     /// - SME platform
     /// - Government securities
-    #[serde(rename = "BSE")]
     BSE,
 
     /// NSE Futures & Options (NFO)
@@ -79,7 +90,6 @@ pub enum Exchange {
     /// - Index futures and options
     /// - Stock futures and options
     /// - Currency derivatives
-    #[serde(rename = "NFO")]
     NFO,
 
     /// Currency Derivatives Segment (CDS)
@@ -88,7 +98,6 @@ pub enum Exchange {
     /// - Currency futures
     /// - Currency options
     /// - Cross-currency pairs
-    #[serde(rename = "CDS")]
     CDS,
 
     /// BSE Futures & Options (BFO)
@@ -97,9 +106,15 @@ pub enum Exchange {
     /// - Index derivatives
     /// - Stock derivatives
     /// - Weekly options
-    #[serde(rename = "BFO")]
     BFO,
 
+    /// BSE Currency Derivatives (BCD)
+    ///
+    /// Currency segment of BSE. Supports:
+    /// - Currency futures
+    /// - Currency options
+    BCD,
+
     /// Multi Commodity Exchange (MCX)
     ///
     /// India's largest commodity exchange. Supports:
@@ -107,14 +122,12 @@ pub enum Exchange {
     /// - Base metals (copper, aluminum)
     /// - Energy commodities (crude oil, natural gas)
     /// - Agricultural commodities
-    #[serde(rename = "MCX")]
     MCX,
 
     /// Global/International markets
     ///
     /// Access to international instruments and markets.
     /// Availability depends on broker permissions.
-    #[serde(rename = "GLOBAL")]
     GLOBAL,
 
     /// National Commodity & Derivatives Exchange (NCDEX)
@@ -123,7 +136,6 @@ pub enum Exchange {
     /// - Agricultural futures
     /// - Agricultural options
     /// - Weather derivatives
-    #[serde(rename = "NCO")]
     NCO,
 
     /// NSE Indices Exchange (NSEIX)
@@ -131,12 +143,23 @@ pub enum Exchange {
     /// Index-based trading platform. Supports:
     /// - Index trading
     /// - Specialized index products
-    #[serde(rename = "NSEIX")]
     NSEIX,
+
+    /// Mutual funds (MF)
+    ///
+    /// Exchange reported for mutual fund instruments and orders.
+    MF,
+
+    /// An exchange not known to this version of the crate
+    ///
+    /// Holds the code exactly as received and serializes back to it.
+    Unknown(ExchangeCode),
 }
 
 impl Exchange {
     /// Get all supported exchanges
+    ///
+    /// Does not include [`Exchange::Unknown`].
     pub fn all() -> Vec<Self> {
         vec![
             Exchange::NSE,
@@ -144,46 +167,221 @@ impl Exchange {
             Exchange::NFO,
             Exchange::CDS,
             Exchange::BFO,
+            Exchange::BCD,
             Exchange::MCX,
             Exchange::GLOBAL,
             Exchange::NCO,
             Exchange::NSEIX,
+            Exchange::MF,
         ]
     }
 
     /// Check if exchange supports equity trading
-    pub fn is_equity(self) -> bool {
+    pub fn is_equity(&self) -> bool {
         matches!(self, Exchange::NSE | Exchange::BSE | Exchange::NSEIX)
     }
 
     /// Check if exchange supports derivatives trading
-    pub fn is_derivative(self) -> bool {
+    pub fn is_derivative(&self) -> bool {
         matches!(self, Exchange::NFO | Exchange::BFO)
     }
 
     /// Check if exchange supports commodity trading
-    pub fn is_commodity(self) -> bool {
-        matches!(self, Exchange::MCX | Exchange::CDS | Exchange::NCO)
+    pub fn is_commodity(&self) -> bool {
+        matches!(
+            self,
+            Exchange::MCX | Exchange::CDS | Exchange::BCD | Exchange::NCO
+        )
     }
 
     /// Check if exchange is international/global
-    pub fn is_global(self) -> bool {
+    pub fn is_global(&self) -> bool {
         matches!(self, Exchange::GLOBAL)
     }
+
+    /// Get the market segment traded on this exchange
+    ///
+    /// Unlike [`is_commodity`](Self::is_commodity), this separates currency
+    /// derivatives (CDS) from commodities.
+    pub fn market_segment(&self) -> MarketSegment {
+        match self {
+            Exchange::NSE | Exchange::BSE | Exchange::NSEIX => MarketSegment::Equity,
+            Exchange::NFO | Exchange::BFO => MarketSegment::Derivative,
+            Exchange::CDS | Exchange::BCD => MarketSegment::Currency,
+            Exchange::MCX | Exchange::NCO => MarketSegment::Commodity,
+            Exchange::GLOBAL => MarketSegment::Global,
+            Exchange::MF => MarketSegment::MutualFund,
+            Exchange::Unknown(_) => MarketSegment::Unknown,
+        }
+    }
+
+    /// Exchange code as used by the Kite API, e.g. `"NSE"`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Exchange::NSE => "NSE",
+            Exchange::BSE => "BSE",
+            Exchange::NFO => "NFO",
+            Exchange::CDS => "CDS",
+            Exchange::BFO => "BFO",
+            Exchange::BCD => "BCD",
+            Exchange::MCX => "MCX",
+            Exchange::GLOBAL => "GLOBAL",
+            Exchange::NCO => "NCO",
+            Exchange::NSEIX => "NSEIX",
+            Exchange::MF => "MF",
+            Exchange::Unknown(code) => code.as_str(),
+        }
+    }
+
+    /// Whether this is a venue known to this version of the crate
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Exchange::Unknown(_))
+    }
+}
+
+/// Code of an exchange not known to this crate
+///
+/// Stored inline so [`Exchange`] stays `Copy`. Codes longer than
+/// [`ExchangeCode::MAX_LEN`] bytes are truncated.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExchangeCode {
+    bytes: [u8; ExchangeCode::MAX_LEN],
+    len: u8,
+}
+
+impl ExchangeCode {
+    /// Longest code kept, in bytes
+    pub const MAX_LEN: usize = 16;
+
+    /// Store `code`, truncated to [`MAX_LEN`](Self::MAX_LEN) bytes
+    pub fn new(code: &str) -> Self {
+        let mut len = code.len().min(Self::MAX_LEN);
+        while !code.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut bytes = [0; Self::MAX_LEN];
+        bytes[..len].copy_from_slice(&code.as_bytes()[..len]);
+        Self {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// The code as received
+    pub fn as_str(&self) -> &str {
+        // Always a prefix of a `str` cut at a char boundary
+        std::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or_default()
+    }
+}
+
+impl std::fmt::Debug for ExchangeCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl std::fmt::Display for ExchangeCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Broad market segment of an exchange
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MarketSegment {
+    /// Cash equity (NSE, BSE, NSEIX)
+    Equity,
+    /// Equity futures and options (NFO, BFO)
+    Derivative,
+    /// Currency derivatives (CDS, BCD)
+    Currency,
+    /// Commodity futures and options (MCX, NCO)
+    Commodity,
+    /// International markets (GLOBAL)
+    Global,
+    /// Mutual funds (MF)
+    MutualFund,
+    /// Exchanges not known to this crate
+    Unknown,
 }
 
 impl std::fmt::Display for Exchange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Exchange::NSE => write!(f, "NSE"),
-            Exchange::BSE => write!(f, "BSE"),
-            Exchange::NFO => write!(f, "NFO"),
-            Exchange::CDS => write!(f, "CDS"),
-            Exchange::BFO => write!(f, "BFO"),
-            Exchange::MCX => write!(f, "MCX"),
-            Exchange::GLOBAL => write!(f, "GLOBAL"),
-            Exchange::NCO => write!(f, "NCO"),
-            Exchange::NSEIX => write!(f, "NSEIX"),
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Exchange {
+    type Err = Infallible;
+
+    /// Parse an exchange code, case-insensitively
+    ///
+    /// Unrecognised codes become [`Exchange::Unknown`] rather than an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.trim();
+        let exchange = Exchange::all()
+            .into_iter()
+            .find(|exchange| exchange.as_str().eq_ignore_ascii_case(code))
+            .unwrap_or_else(|| Exchange::Unknown(ExchangeCode::new(code)));
+        Ok(exchange)
+    }
+}
+
+impl Serialize for Exchange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Exchange {
+    /// Deserialize an exchange code, case-insensitively like [`FromStr`]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ExchangeVisitor;
+
+        impl serde::de::Visitor<'_> for ExchangeVisitor {
+            type Value = Exchange;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an exchange code")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, code: &str) -> Result<Exchange, E> {
+                Ok(code.parse().unwrap_or_else(|never| match never {}))
+            }
         }
+
+        deserializer.deserialize_str(ExchangeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exchange_round_trips_every_venue() {
+        for exchange in Exchange::all() {
+            let code = exchange.to_string();
+            assert_eq!(code.parse::<Exchange>().unwrap(), exchange);
+
+            let json = serde_json::to_string(&exchange).unwrap();
+            assert_eq!(json, format!("\"{}\"", code));
+            assert_eq!(serde_json::from_str::<Exchange>(&json).unwrap(), exchange);
+        }
+        assert_eq!("nfo".parse::<Exchange>().unwrap(), Exchange::NFO);
+        assert_eq!(
+            serde_json::from_str::<Exchange>("\"nfo\"").unwrap(),
+            Exchange::NFO
+        );
+    }
+
+    #[test]
+    fn test_unknown_exchange_round_trips() {
+        let exchange: Exchange = serde_json::from_str("\"NEWX\"").unwrap();
+        assert_eq!(exchange, Exchange::Unknown(ExchangeCode::new("NEWX")));
+        assert!(!exchange.is_known());
+        assert_eq!(exchange.market_segment(), MarketSegment::Unknown);
+        assert_eq!(serde_json::to_string(&exchange).unwrap(), "\"NEWX\"");
+        assert_eq!(exchange.to_string(), "NEWX");
     }
 }
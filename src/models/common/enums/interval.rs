@@ -164,16 +164,7 @@ pub enum Interval {
 
 impl std::fmt::Display for Interval {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Interval::Day => write!(f, "day"),
-            Interval::Minute => write!(f, "minute"),
-            Interval::ThreeMinute => write!(f, "3minute"),
-            Interval::FiveMinute => write!(f, "5minute"),
-            Interval::TenMinute => write!(f, "10minute"),
-            Interval::FifteenMinute => write!(f, "15minute"),
-            Interval::ThirtyMinute => write!(f, "30minute"),
-            Interval::SixtyMinute => write!(f, "60minute"),
-        }
+        f.write_str(self.to_api_str())
     }
 }
 
@@ -184,7 +175,7 @@ impl serde::Serialize for Interval {
         S: serde::Serializer,
     {
         // Try to serialize as string first (for compatibility)
-        serializer.serialize_str(&self.to_string())
+        serializer.serialize_str(self.to_api_str())
     }
 }
 
@@ -348,49 +339,70 @@ impl Interval {
         ]
     }
 
-    /// Get the maximum number of days allowed for historical data retrieval for this interval
+    /// Get the interval string used by the historical data API
     ///
-    /// These limits are based on KiteConnect API restrictions for historical data requests.
-    /// Attempting to retrieve data beyond these limits will result in API errors.
+    /// # Example
     ///
-    /// # Returns
+    /// ```rust
+    /// use kiteconnect_async_wasm::models::common::Interval;
+    ///
+    /// assert_eq!(Interval::Minute.to_api_str(), "minute");
+    /// assert_eq!(Interval::FifteenMinute.to_api_str(), "15minute");
+    /// ```
+    pub fn to_api_str(&self) -> &'static str {
+        match self {
+            Interval::Day => "day",
+            Interval::Minute => "minute",
+            Interval::ThreeMinute => "3minute",
+            Interval::FiveMinute => "5minute",
+            Interval::TenMinute => "10minute",
+            Interval::FifteenMinute => "15minute",
+            Interval::ThirtyMinute => "30minute",
+            Interval::SixtyMinute => "60minute",
+        }
+    }
+
+    /// Get KiteConnect's published maximum range, in days, for one request
     ///
-    /// Maximum number of days that can be requested in a single historical data call
+    /// Longer ranges are rejected by the API. `historical_data_typed` splits
+    /// them into chunks of at most this many days.
     ///
     /// # Limits
     ///
     /// - **Daily**: 2000 days (about 5.5 years)
-    /// - **1-minute**: 30 days
-    /// - **3-minute**: 90 days  
-    /// - **5-minute**: 90 days
-    /// - **10-minute**: 90 days
-    /// - **15-minute**: 180 days
-    /// - **30-minute**: 180 days
-    /// - **60-minute**: 365 days (1 year)
+    /// - **1-minute**: 60 days
+    /// - **3, 5 and 10-minute**: 100 days
+    /// - **15 and 30-minute**: 200 days
+    /// - **60-minute**: 400 days
     ///
     /// # Example
     ///
     /// ```rust
     /// use kiteconnect_async_wasm::models::common::Interval;
     ///
-    /// assert_eq!(Interval::Day.max_days_allowed(), 2000);
-    /// assert_eq!(Interval::Minute.max_days_allowed(), 30);
-    /// assert_eq!(Interval::FiveMinute.max_days_allowed(), 90);
-    /// assert_eq!(Interval::SixtyMinute.max_days_allowed(), 365);
+    /// assert_eq!(Interval::Minute.max_days(), 60);
+    /// assert_eq!(Interval::FiveMinute.max_days(), 100);
+    /// assert_eq!(Interval::Day.max_days(), 2000);
     /// ```
-    pub fn max_days_allowed(&self) -> u32 {
+    pub fn max_days(&self) -> u32 {
         match self {
             Interval::Day => 2000,
-            Interval::Minute => 30,
-            Interval::ThreeMinute => 90,
-            Interval::FiveMinute => 90,
-            Interval::TenMinute => 90,
-            Interval::FifteenMinute => 180,
-            Interval::ThirtyMinute => 180,
-            Interval::SixtyMinute => 365,
+            Interval::Minute => 60,
+            Interval::ThreeMinute => 100,
+            Interval::FiveMinute => 100,
+            Interval::TenMinute => 100,
+            Interval::FifteenMinute => 200,
+            Interval::ThirtyMinute => 200,
+            Interval::SixtyMinute => 400,
         }
     }
 
+    /// Maximum days allowed per request for this interval
+    #[deprecated(note = "use max_days")]
+    pub fn max_days_allowed(&self) -> u32 {
+        self.max_days()
+    }
+
     /// Check if a date range is within the allowed limits for this interval
     ///
     /// # Arguments
@@ -422,7 +434,7 @@ impl Interval {
         let duration = *to - *from;
         let days = duration.num_days() as u32;
         
-        days <= self.max_days_allowed()
+        days <= self.max_days()
     }
 
     /// Calculate the maximum allowed "to" date given a "from" date for this interval
@@ -447,6 +459,37 @@ impl Interval {
     /// println!("For 5-minute data starting {}, maximum end date is {}", from, max_to);
     /// ```
     pub fn max_to_date(&self, from: &chrono::NaiveDateTime) -> chrono::NaiveDateTime {
-        *from + chrono::Duration::days(self.max_days_allowed() as i64)
+        *from + chrono::Duration::days(self.max_days() as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_days_matches_published_limits() {
+        let expected = [
+            (Interval::Day, 2000),
+            (Interval::Minute, 60),
+            (Interval::ThreeMinute, 100),
+            (Interval::FiveMinute, 100),
+            (Interval::TenMinute, 100),
+            (Interval::FifteenMinute, 200),
+            (Interval::ThirtyMinute, 200),
+            (Interval::SixtyMinute, 400),
+        ];
+        for (interval, days) in expected {
+            assert_eq!(interval.max_days(), days, "{}", interval);
+        }
+    }
+
+    #[test]
+    fn test_to_api_str_round_trips() {
+        for interval in Interval::all() {
+            let json = format!("\"{}\"", interval.to_api_str());
+            assert_eq!(serde_json::from_str::<Interval>(&json).unwrap(), interval);
+            assert_eq!(interval.to_string(), interval.to_api_str());
+        }
     }
 }
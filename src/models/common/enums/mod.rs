@@ -20,7 +20,7 @@ pub mod interval;
 pub mod trading;
 
 // Re-export all enums for backward compatibility
-pub use exchange::Exchange;
+pub use exchange::{Exchange, ExchangeCode, MarketSegment};
 pub use gtt::GttStatus;
 pub use instruments::{InstrumentType, Segment};
 pub use interval::Interval;
@@ -15,6 +15,10 @@ pub enum Product {
     MIS, // Margin Intraday Squareoff for futures and options
     #[serde(rename = "MTF")]
     MTF, // Margin Trading Facility
+    #[serde(rename = "CO")]
+    CO, // Cover order, reported on cover order positions
+    #[serde(rename = "BO")]
+    BO, // Bracket order, reported on older bracket order positions
 }
 
 impl std::fmt::Display for Product {
@@ -24,6 +28,8 @@ impl std::fmt::Display for Product {
             Product::MIS => write!(f, "MIS"),
             Product::NRML => write!(f, "NRML"),
             Product::MTF => write!(f, "MTF"),
+            Product::CO => write!(f, "CO"),
+            Product::BO => write!(f, "BO"),
         }
     }
 }
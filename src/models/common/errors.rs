@@ -4,6 +4,9 @@ Custom error types for KiteConnect operations using `thiserror`.
 Provides comprehensive error handling with proper error chaining and context.
 */
 
+use serde::de::DeserializeOwned;
+use serde_json::Value as JsonValue;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Main error type for all KiteConnect operations
@@ -11,12 +14,37 @@ use thiserror::Error;
 pub enum KiteError {
     /// HTTP request failed
     #[error("HTTP request failed: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[source] reqwest::Error),
+
+    /// HTTP request timed out before the server responded
+    ///
+    /// Raised instead of [`KiteError::Http`] when the underlying
+    /// `reqwest::Error` reports a timeout. Always retryable.
+    #[error("HTTP request timed out: {0}")]
+    Timeout(#[source] reqwest::Error),
 
     /// JSON parsing failed
     #[error("JSON parsing failed: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// JSON deserialization into a typed model failed at a known location
+    ///
+    /// `path` is the location of the offending field (e.g. `data[2].quantity`),
+    /// `expected` describes what was expected there, `line`/`column` refer to
+    /// the pretty-printed payload and `snippet` shows the surrounding lines
+    /// with sensitive values redacted. The message leaves out the serde error,
+    /// which can quote the offending value; it stays reachable as the source.
+    #[error("JSON parsing failed at `{path}` (line {line}, column {column}): expected {expected}\n{snippet}")]
+    JsonContext {
+        path: String,
+        expected: String,
+        line: usize,
+        column: usize,
+        snippet: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
     // === Official KiteConnect API Exception Types ===
     /// Session expired or invalidated (403 header)
     /// User should clear session and re-initiate login
@@ -64,6 +92,23 @@ pub enum KiteError {
         error_type: Option<String>,
     },
 
+    /// Too many requests (HTTP 429)
+    ///
+    /// `retry_after` holds the server's `Retry-After` hint when one was sent;
+    /// see [`KiteError::retry_after`].
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+
+    /// Request token was already exchanged for a session or has expired
+    ///
+    /// Request tokens are single-use; the login flow must be restarted to
+    /// obtain a fresh one. Never retried automatically.
+    #[error("Request token already used or expired: {0}. Restart the login flow to obtain a new request_token")]
+    RequestTokenUsed(String),
+
     /// Authentication failed (generic)
     #[error("Authentication failed: {0}")]
     Authentication(String),
@@ -85,6 +130,10 @@ pub enum KiteError {
     #[error("URL parsing failed: {0}")]
     UrlParsing(#[from] url::ParseError),
 
+    /// The client was shut down with `KiteConnect::shutdown`
+    #[error("KiteConnect client has been shut down")]
+    Shutdown,
+
     /// General error with custom message
     #[error("KiteConnect error: {0}")]
     General(String),
@@ -97,6 +146,16 @@ pub enum KiteError {
 /// Result type alias for KiteConnect operations
 pub type KiteResult<T> = Result<T, KiteError>;
 
+impl From<reqwest::Error> for KiteError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Self::Timeout(error)
+        } else {
+            Self::Http(error)
+        }
+    }
+}
+
 impl KiteError {
     /// Create a new API error from response
     /// Maps official KiteConnect error_type to specific exception types
@@ -108,6 +167,14 @@ impl KiteError {
     ) -> Self {
         let message = message.into();
 
+        // Rate limiting takes precedence over whatever error_type accompanies it
+        if status_code == 429 {
+            return Self::RateLimited {
+                message,
+                retry_after: None,
+            };
+        }
+
         // First, map based on error_type from API response
         if let Some(error_type) = error_type.as_ref() {
             return match error_type.as_str() {
@@ -147,11 +214,6 @@ impl KiteError {
                 message,
                 error_type: Some("ResourceGone".to_string()), // The requested resource is gone permanently
             },
-            429 => Self::Api {
-                status: status.into(),
-                message,
-                error_type: Some("RateLimited".to_string()), // Too many requests to the API (rate limiting)
-            },
             500 => Self::GeneralException(message), // Something unexpected went wrong
             502 => Self::NetworkException(message), // The backend OMS is down and the API is unable to communicate with it
             503 => Self::NetworkException(message), // Service unavailable; the API is down
@@ -164,6 +226,94 @@ impl KiteError {
         }
     }
 
+    /// Deserialize a JSON value into `T`, reporting the offending field on failure
+    ///
+    /// On a type mismatch or missing field the returned [`KiteError::JsonContext`]
+    /// names the JSON path of the field, its position in the pretty-printed
+    /// payload and a redacted snippet of the surrounding JSON.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::models::common::KiteError;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct Holding {
+    ///     quantity: i64,
+    /// }
+    ///
+    /// let payload = serde_json::json!([{ "quantity": 10 }, { "quantity": "ten" }]);
+    /// let err = KiteError::from_json_value::<Vec<Holding>>(&payload).unwrap_err();
+    /// assert!(err.to_string().contains("[1].quantity"));
+    /// ```
+    pub fn from_json_value<T: DeserializeOwned>(value: &JsonValue) -> KiteResult<T> {
+        T::deserialize(value).map_err(|error| Self::json_with_context::<T>(error, value))
+    }
+
+    /// Deserialize like [`from_json_value`](Self::from_json_value), tolerating nulls
+    ///
+    /// When strict deserialization fails, `null` object entries are dropped
+    /// and the payload is parsed once more, so those fields take their serde
+    /// default: `None` for optional fields, and `0` for the numeric fields of
+    /// the models that declare `#[serde(default)]`. Unknown fields are already
+    /// ignored by the models. Any other failure, such as an unknown enum
+    /// variant or a null in a field without a default, returns the strict error.
+    pub fn from_json_value_lenient<T: DeserializeOwned>(value: &JsonValue) -> KiteResult<T> {
+        let strict = match T::deserialize(value) {
+            Ok(parsed) => return Ok(parsed),
+            Err(error) => error,
+        };
+
+        let mut repaired = value.clone();
+        remove_nulls(&mut repaired);
+        // Report the original failure rather than one in the repaired payload
+        T::deserialize(&repaired).map_err(|_| Self::json_with_context::<T>(strict, value))
+    }
+
+    /// Attach path, position and a redacted snippet to a deserialization error
+    ///
+    /// Value-based errors carry no position, so the deserialization is replayed
+    /// against the pretty-printed payload to locate it. Falls back to
+    /// [`KiteError::Json`] if the failure cannot be located.
+    fn json_with_context<T: DeserializeOwned>(error: serde_json::Error, value: &JsonValue) -> Self {
+        let Ok(pretty) = serde_json::to_string_pretty(value) else {
+            return Self::Json(error);
+        };
+        let mut deserializer = serde_json::Deserializer::from_str(&pretty);
+        let located = match serde_path_to_error::deserialize::<_, T>(&mut deserializer) {
+            Err(replayed) if replayed.inner().line() > 0 => replayed,
+            _ => return Self::Json(error),
+        };
+        let (line, column) = (located.inner().line(), located.inner().column());
+        let path = located.path().to_string();
+
+        // Redaction only replaces scalars, so line numbers stay aligned
+        let mut redacted = value.clone();
+        redact_sensitive(&mut redacted);
+        let redacted = serde_json::to_string_pretty(&redacted).unwrap_or_default();
+        let snippet = redacted
+            .lines()
+            .enumerate()
+            .skip(line.saturating_sub(3))
+            .take(5)
+            .map(|(i, text)| {
+                let marker = if i + 1 == line { ">" } else { " " };
+                format!("{} {:>4} | {}", marker, i + 1, text)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Self::JsonContext {
+            path,
+            expected: expected_of(&error),
+            line,
+            column,
+            snippet,
+            source: error,
+        }
+    }
+
     /// Create a new API error (legacy method for backward compatibility)
     pub fn api_error(status: impl Into<String>, message: impl Into<String>) -> Self {
         Self::Api {
@@ -186,6 +336,43 @@ impl KiteError {
         }
     }
 
+    /// Attach a `Retry-After` hint to a rate-limit error
+    ///
+    /// Has no effect on other variants.
+    pub fn with_retry_after(mut self, hint: Option<Duration>) -> Self {
+        if let Self::RateLimited { retry_after, .. } = &mut self {
+            *retry_after = hint;
+        }
+        self
+    }
+
+    /// How long the server asked us to wait before retrying
+    ///
+    /// Populated for [`KiteError::RateLimited`] when the response carried a
+    /// `Retry-After` header; `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::models::common::KiteError;
+    /// use std::time::Duration;
+    ///
+    /// let err = KiteError::from_api_response(429, "429", "Too many requests", None)
+    ///     .with_retry_after(Some(Duration::from_secs(2)));
+    /// assert_eq!(err.retry_after(), Some(Duration::from_secs(2)));
+    /// ```
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Create a new request-token-already-used error
+    pub fn request_token_used(message: impl Into<String>) -> Self {
+        Self::RequestTokenUsed(message.into())
+    }
+
     /// Create a new authentication error
     pub fn auth_error(message: impl Into<String>) -> Self {
         Self::Authentication(message.into())
@@ -250,13 +437,37 @@ impl KiteError {
 
     /// Check if this error requires re-authentication
     pub fn requires_reauth(&self) -> bool {
-        matches!(self, Self::TokenException(_) | Self::Authentication(_))
+        matches!(
+            self,
+            Self::TokenException(_) | Self::Authentication(_) | Self::RequestTokenUsed(_)
+        )
+    }
+
+    /// Check if an order was rejected for lack of funds or margin
+    ///
+    /// Kite reports these as `MarginException`, but RMS rejections can also
+    /// arrive as an `InputException` or `OrderException` whose message reads
+    /// e.g. "Insufficient funds. Required margin is ...". Strategies can use
+    /// this to retry with a smaller size instead of treating it as bad input.
+    pub fn is_insufficient_funds(&self) -> bool {
+        let message = match self {
+            Self::MarginException(_) => return true,
+            Self::InputException(message)
+            | Self::OrderException(message)
+            | Self::Api { message, .. } => message.to_ascii_lowercase(),
+            _ => return false,
+        };
+        message.contains("insufficient funds") || message.contains("insufficient margin")
     }
 
     /// Check if this is a client-side error (4xx)
     pub fn is_client_error(&self) -> bool {
         match self {
-            Self::TokenException(_) | Self::InputException(_) | Self::InvalidParameter(_) => true,
+            Self::TokenException(_)
+            | Self::InputException(_)
+            | Self::InvalidParameter(_)
+            | Self::RequestTokenUsed(_)
+            | Self::RateLimited { .. } => true,
             Self::Api { status, .. } => status.starts_with('4'),
             _ => false,
         }
@@ -271,12 +482,258 @@ impl KiteError {
         }
     }
 
+    /// Name of the error variant, e.g. `"TokenException"` or `"Timeout"`
+    ///
+    /// Stable across messages, so suitable as a metrics label.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Http(_) => "Http",
+            Self::Timeout(_) => "Timeout",
+            Self::Json(_) => "Json",
+            Self::JsonContext { .. } => "JsonContext",
+            Self::TokenException(_) => "TokenException",
+            Self::UserException(_) => "UserException",
+            Self::OrderException(_) => "OrderException",
+            Self::InputException(_) => "InputException",
+            Self::MarginException(_) => "MarginException",
+            Self::HoldingException(_) => "HoldingException",
+            Self::NetworkException(_) => "NetworkException",
+            Self::DataException(_) => "DataException",
+            Self::GeneralException(_) => "GeneralException",
+            Self::Api { .. } => "Api",
+            Self::RateLimited { .. } => "RateLimited",
+            Self::RequestTokenUsed(_) => "RequestTokenUsed",
+            Self::Authentication(_) => "Authentication",
+            Self::InvalidParameter(_) => "InvalidParameter",
+            #[cfg(feature = "native")]
+            Self::CsvParsing(_) => "CsvParsing",
+            Self::DateTimeParsing(_) => "DateTimeParsing",
+            Self::UrlParsing(_) => "UrlParsing",
+            Self::Shutdown => "Shutdown",
+            Self::General(_) => "General",
+            Self::Legacy(_) => "Legacy",
+        }
+    }
+
     /// Check if this error can be retried
     pub fn is_retryable(&self) -> bool {
         match self {
             Self::NetworkException(_) | Self::Http(_) => true, // Includes 502, 503, 504 network errors
-            Self::Api { status, .. } => matches!(status.as_str(), "429"), // Only rate limiting is retryable for API errors
+            Self::Timeout(_) => true,
+            Self::RateLimited { .. } => true,
             _ => false,
         }
     }
 }
+
+/// Keys whose values must never appear in error messages
+const SENSITIVE_KEYS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "public_token",
+    "enctoken",
+    "api_key",
+    "api_secret",
+    "checksum",
+    "request_token",
+];
+
+/// Replace scalar values of sensitive keys with `"***"`
+fn redact_sensitive(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                let is_scalar = !(child.is_object() || child.is_array());
+                if is_scalar && SENSITIVE_KEYS.contains(&key.as_str()) {
+                    *child = JsonValue::String("***".to_string());
+                } else {
+                    redact_sensitive(child);
+                }
+            }
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(redact_sensitive),
+        _ => {}
+    }
+}
+
+/// What a deserialization error expected, without the value it found
+fn expected_of(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    let message = message.split(" at line ").next().unwrap_or(&message);
+    if let Some((_, expected)) = message.split_once(", expected ") {
+        expected.to_string()
+    } else if let Some(field) = message.strip_prefix("missing field ") {
+        format!("field {}", field)
+    } else {
+        "a valid value".to_string()
+    }
+}
+
+/// Drop `null` entries from every object, so the fields fall back to their defaults
+fn remove_nulls(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(map) => {
+            map.retain(|_, child| !child.is_null());
+            map.values_mut().for_each(remove_nulls);
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Position {
+        tradingsymbol: String,
+        #[serde(default)]
+        quantity: i64,
+        average_price: Option<f64>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Positions {
+        access_token: String,
+        net: Vec<Position>,
+    }
+
+    #[test]
+    fn test_json_error_names_bad_field() {
+        let payload = serde_json::json!({
+            "access_token": "secret-token",
+            "net": [
+                { "tradingsymbol": "INFY", "quantity": 10 },
+                { "tradingsymbol": "TCS", "quantity": "ten" }
+            ]
+        });
+
+        let err = KiteError::from_json_value::<Positions>(&payload).unwrap_err();
+        match &err {
+            KiteError::JsonContext {
+                path,
+                line,
+                snippet,
+                ..
+            } => {
+                assert_eq!(path, "net[1].quantity");
+                assert!(*line > 0);
+                assert!(snippet.contains("\"ten\""));
+            }
+            other => panic!("expected JsonContext, got {:?}", other),
+        }
+
+        let message = err.to_string();
+        assert!(message.contains("net[1].quantity"));
+        assert!(message.contains("expected i64"));
+        assert!(!message.contains("invalid type"));
+    }
+
+    #[test]
+    fn test_lenient_parse_defaults_nulls() {
+        let payload = serde_json::json!({
+            "access_token": "token",
+            "extra_field": {"added": "later"},
+            "net": [
+                { "tradingsymbol": "INFY", "quantity": 10 },
+                { "tradingsymbol": "TCS", "quantity": null, "average_price": null }
+            ]
+        });
+
+        assert!(KiteError::from_json_value::<Positions>(&payload).is_err());
+        let positions = KiteError::from_json_value_lenient::<Positions>(&payload).unwrap();
+        assert_eq!(positions.net[0].quantity, 10);
+        assert_eq!(positions.net[1].quantity, 0);
+        assert_eq!(positions.net[1].average_price, None);
+
+        // Nulls in fields without a default still fail
+        let payload = serde_json::json!({
+            "access_token": null,
+            "net": []
+        });
+        let err = KiteError::from_json_value_lenient::<Positions>(&payload).unwrap_err();
+        assert!(err.to_string().contains("access_token"));
+
+        // Wrong types are not guessed at
+        let payload = serde_json::json!({
+            "access_token": "token",
+            "net": [{ "tradingsymbol": "TCS", "quantity": "ten" }]
+        });
+        let err = KiteError::from_json_value_lenient::<Positions>(&payload).unwrap_err();
+        assert!(err.to_string().contains("net[0].quantity"));
+    }
+
+    #[test]
+    fn test_json_error_redacts_sensitive_values() {
+        let payload = serde_json::json!({
+            "access_token": "secret-token",
+            "net": [{ "tradingsymbol": 42, "quantity": 1 }]
+        });
+
+        let err = KiteError::from_json_value::<Positions>(&payload).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("net[0].tradingsymbol"));
+        assert!(!message.contains("secret-token"));
+    }
+
+    #[test]
+    fn test_rate_limit_error_carries_retry_after() {
+        let err = KiteError::from_api_response(
+            429,
+            "429",
+            "Too many requests",
+            Some("NetworkException".to_string()),
+        )
+        .with_retry_after(Some(Duration::from_secs(3)));
+
+        assert!(matches!(err, KiteError::RateLimited { .. }));
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(3)));
+        assert!(err.is_retryable());
+
+        let other =
+            KiteError::network_exception("down").with_retry_after(Some(Duration::from_secs(3)));
+        assert_eq!(other.retry_after(), None);
+    }
+
+    #[test]
+    fn test_insufficient_funds_detection() {
+        let rms = KiteError::from_api_response(
+            400,
+            "error",
+            "Insufficient funds. Required margin is 95417.84 but available margin is 74251.80.",
+            Some("InputException".to_string()),
+        );
+        assert!(matches!(rms, KiteError::InputException(_)));
+        assert!(rms.is_insufficient_funds());
+
+        let margin = KiteError::from_api_response(
+            400,
+            "error",
+            "Margin shortfall",
+            Some("MarginException".to_string()),
+        );
+        assert!(margin.is_insufficient_funds());
+
+        assert!(!KiteError::input_exception("Invalid `quantity`.").is_insufficient_funds());
+        assert!(!KiteError::network_exception("insufficient funds").is_insufficient_funds());
+    }
+
+    #[test]
+    fn test_json_error_paths() {
+        let err = KiteError::from_json_value::<Positions>(&serde_json::json!(42)).unwrap_err();
+        assert!(matches!(err, KiteError::JsonContext { ref path, .. } if path == "."));
+
+        let payload = serde_json::json!({ "net": [] });
+        match KiteError::from_json_value::<Positions>(&payload).unwrap_err() {
+            KiteError::JsonContext { path, expected, .. } => {
+                assert_eq!(path, ".");
+                assert_eq!(expected, "field `access_token`");
+            }
+            other => panic!("expected JsonContext, got {:?}", other),
+        }
+    }
+}
@@ -4,6 +4,7 @@ Common types and utilities shared across all KiteConnect models.
 This module provides:
 - Custom error types (`KiteError`)
 - Response wrapper types (`KiteResponse<T>`)
+- Price rounding helpers (`round_price`)
 - Shared enums organized in logical submodules:
   - `enums::exchange`: Stock exchanges and trading venues
   - `enums::trading`: Trading-related enums (products, validity, transaction types, etc.)
@@ -17,9 +18,12 @@ All enums are re-exported at the module level for convenient access.
 
 pub mod enums;
 pub mod errors;
+pub mod precision;
 pub mod response;
+pub(crate) mod timestamps;
 
 // Re-export main types for convenient access
 pub use enums::*;
 pub use errors::*;
+pub use precision::*;
 pub use response::*;
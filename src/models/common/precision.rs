@@ -0,0 +1,91 @@
+/*!
+Rounding helpers for prices and money amounts.
+
+Prices are carried as `f64`, so sums like `0.1 + 0.2` come out as
+`0.30000000000000004`. Summary computations round their totals with
+[`round_price`] so aggregates display as exchange-style values.
+
+With the `decimal` feature, [`to_decimal`] converts prices to
+`rust_decimal::Decimal` for exact aggregation, e.g. when reconciling P&L.
+*/
+
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+
+/// Decimal places used for rupee amounts in summaries
+pub const PRICE_DECIMALS: u32 = 2;
+
+/// Round a price or amount to `decimals` decimal places
+///
+/// Halfway values round away from zero. Non-finite values are returned
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use kiteconnect_async_wasm::models::common::round_price;
+///
+/// assert_eq!(round_price(100.29999999, 2), 100.3);
+/// assert_eq!(round_price(0.1 + 0.2, 2), 0.3);
+/// assert_eq!(round_price(83.12254, 4), 83.1225);
+/// ```
+pub fn round_price(value: f64, decimals: u32) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+
+    let factor = 10_f64.powi(decimals as i32);
+    (value * factor).round() / factor
+}
+
+/// Convert a price to [`Decimal`] as written in the JSON response
+///
+/// Goes through the shortest decimal representation of `value`, so `0.1`
+/// becomes exactly `0.1` rather than the binary approximation. Sums of the
+/// converted values are then exact. Non-finite values convert to zero.
+///
+/// # Example
+///
+/// ```rust
+/// use kiteconnect_async_wasm::models::common::{to_decimal, Decimal};
+///
+/// let total: Decimal = [0.1, 0.2].into_iter().map(to_decimal).sum();
+/// assert_eq!(total.to_string(), "0.3");
+/// ```
+#[cfg(feature = "decimal")]
+pub fn to_decimal(value: f64) -> Decimal {
+    use std::str::FromStr;
+
+    if !value.is_finite() {
+        return Decimal::ZERO;
+    }
+    Decimal::from_str(&value.to_string())
+        .ok()
+        .or_else(|| Decimal::from_f64_retain(value))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_price() {
+        assert_eq!(round_price(100.29999999, PRICE_DECIMALS), 100.3);
+        assert_eq!(round_price(-12.345, 2), -12.35);
+        assert_eq!(round_price(1500.0, 0), 1500.0);
+        assert_eq!(round_price(0.05 * 3.0, 2), 0.15);
+        assert!(round_price(f64::NAN, 2).is_nan());
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_to_decimal() {
+        assert_eq!(to_decimal(1500.05).to_string(), "1500.05");
+        assert_eq!(to_decimal(-0.05).to_string(), "-0.05");
+        assert_eq!(to_decimal(f64::NAN), Decimal::ZERO);
+
+        let total: Decimal = [0.05; 3].into_iter().map(to_decimal).sum();
+        assert_eq!(total.to_string(), "0.15");
+    }
+}
@@ -0,0 +1,78 @@
+/*!
+Deserializers for Kite's timestamp fields.
+
+Kite sends timestamps as `YYYY-MM-DD HH:MM:SS` in IST (`YYYY-MM-DD HH:MM`
+for mutual fund orders), while values serialized by this crate are RFC3339.
+All are accepted so models round-trip through serde.
+*/
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer};
+
+/// India Standard Time (UTC+05:30), the timezone of Kite's timestamps and market hours
+pub(crate) const IST: FixedOffset = match FixedOffset::east_opt(5 * 3600 + 30 * 60) {
+    Some(offset) => offset,
+    None => panic!("invalid IST offset"),
+};
+
+/// Parse RFC3339 or Kite's `YYYY-MM-DD HH:MM[:SS]` (IST)
+pub(crate) fn parse_kite_time(s: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(s) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M"))
+        .map_err(|e| format!("invalid timestamp {:?}: {}", s, e))?
+        .and_local_timezone(IST)
+        .single()
+        .map(|time| time.with_timezone(&Utc))
+        .ok_or_else(|| format!("invalid timestamp: {}", s))
+}
+
+/// Format a time as Kite's `YYYY-MM-DD HH:MM:SS` in IST, for request parameters
+pub(crate) fn format_kite_time(time: &DateTime<Utc>) -> String {
+    time.with_timezone(&IST)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+/// Deserialize a required Kite timestamp
+pub(crate) fn deserialize_kite_time<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_kite_time(&s).map_err(serde::de::Error::custom)
+}
+
+/// Deserialize an optional Kite timestamp; null and `""` become `None`
+pub(crate) fn deserialize_optional_kite_time<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.is_empty() => parse_kite_time(&s)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kite_time_formats() {
+        let kite = parse_kite_time("2023-11-01 09:15:04").unwrap();
+        assert_eq!(kite.to_rfc3339(), "2023-11-01T03:45:04+00:00");
+        assert_eq!(parse_kite_time(&kite.to_rfc3339()).unwrap(), kite);
+        let minutes = parse_kite_time("2017-12-28 11:44").unwrap();
+        assert_eq!(minutes.to_rfc3339(), "2017-12-28T06:14:00+00:00");
+        assert!(parse_kite_time("01/11/2023").is_err());
+        assert_eq!(format_kite_time(&kite), "2023-11-01 09:15:04");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::models::common::timestamps::{deserialize_kite_time, deserialize_optional_kite_time};
 use crate::models::common::{Exchange, GttStatus, OrderType, Product, TransactionType};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -49,11 +50,13 @@ pub struct GTTOrderParams {
     pub price: f64,
 
     /// Result (order ID when triggered, if successful)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub result: Option<GTTOrderResult>,
 }
 
 /// GTT order execution result
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawGTTOrderResult")]
 pub struct GTTOrderResult {
     /// Order ID
     #[serde(rename = "order_id")]
@@ -64,6 +67,30 @@ pub struct GTTOrderResult {
     pub rejection_reason: Option<String>,
 }
 
+/// Kite nests the outcome under `order_result`; flat results are accepted too
+#[derive(Deserialize)]
+struct RawGTTOrderResult {
+    #[serde(default)]
+    order_id: Option<String>,
+    #[serde(default)]
+    rejection_reason: Option<String>,
+    #[serde(default)]
+    order_result: Option<Box<RawGTTOrderResult>>,
+}
+
+impl From<RawGTTOrderResult> for GTTOrderResult {
+    fn from(raw: RawGTTOrderResult) -> Self {
+        let raw = match raw.order_result {
+            Some(nested) => *nested,
+            None => raw,
+        };
+        Self {
+            order_id: raw.order_id.unwrap_or_default(),
+            rejection_reason: raw.rejection_reason.filter(|reason| !reason.is_empty()),
+        }
+    }
+}
+
 /// GTT trigger type enumeration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -79,7 +106,7 @@ pub enum GTTTriggerType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GTT {
     /// GTT ID
-    pub id: u32,
+    pub id: u64,
 
     /// User ID
     #[serde(rename = "user_id")]
@@ -87,22 +114,26 @@ pub struct GTT {
 
     /// Parent trigger (if this is part of a multi-leg GTT)
     #[serde(rename = "parent_trigger")]
-    pub parent_trigger: Option<u32>,
+    pub parent_trigger: Option<u64>,
 
     /// GTT type
     #[serde(rename = "type")]
     pub gtt_type: GTTTriggerType,
 
     /// Created timestamp
-    #[serde(rename = "created_at")]
+    #[serde(rename = "created_at", deserialize_with = "deserialize_kite_time")]
     pub created_at: DateTime<Utc>,
 
     /// Updated timestamp
-    #[serde(rename = "updated_at")]
+    #[serde(rename = "updated_at", deserialize_with = "deserialize_kite_time")]
     pub updated_at: DateTime<Utc>,
 
     /// Expires at (optional expiry)
-    #[serde(rename = "expires_at")]
+    #[serde(
+        rename = "expires_at",
+        default,
+        deserialize_with = "deserialize_optional_kite_time"
+    )]
     pub expires_at: Option<DateTime<Utc>>,
 
     /// GTT status
@@ -115,6 +146,7 @@ pub struct GTT {
     pub orders: Vec<GTTOrderParams>,
 
     /// Metadata
+    #[serde(default)]
     pub meta: Option<serde_json::Value>,
 }
 
@@ -141,7 +173,7 @@ pub struct GTTCreateParams {
 pub struct GTTModifyParams {
     /// GTT ID
     #[serde(skip_serializing)]
-    pub gtt_id: u32,
+    pub gtt_id: u64,
 
     /// New condition
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -159,8 +191,9 @@ pub struct GTTModifyParams {
 /// GTT response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GTTResponse {
-    /// GTT ID
-    pub id: u32,
+    /// GTT ID (`trigger_id` in Kite's response)
+    #[serde(alias = "trigger_id")]
+    pub id: u64,
 }
 
 /// GTTs collection
@@ -350,6 +383,59 @@ impl GTTCreateParams {
     }
 }
 
+impl GTTModifyParams {
+    /// Create modification parameters replacing a GTT's condition and orders
+    pub fn new(gtt_id: u64, condition: GTTCondition, orders: Vec<GTTOrderParams>) -> Self {
+        Self {
+            gtt_id,
+            condition: Some(condition),
+            orders: Some(orders),
+            expires_at: None,
+        }
+    }
+
+    /// Trigger type implied by the orders: two orders make an OCO trigger
+    pub fn gtt_type(&self) -> GTTTriggerType {
+        match self.orders.as_ref().map(Vec::len) {
+            Some(2) => GTTTriggerType::TwoLeg,
+            _ => GTTTriggerType::Single,
+        }
+    }
+
+    /// Validate modification parameters
+    ///
+    /// Kite replaces the whole trigger on modify, so both the condition and
+    /// the orders are required. OCO triggers need exactly two trigger values
+    /// in ascending order (stop-loss below target).
+    pub fn validate(&self) -> Result<(), String> {
+        let (Some(condition), Some(orders)) = (&self.condition, &self.orders) else {
+            return Err("Modifying a GTT requires both the condition and the orders".to_string());
+        };
+
+        match self.gtt_type() {
+            GTTTriggerType::Single => {
+                if orders.len() != 1 {
+                    return Err("Single trigger GTT must have exactly one order".to_string());
+                }
+                if condition.trigger_values.len() != 1 {
+                    return Err(
+                        "Single trigger GTT must have exactly one trigger value".to_string()
+                    );
+                }
+            }
+            GTTTriggerType::TwoLeg => match condition.trigger_values.as_slice() {
+                [lower, upper] if lower < upper => {}
+                [_, _] => {
+                    return Err("Two-leg GTT trigger values must be in ascending order".to_string())
+                }
+                _ => return Err("Two-leg GTT must have exactly two trigger values".to_string()),
+            },
+        }
+
+        Ok(())
+    }
+}
+
 impl GTTs {
     /// Get active GTTs
     pub fn active_gtts(&self) -> Vec<&GTT> {
@@ -381,7 +467,7 @@ impl GTTs {
     }
 
     /// Find GTT by ID
-    pub fn find_gtt(&self, gtt_id: u32) -> Option<&GTT> {
+    pub fn find_gtt(&self, gtt_id: u64) -> Option<&GTT> {
         self.triggers.iter().find(|gtt| gtt.id == gtt_id)
     }
 
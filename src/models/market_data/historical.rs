@@ -31,8 +31,9 @@ let request = HistoricalDataRequest::new(
 ```
 */
 
-use crate::models::common::Interval;
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use crate::models::common::timestamps::IST;
+use crate::models::common::{Interval, KiteError, KiteResult};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
@@ -166,6 +167,137 @@ pub struct HistoricalDataRequest {
     ///
     /// Only applicable to futures and options. Has no effect on equity instruments.
     pub oi: Option<bool>,
+
+    /// Adjust candles for splits and bonuses
+    ///
+    /// Kite's historical prices are **not** adjusted for corporate actions.
+    /// When `true`, candles dated before each ex-date in `corporate_actions`
+    /// are rescaled after fetching (native targets only). Set both fields
+    /// with [`HistoricalDataRequest::adjust_for_corporate_actions`].
+    #[serde(default)]
+    pub adjust_for_corporate_actions: bool,
+
+    /// Corporate actions table used when adjusting candles
+    ///
+    /// Sourcing this data is left to the caller; it is never sent to the API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corporate_actions: Option<CorporateActions>,
+}
+
+/// A single split or bonus event
+///
+/// `ratio` is the number of shares held after the event for every share held
+/// before it. A 1:2 split has a ratio of `2.0`; a 1:1 bonus also has `2.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CorporateAction {
+    /// First trading day on which prices reflect the event (IST)
+    pub ex_date: NaiveDate,
+
+    /// Shares after the event per share before it
+    pub ratio: f64,
+}
+
+/// User-supplied table of corporate actions for back-adjusting candles
+///
+/// Candles dated before an action's ex-date have their prices divided by the
+/// ratio and their volume multiplied by it, so the series is continuous across
+/// the event. Open interest is left unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use kiteconnect_async_wasm::models::market_data::CorporateActions;
+/// use chrono::NaiveDate;
+///
+/// let actions = CorporateActions::new()
+///     .split(NaiveDate::from_ymd_opt(2023, 6, 15).unwrap(), 1, 2)
+///     .bonus(NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(), 1, 1);
+///
+/// assert_eq!(actions.factor_for(NaiveDate::from_ymd_opt(2023, 1, 2).unwrap()), 4.0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CorporateActions {
+    /// Actions in no particular order
+    pub actions: Vec<CorporateAction>,
+}
+
+impl CorporateActions {
+    /// Create an empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an action with an explicit ratio
+    pub fn with_action(mut self, ex_date: NaiveDate, ratio: f64) -> Self {
+        self.actions.push(CorporateAction { ex_date, ratio });
+        self
+    }
+
+    /// Add a split where `from` shares become `to` shares (e.g. 1:2 is `split(date, 1, 2)`)
+    ///
+    /// A zero share count gives an invalid ratio, reported by [`validate`](Self::validate).
+    pub fn split(self, ex_date: NaiveDate, from: u32, to: u32) -> Self {
+        self.with_action(ex_date, to as f64 / from as f64)
+    }
+
+    /// Add a bonus of `bonus` shares for every `held` shares (e.g. 1:1 is `bonus(date, 1, 1)`)
+    ///
+    /// A zero `held` gives an invalid ratio, reported by [`validate`](Self::validate).
+    pub fn bonus(self, ex_date: NaiveDate, bonus: u32, held: u32) -> Self {
+        self.with_action(ex_date, (held + bonus) as f64 / held as f64)
+    }
+
+    /// Check every ratio is a positive, finite number
+    ///
+    /// Fails with `KiteError::InputException` naming the first bad action,
+    /// e.g. a split with a zero share count.
+    pub fn validate(&self) -> KiteResult<()> {
+        match self
+            .actions
+            .iter()
+            .find(|action| !(action.ratio.is_finite() && action.ratio > 0.0))
+        {
+            Some(action) => Err(KiteError::input_exception(format!(
+                "Corporate action on {} has invalid ratio {}",
+                action.ex_date, action.ratio
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Cumulative adjustment factor for a candle on `date`
+    ///
+    /// This is the product of the ratios of every action whose ex-date falls
+    /// after `date`; `1.0` when no adjustment applies.
+    pub fn factor_for(&self, date: NaiveDate) -> f64 {
+        self.actions
+            .iter()
+            .filter(|action| date < action.ex_date)
+            .map(|action| action.ratio)
+            .product()
+    }
+
+    /// Back-adjust candle prices and volumes in place
+    ///
+    /// Candle timestamps are converted to IST before comparing with ex-dates.
+    /// Fails without touching the candles if [`validate`](Self::validate) does.
+    pub fn apply(&self, candles: &mut [Candle]) -> KiteResult<()> {
+        self.validate()?;
+
+        for candle in candles.iter_mut() {
+            let factor = self.factor_for(candle.date.with_timezone(&IST).date_naive());
+            if factor == 1.0 {
+                continue;
+            }
+
+            candle.open /= factor;
+            candle.high /= factor;
+            candle.low /= factor;
+            candle.close /= factor;
+            candle.volume = (candle.volume as f64 * factor).round() as u64;
+        }
+        Ok(())
+    }
 }
 
 /// Historical candle data point
@@ -176,7 +308,8 @@ pub struct HistoricalDataRequest {
 ///
 /// # Data Quality
 ///
-/// - Prices are adjusted for corporate actions (splits, bonuses, dividends)
+/// - Prices are **not** adjusted for corporate actions (splits, bonuses);
+///   see [`HistoricalDataRequest::adjust_for_corporate_actions`]
 /// - Volume is in number of shares/contracts traded
 /// - Open interest (if available) is the total outstanding contracts
 ///
@@ -284,9 +417,7 @@ impl<'de> Deserialize<'de> for Candle {
                         // Try parsing as simple date format and assume IST
                         NaiveDateTime::parse_from_str(date_str, "%Y-%m-%d %H:%M:%S").map(|dt| {
                             // Assume IST timezone (+05:30) and convert to UTC
-                            use chrono::FixedOffset;
-                            let ist = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
-                            ist.from_local_datetime(&dt).unwrap().into()
+                            IST.from_local_datetime(&dt).unwrap()
                         })
                     })
                     .map_err(|e| {
@@ -317,15 +448,15 @@ impl<'de> Deserialize<'de> for Candle {
             let close = array[4]
                 .as_f64()
                 .ok_or_else(|| serde::de::Error::custom("Close price must be a number"))?;
-            let volume = array[5]
-                .as_u64()
+            let volume = candle_count(&array[5])
                 .ok_or_else(|| serde::de::Error::custom("Volume must be a positive integer"))?;
 
-            // Open interest is optional (7th element)
-            let oi = if array.len() > 6 {
-                array[6].as_u64()
-            } else {
-                None
+            // Open interest is only sent with `oi=1` (7th element)
+            let oi = match array.get(6) {
+                None | Some(Value::Null) => None,
+                Some(value) => Some(candle_count(value).ok_or_else(|| {
+                    serde::de::Error::custom("Open interest must be a positive integer")
+                })?),
             };
 
             Ok(Candle {
@@ -367,6 +498,28 @@ impl<'de> Deserialize<'de> for Candle {
     }
 }
 
+/// Read a volume or open interest value
+///
+/// Accepts whole numbers written as floats (`1000.0`), which some responses use.
+fn candle_count(value: &Value) -> Option<u64> {
+    value.as_u64().or_else(|| {
+        value
+            .as_f64()
+            .filter(|v| *v >= 0.0 && v.fract() == 0.0 && *v <= u64::MAX as f64)
+            .map(|v| v as u64)
+    })
+}
+
+impl Candle {
+    /// Open interest at the end of the interval
+    ///
+    /// Only present when the request was made with
+    /// [`with_oi(true)`](HistoricalDataRequest::with_oi), for derivatives.
+    pub fn oi(&self) -> Option<u64> {
+        self.oi
+    }
+}
+
 /// Historical data response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoricalData {
@@ -394,6 +547,106 @@ pub struct HistoricalMetadata {
     pub count: usize,
 }
 
+/// Historical candles laid out column by column
+///
+/// Built by [`HistoricalData::to_columns`]. All columns have one entry per
+/// candle, in the same order as [`HistoricalData::candles`], which is the
+/// shape dataframe libraries expect when building a frame from series.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HistoricalColumns {
+    /// Candle timestamps as IST wall-clock times
+    pub date: Vec<NaiveDateTime>,
+
+    /// Opening prices
+    pub open: Vec<f64>,
+
+    /// Highest prices
+    pub high: Vec<f64>,
+
+    /// Lowest prices
+    pub low: Vec<f64>,
+
+    /// Closing prices
+    pub close: Vec<f64>,
+
+    /// Traded volumes
+    pub volume: Vec<u64>,
+
+    /// Open interest, present only when every candle carries it
+    pub oi: Option<Vec<u64>>,
+}
+
+impl HistoricalColumns {
+    /// Number of rows (candles)
+    pub fn len(&self) -> usize {
+        self.date.len()
+    }
+
+    /// Whether there are no rows
+    pub fn is_empty(&self) -> bool {
+        self.date.is_empty()
+    }
+}
+
+impl HistoricalData {
+    /// Convert the candles into parallel columns
+    ///
+    /// Timestamps are converted to IST, matching how Kite reports them and
+    /// how [`HistoricalDataRequest`] takes its range. The data is copied;
+    /// `self` is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::models::market_data::HistoricalData;
+    ///
+    /// let data: HistoricalData = serde_json::from_value(serde_json::json!({
+    ///     "candles": [
+    ///         ["2024-01-15T09:15:00+0530", 100.0, 105.0, 99.0, 104.0, 1000],
+    ///         ["2024-01-15T09:16:00+0530", 104.0, 106.0, 103.0, 105.5, 800]
+    ///     ],
+    ///     "metadata": {
+    ///         "instrument_token": 738561, "symbol": "RELIANCE",
+    ///         "interval": "minute", "count": 2
+    ///     }
+    /// })).unwrap();
+    ///
+    /// let columns = data.to_columns();
+    /// assert_eq!(columns.close, vec![104.0, 105.5]);
+    /// assert_eq!(columns.date[0].to_string(), "2024-01-15 09:15:00");
+    /// assert!(columns.oi.is_none());
+    /// ```
+    pub fn to_columns(&self) -> HistoricalColumns {
+        let rows = self.candles.len();
+        let mut columns = HistoricalColumns {
+            date: Vec::with_capacity(rows),
+            open: Vec::with_capacity(rows),
+            high: Vec::with_capacity(rows),
+            low: Vec::with_capacity(rows),
+            close: Vec::with_capacity(rows),
+            volume: Vec::with_capacity(rows),
+            oi: None,
+        };
+
+        for candle in &self.candles {
+            columns
+                .date
+                .push(candle.date.with_timezone(&IST).naive_local());
+            columns.open.push(candle.open);
+            columns.high.push(candle.high);
+            columns.low.push(candle.low);
+            columns.close.push(candle.close);
+            columns.volume.push(candle.volume);
+        }
+
+        if rows > 0 {
+            columns.oi = self.candles.iter().map(|candle| candle.oi).collect();
+        }
+
+        columns
+    }
+}
+
 impl HistoricalDataRequest {
     /// Create a new historical data request
     pub fn new(
@@ -409,9 +662,58 @@ impl HistoricalDataRequest {
             interval,
             continuous: None,
             oi: None,
+            adjust_for_corporate_actions: false,
+            corporate_actions: None,
         }
     }
 
+    /// Create a request from timezone-aware datetimes
+    ///
+    /// Kite interprets `from` and `to` as IST wall-clock times, so passing
+    /// UTC values to [`HistoricalDataRequest::new`] shifts the window by
+    /// 5h30m. This converts any `DateTime<Tz>` to IST first; the values are
+    /// sent as `YYYY-MM-DD HH:MM:SS` (see [`HistoricalDataRequest::from_param`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::models::market_data::HistoricalDataRequest;
+    /// use kiteconnect_async_wasm::models::common::Interval;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let request = HistoricalDataRequest::new_ist(
+    ///     738561,
+    ///     Utc.with_ymd_and_hms(2023, 11, 20, 3, 45, 0).unwrap(),
+    ///     Utc.with_ymd_and_hms(2023, 11, 20, 10, 0, 0).unwrap(),
+    ///     Interval::FiveMinute,
+    /// );
+    /// assert_eq!(request.from_param(), "2023-11-20 09:15:00");
+    /// assert_eq!(request.to_param(), "2023-11-20 15:30:00");
+    /// ```
+    pub fn new_ist<Tz: TimeZone>(
+        instrument_token: u32,
+        from: DateTime<Tz>,
+        to: DateTime<Tz>,
+        interval: Interval,
+    ) -> Self {
+        Self::new(
+            instrument_token,
+            from.with_timezone(&IST).naive_local(),
+            to.with_timezone(&IST).naive_local(),
+            interval,
+        )
+    }
+
+    /// `from` formatted as Kite expects it: IST `YYYY-MM-DD HH:MM:SS`
+    pub fn from_param(&self) -> String {
+        self.from.format("%Y-%m-%d %H:%M:%S").to_string()
+    }
+
+    /// `to` formatted as Kite expects it: IST `YYYY-MM-DD HH:MM:SS`
+    pub fn to_param(&self) -> String {
+        self.to.format("%Y-%m-%d %H:%M:%S").to_string()
+    }
+
     /// Enable continuous data for futures
     pub fn continuous(mut self, continuous: bool) -> Self {
         self.continuous = Some(continuous);
@@ -424,6 +726,17 @@ impl HistoricalDataRequest {
         self
     }
 
+    /// Back-adjust returned candles for splits and bonuses
+    ///
+    /// Kite returns unadjusted prices, so a 1:2 split shows up as a 50% gap.
+    /// The supplied table is applied to the candles after they are fetched
+    /// (native targets only); fetching the corporate actions is up to you.
+    pub fn adjust_for_corporate_actions(mut self, actions: CorporateActions) -> Self {
+        self.adjust_for_corporate_actions = true;
+        self.corporate_actions = Some(actions);
+        self
+    }
+
     /// Validate the date range against API limits for the specified interval
     ///
     /// Checks if the requested date range exceeds the maximum allowed days
@@ -458,7 +771,7 @@ impl HistoricalDataRequest {
         if !self.interval.is_date_range_valid(&self.from, &self.to) {
             let duration = self.to - self.from;
             let days = duration.num_days();
-            let max_days = self.interval.max_days_allowed();
+            let max_days = self.interval.max_days();
 
             return Err(format!(
                 "Date range of {} days exceeds maximum allowed {} days for {} interval",
@@ -512,6 +825,93 @@ impl HistoricalDataRequest {
         Ok(request)
     }
 
+    /// Check the request for mistakes Kite would reject
+    ///
+    /// Fails with `KiteError::General` if the instrument token is zero,
+    /// `from` is after `to`, or the range is longer than
+    /// [`Interval::max_days`] for the interval, and with
+    /// `KiteError::InputException` if the corporate actions to adjust for
+    /// fail [`CorporateActions::validate`]. Catching these here avoids a
+    /// network round-trip that ends in an opaque API error.
+    ///
+    /// `historical_data_typed` skips the range length check, since it
+    /// splits longer ranges into several requests itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::models::market_data::HistoricalDataRequest;
+    /// use kiteconnect_async_wasm::models::common::Interval;
+    /// use chrono::NaiveDateTime;
+    ///
+    /// let request = HistoricalDataRequest::new(
+    ///     738561,
+    ///     NaiveDateTime::parse_from_str("2023-11-30 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+    ///     NaiveDateTime::parse_from_str("2023-11-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+    ///     Interval::Day,
+    /// );
+    ///
+    /// assert!(request.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> KiteResult<()> {
+        self.validate_bounds()?;
+
+        let max_days = self.interval.max_days();
+        if self.days_span() > max_days as i64 {
+            return Err(KiteError::general(format!(
+                "Date range of {} days exceeds the maximum of {} days for {} interval",
+                self.days_span(),
+                max_days,
+                self.interval
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate the request and return it, for use at the end of a chain
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::models::market_data::HistoricalDataRequest;
+    /// use kiteconnect_async_wasm::models::common::Interval;
+    /// use chrono::NaiveDateTime;
+    ///
+    /// let request = HistoricalDataRequest::new(
+    ///     0,
+    ///     NaiveDateTime::parse_from_str("2023-11-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+    ///     NaiveDateTime::parse_from_str("2023-11-30 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+    ///     Interval::Day,
+    /// )
+    /// .with_oi(true)
+    /// .try_build();
+    ///
+    /// assert!(request.is_err());
+    /// ```
+    pub fn try_build(self) -> KiteResult<Self> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Checks of [`validate`](Self::validate) that still apply when the
+    /// range will be split into several requests
+    pub(crate) fn validate_bounds(&self) -> KiteResult<()> {
+        if self.instrument_token == 0 {
+            return Err(KiteError::general("Instrument token must be non-zero"));
+        }
+        if self.from > self.to {
+            return Err(KiteError::general(format!(
+                "From date {} is after to date {}",
+                self.from_param(),
+                self.to_param()
+            )));
+        }
+        if self.adjust_for_corporate_actions {
+            if let Some(actions) = &self.corporate_actions {
+                actions.validate()?;
+            }
+        }
+        Ok(())
+    }
+
     /// Split a large date range into multiple smaller requests that respect API limits
     ///
     /// If the requested date range exceeds the maximum allowed for the interval,
@@ -540,7 +940,7 @@ impl HistoricalDataRequest {
     /// println!("Split into {} requests", sub_requests.len());
     /// ```
     pub fn split_into_valid_requests(&self) -> Vec<Self> {
-        let max_days = self.interval.max_days_allowed() as i64;
+        let max_days = self.interval.max_days() as i64;
         let total_duration = self.to - self.from;
         let total_days = total_duration.num_days();
 
@@ -563,6 +963,8 @@ impl HistoricalDataRequest {
                 interval: self.interval,
                 continuous: self.continuous,
                 oi: self.oi,
+                adjust_for_corporate_actions: self.adjust_for_corporate_actions,
+                corporate_actions: self.corporate_actions.clone(),
             };
 
             requests.push(request);
@@ -626,7 +1028,7 @@ impl HistoricalDataRequest {
     /// println!("Split into {} requests (newest first)", sub_requests.len());
     /// ```
     pub fn split_into_valid_requests_reverse(&self) -> Vec<Self> {
-        let max_days = self.interval.max_days_allowed() as i64;
+        let max_days = self.interval.max_days() as i64;
         let total_duration = self.to - self.from;
         let total_days = total_duration.num_days();
 
@@ -653,6 +1055,8 @@ impl HistoricalDataRequest {
                 interval: self.interval,
                 continuous: self.continuous,
                 oi: self.oi,
+                adjust_for_corporate_actions: self.adjust_for_corporate_actions,
+                corporate_actions: self.corporate_actions.clone(),
             };
 
             requests.push(request);
@@ -823,6 +1227,38 @@ mod tests {
     use chrono::{DateTime, Utc};
     use serde_json::json;
 
+    #[test]
+    fn test_validate_rejects_common_mistakes() {
+        let at = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+        let from = at("2024-01-01 09:15:00");
+        let to = at("2024-01-20 15:30:00");
+
+        assert!(
+            HistoricalDataRequest::new(738561, from, to, Interval::Minute)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            HistoricalDataRequest::new(738561, from, from, Interval::Minute)
+                .validate()
+                .is_ok()
+        );
+
+        for request in [
+            HistoricalDataRequest::new(0, from, to, Interval::Minute),
+            HistoricalDataRequest::new(738561, to, from, Interval::Minute),
+            HistoricalDataRequest::new(738561, from, at("2024-03-15 15:30:00"), Interval::Minute),
+        ] {
+            let err = request.validate().unwrap_err();
+            assert!(matches!(err, KiteError::General(_)), "{:?}", err);
+        }
+
+        // Long ranges are left to chunking
+        HistoricalDataRequest::new(738561, from, at("2024-03-15 15:30:00"), Interval::Minute)
+            .validate_bounds()
+            .unwrap();
+    }
+
     #[test]
     fn test_candle_deserialization_array_format() {
         // Test with 6 elements (no OI)
@@ -861,6 +1297,45 @@ mod tests {
         assert_eq!(candle.oi, Some(500));
     }
 
+    #[test]
+    fn test_candle_oi_shapes() {
+        let candle: Candle = serde_json::from_value(json!([
+            "2024-12-20T09:15:00+0530",
+            100.5,
+            105.0,
+            99.5,
+            104.0,
+            1000.0,
+            12500.0
+        ]))
+        .unwrap();
+        assert_eq!(candle.volume, 1000);
+        assert_eq!(candle.oi(), Some(12500));
+
+        let candle: Candle = serde_json::from_value(json!([
+            "2024-12-20T09:15:00+0530",
+            100.5,
+            105.0,
+            99.5,
+            104.0,
+            1000,
+            null
+        ]))
+        .unwrap();
+        assert_eq!(candle.oi(), None);
+
+        let malformed = json!([
+            "2024-12-20T09:15:00+0530",
+            100.5,
+            105.0,
+            99.5,
+            104.0,
+            1000,
+            "500"
+        ]);
+        assert!(serde_json::from_value::<Candle>(malformed).is_err());
+    }
+
     #[test]
     fn test_date_parsing_formats() {
         // Test IST timezone format
@@ -876,4 +1351,176 @@ mod tests {
 
         assert_eq!(candle.date, expected_utc);
     }
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_split_adjustment_halves_prices_before_ex_date() {
+        let mut candles: Vec<Candle> = serde_json::from_value(json!([
+            [
+                "2023-06-14T00:00:00+0530",
+                2000.0,
+                2100.0,
+                1980.0,
+                2050.0,
+                1000
+            ],
+            [
+                "2023-06-15T00:00:00+0530",
+                1030.0,
+                1040.0,
+                1010.0,
+                1020.0,
+                2500
+            ]
+        ]))
+        .unwrap();
+
+        let actions = CorporateActions::new().split(ymd(2023, 6, 15), 1, 2);
+        actions.apply(&mut candles).unwrap();
+
+        assert_eq!(candles[0].open, 1000.0);
+        assert_eq!(candles[0].high, 1050.0);
+        assert_eq!(candles[0].low, 990.0);
+        assert_eq!(candles[0].close, 1025.0);
+        assert_eq!(candles[0].volume, 2000);
+
+        // On and after the ex-date nothing changes
+        assert_eq!(candles[1].open, 1030.0);
+        assert_eq!(candles[1].volume, 2500);
+    }
+
+    #[test]
+    fn test_adjustment_factors_compound() {
+        let actions =
+            CorporateActions::new()
+                .split(ymd(2023, 6, 15), 1, 2)
+                .bonus(ymd(2024, 1, 10), 1, 1);
+
+        assert_eq!(actions.factor_for(ymd(2023, 1, 2)), 4.0);
+        assert_eq!(actions.factor_for(ymd(2023, 6, 15)), 2.0);
+        assert_eq!(actions.factor_for(ymd(2024, 1, 10)), 1.0);
+    }
+
+    #[test]
+    fn test_zero_ratios_are_rejected() {
+        let mut candles: Vec<Candle> = serde_json::from_value(json!([[
+            "2023-06-14T00:00:00+0530",
+            2000.0,
+            2100.0,
+            1980.0,
+            2050.0,
+            1000
+        ]]))
+        .unwrap();
+
+        for actions in [
+            CorporateActions::new().split(ymd(2023, 6, 15), 1, 0),
+            CorporateActions::new().split(ymd(2023, 6, 15), 0, 2),
+            CorporateActions::new().bonus(ymd(2023, 6, 15), 1, 0),
+        ] {
+            let err = actions.apply(&mut candles).unwrap_err();
+            assert!(matches!(err, KiteError::InputException(_)), "{:?}", err);
+            assert_eq!(candles[0].open, 2000.0);
+
+            let from =
+                NaiveDateTime::parse_from_str("2023-06-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap();
+            let to =
+                NaiveDateTime::parse_from_str("2023-06-30 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+            let request = HistoricalDataRequest::new(738561, from, to, Interval::Day)
+                .adjust_for_corporate_actions(actions);
+            assert!(request.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_adjust_builder_sets_flag_and_table() {
+        let from =
+            NaiveDateTime::parse_from_str("2023-06-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let to = NaiveDateTime::parse_from_str("2023-06-30 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let actions = CorporateActions::new().split(ymd(2023, 6, 15), 1, 2);
+
+        let request = HistoricalDataRequest::new(738561, from, to, Interval::Day)
+            .adjust_for_corporate_actions(actions.clone());
+
+        assert!(request.adjust_for_corporate_actions);
+        assert_eq!(request.corporate_actions, Some(actions));
+    }
+
+    #[test]
+    fn test_new_ist_converts_utc_to_ist_wall_clock() {
+        let from = Utc.with_ymd_and_hms(2023, 11, 20, 3, 45, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2023, 11, 20, 20, 0, 0).unwrap();
+
+        let request = HistoricalDataRequest::new_ist(738561, from, to, Interval::Minute);
+
+        assert_eq!(request.from_param(), "2023-11-20 09:15:00");
+        // Crosses midnight in IST
+        assert_eq!(request.to_param(), "2023-11-21 01:30:00");
+
+        // Already-IST input is left untouched
+        let same = HistoricalDataRequest::new_ist(
+            738561,
+            from.with_timezone(&IST),
+            to.with_timezone(&IST),
+            Interval::Minute,
+        );
+        assert_eq!(same.from, request.from);
+        assert_eq!(same.to, request.to);
+    }
+
+    #[test]
+    fn test_to_columns_keeps_row_order_and_oi() {
+        let candles: Vec<Candle> = serde_json::from_value(json!([
+            [
+                "2024-01-15T09:15:00+0530",
+                100.0,
+                105.0,
+                99.0,
+                104.0,
+                1000,
+                50
+            ],
+            [
+                "2024-01-15T09:16:00+0530",
+                104.0,
+                106.0,
+                103.0,
+                105.5,
+                800,
+                55
+            ]
+        ]))
+        .unwrap();
+        let mut data = HistoricalData {
+            candles,
+            metadata: HistoricalMetadata {
+                instrument_token: 738561,
+                symbol: "NIFTY24JANFUT".to_string(),
+                interval: Interval::Minute,
+                count: 2,
+            },
+        };
+
+        let columns = data.to_columns();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(
+            columns.date[1],
+            NaiveDateTime::parse_from_str("2024-01-15 09:16:00", "%Y-%m-%d %H:%M:%S").unwrap()
+        );
+        assert_eq!(columns.open, vec![100.0, 104.0]);
+        assert_eq!(columns.high, vec![105.0, 106.0]);
+        assert_eq!(columns.low, vec![99.0, 103.0]);
+        assert_eq!(columns.volume, vec![1000, 800]);
+        assert_eq!(columns.oi, Some(vec![50, 55]));
+
+        // A single candle without OI drops the column
+        data.candles[1].oi = None;
+        assert_eq!(data.to_columns().oi, None);
+
+        data.candles.clear();
+        assert!(data.to_columns().is_empty());
+    }
 }
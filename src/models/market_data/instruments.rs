@@ -1,6 +1,8 @@
-use crate::models::common::{Exchange, InstrumentType, Segment};
-use chrono::{NaiveDate, NaiveTime};
+use crate::models::common::timestamps::IST;
+use crate::models::common::{Exchange, InstrumentType, Product, Segment};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc, Weekday};
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 
 /// Custom deserializer to convert string to u32
 fn deserialize_string_to_u32<'de, D>(deserializer: D) -> Result<u32, D::Error>
@@ -26,7 +28,7 @@ where
     D: Deserializer<'de>,
 {
     let s: String = Deserialize::deserialize(deserializer)?;
-    if s.is_empty() || s == "null" || s == "" {
+    if s.is_empty() || s == "null" {
         Ok(None)
     } else {
         NaiveDate::parse_from_str(&s, "%Y-%m-%d")
@@ -84,6 +86,29 @@ pub struct Instrument {
     pub exchange: Exchange,
 }
 
+/// Instruments parsed from the CSV dump, with the rows that were dropped
+///
+/// Returned by `KiteConnect::instruments_with_report`. `instruments_typed`
+/// drops the same rows silently and only records how many.
+#[derive(Debug, Clone, Default)]
+pub struct InstrumentParseReport {
+    /// Instruments that parsed cleanly, in CSV order
+    pub parsed: Vec<Instrument>,
+
+    /// Number of rows dropped
+    pub skipped: usize,
+
+    /// One message per dropped row, naming its CSV line and the cause
+    pub errors: Vec<String>,
+}
+
+impl InstrumentParseReport {
+    /// Check if every row parsed
+    pub fn is_clean(&self) -> bool {
+        self.skipped == 0
+    }
+}
+
 /// Market status information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketStatus {
@@ -136,10 +161,16 @@ pub enum MarketState {
     Break,
     /// Holiday
     Holiday,
+    /// No session schedule is known for the exchange
+    Unknown,
 }
 
 /// Instrument search parameters
+///
+/// Build it with [`new`](Self::new) or [`fuzzy`](Self::fuzzy) and the
+/// chained setters; new options can be added without breaking callers.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct InstrumentSearch {
     /// Exchange to search in (optional)
     pub exchange: Option<Exchange>,
@@ -152,6 +183,10 @@ pub struct InstrumentSearch {
 
     /// Maximum number of results
     pub limit: Option<u32>,
+
+    /// Rank results by edit distance instead of exact substring matching
+    #[serde(default)]
+    pub fuzzy: bool,
 }
 
 /// Instrument lookup by token
@@ -164,6 +199,17 @@ pub struct InstrumentLookup {
     pub exchange: Option<Exchange>,
 }
 
+/// Instrument list indexed by symbol and by token
+///
+/// `InstrumentLookup` above is a request for a set of tokens; this is the
+/// in-memory index for resolving symbols against an already fetched list.
+#[derive(Debug, Clone, Default)]
+pub struct InstrumentIndex {
+    instruments: Vec<Instrument>,
+    by_symbol: HashMap<(Exchange, String), usize>,
+    by_token: HashMap<u32, usize>,
+}
+
 impl Instrument {
     /// Check if the instrument is an equity
     pub fn is_equity(&self) -> bool {
@@ -193,6 +239,32 @@ impl Instrument {
         matches!(self.instrument_type, InstrumentType::PE)
     }
 
+    /// Check if the instrument is a call option (alias of `is_call_option`)
+    pub fn is_call(&self) -> bool {
+        self.is_call_option()
+    }
+
+    /// Check if the instrument is a put option (alias of `is_put_option`)
+    pub fn is_put(&self) -> bool {
+        self.is_put_option()
+    }
+
+    /// Default product for carrying a position in this instrument overnight
+    ///
+    /// `NRML` for futures, options and other derivative/commodity segments,
+    /// `CNC` (delivery) otherwise. Intraday (`MIS`) is never implied.
+    pub fn default_product(&self) -> Product {
+        if self.is_future()
+            || self.is_option()
+            || self.exchange.is_derivative()
+            || self.exchange.is_commodity()
+        {
+            Product::NRML
+        } else {
+            Product::CNC
+        }
+    }
+
     /// Check if the instrument has expired
     pub fn is_expired(&self) -> bool {
         if let Some(expiry) = self.expiry {
@@ -212,11 +284,15 @@ impl Instrument {
     }
 
     /// Get days to expiry (None for non-expiring instruments)
+    ///
+    /// Counts from today's date in IST, the timezone Kite expiry dates are in.
     pub fn days_to_expiry(&self) -> Option<i64> {
-        self.expiry.map(|expiry| {
-            let today = chrono::Utc::now().date_naive();
-            (expiry - today).num_days()
-        })
+        self.days_to_expiry_from(Utc::now().with_timezone(&IST).date_naive())
+    }
+
+    /// Get days from `today` to expiry; negative once expired
+    pub fn days_to_expiry_from(&self, today: NaiveDate) -> Option<i64> {
+        self.expiry.map(|expiry| (expiry - today).num_days())
     }
 
     /// Check if the instrument is in-the-money (for options)
@@ -265,9 +341,83 @@ impl Instrument {
     pub fn tick_value(&self) -> f64 {
         self.tick_size * self.lot_size as f64
     }
+
+    /// Round a price to the nearest valid tick
+    ///
+    /// Prices exactly halfway between two ticks round away from zero. A
+    /// non-positive `tick_size` leaves the price unchanged.
+    pub fn round_to_tick(&self, price: f64) -> f64 {
+        if self.tick_size <= 0.0 {
+            return price;
+        }
+
+        let snapped = (price / self.tick_size).round() * self.tick_size;
+        // Strip floating point noise such as 100.05000000000001
+        (snapped * 1e6).round() / 1e6
+    }
+
+    /// Check if a price is a whole multiple of the tick size
+    pub fn is_valid_price(&self, price: f64) -> bool {
+        price.is_finite() && (self.round_to_tick(price) - price).abs() < 1e-6
+    }
 }
 
 impl MarketStatus {
+    /// Derive the status from the exchange's regular session times
+    ///
+    /// Uses the standard IST timings (equity and F&O 09:15–15:30 with a
+    /// 09:00 pre-open and 15:40–16:00 post-close session, currency
+    /// 09:00–17:00, MCX 09:00–23:30, NCO 09:00–17:00) and treats weekends as
+    /// closed. Exchange holidays and special sessions are not known here.
+    /// `GLOBAL`, `NSEIX`, `MF` and unknown exchanges have no schedule here and
+    /// are reported as [`MarketState::Unknown`].
+    pub fn from_schedule(exchange: Exchange, at: DateTime<Utc>) -> Self {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+        let ist = at.with_timezone(&IST);
+
+        let (open, close, pre_market, post_market) = match exchange {
+            Exchange::NSE | Exchange::BSE => (
+                time(9, 15),
+                time(15, 30),
+                Some((time(9, 0), time(9, 8))),
+                Some((time(15, 40), time(16, 0))),
+            ),
+            Exchange::NFO | Exchange::BFO => (time(9, 15), time(15, 30), None, None),
+            Exchange::CDS | Exchange::BCD | Exchange::NCO => (time(9, 0), time(17, 0), None, None),
+            Exchange::MCX => (time(9, 0), time(23, 30), None, None),
+            Exchange::GLOBAL | Exchange::NSEIX | Exchange::MF | Exchange::Unknown(_) => {
+                (None, None, None, None)
+            }
+        };
+
+        let within = |start: Option<NaiveTime>, end: Option<NaiveTime>| matches!((start, end), (Some(s), Some(e)) if s <= ist.time() && ist.time() < e);
+
+        let status = if open.is_none() {
+            MarketState::Unknown
+        } else if matches!(ist.weekday(), Weekday::Sat | Weekday::Sun) {
+            MarketState::Closed
+        } else if within(open, close) {
+            MarketState::Open
+        } else if pre_market.is_some_and(|(s, e)| within(s, e)) {
+            MarketState::PreMarket
+        } else if post_market.is_some_and(|(s, e)| within(s, e)) {
+            MarketState::PostMarket
+        } else {
+            MarketState::Closed
+        };
+
+        Self {
+            exchange,
+            status,
+            market_open: open,
+            market_close: close,
+            pre_market_open: pre_market.and_then(|(s, _)| s),
+            pre_market_close: pre_market.and_then(|(_, e)| e),
+            post_market_open: post_market.and_then(|(s, _)| s),
+            post_market_close: post_market.and_then(|(_, e)| e),
+        }
+    }
+
     /// Check if the market is currently open
     pub fn is_open(&self) -> bool {
         self.status == MarketState::Open
@@ -298,6 +448,11 @@ impl MarketStatus {
         self.status == MarketState::Holiday
     }
 
+    /// Check if no schedule is known for the exchange
+    pub fn is_unknown(&self) -> bool {
+        self.status == MarketState::Unknown
+    }
+
     /// Check if any trading is allowed (including pre/post market)
     pub fn is_trading_allowed(&self) -> bool {
         matches!(
@@ -315,6 +470,31 @@ impl InstrumentSearch {
             instrument_type: None,
             query,
             limit: None,
+            fuzzy: false,
+        }
+    }
+
+    /// Create a fuzzy search returning at most `limit` ranked matches
+    ///
+    /// Fuzzy searches tolerate typos and spacing variants by scoring each
+    /// instrument's `tradingsymbol` and `name` with a Levenshtein distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kiteconnect_async_wasm::models::market_data::InstrumentSearch;
+    ///
+    /// let search = InstrumentSearch::fuzzy("RELIANSE", 5);
+    /// assert!(search.fuzzy);
+    /// assert_eq!(search.limit, Some(5));
+    /// ```
+    pub fn fuzzy(query: impl Into<String>, limit: u32) -> Self {
+        Self {
+            exchange: None,
+            instrument_type: None,
+            query: query.into(),
+            limit: Some(limit),
+            fuzzy: true,
         }
     }
 
@@ -336,6 +516,12 @@ impl InstrumentSearch {
         self
     }
 
+    /// Rank results by edit distance instead of exact substring matching
+    pub fn fuzzy_matching(mut self, fuzzy: bool) -> Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
     /// Search for equity instruments only
     pub fn equity_only(mut self) -> Self {
         self.instrument_type = Some(InstrumentType::EQ);
@@ -354,6 +540,104 @@ impl InstrumentSearch {
         self.instrument_type = Some(InstrumentType::FUT);
         self
     }
+
+    /// Run the search against a list of instruments
+    ///
+    /// Exchange and instrument type filters are applied first. Exact searches
+    /// return instruments whose symbol or name contains the query
+    /// (case-insensitive), in input order. Fuzzy searches return the closest
+    /// matches ordered by score, best first.
+    pub fn search<'a>(&self, instruments: &'a [Instrument]) -> Vec<&'a Instrument> {
+        let query = normalize_search_text(&self.query);
+        let limit = self.limit.map(|l| l as usize).unwrap_or(usize::MAX);
+
+        let candidates = instruments.iter().filter(|inst| {
+            self.exchange.as_ref().is_none_or(|e| &inst.exchange == e)
+                && self
+                    .instrument_type
+                    .is_none_or(|t| inst.instrument_type == t)
+        });
+
+        if !self.fuzzy {
+            return candidates
+                .filter(|inst| {
+                    normalize_search_text(&inst.trading_symbol).contains(&query)
+                        || normalize_search_text(&inst.name).contains(&query)
+                })
+                .take(limit)
+                .collect();
+        }
+
+        let mut scored: Vec<(f64, &Instrument)> = candidates
+            .filter_map(|inst| {
+                let score =
+                    fuzzy_score(&query, &inst.trading_symbol).max(fuzzy_score(&query, &inst.name));
+                (score >= FUZZY_MIN_SCORE).then_some((score, inst))
+            })
+            .collect();
+
+        scored.sort_by(|(a, a_inst), (b, b_inst)| {
+            b.total_cmp(a)
+                .then_with(|| a_inst.trading_symbol.cmp(&b_inst.trading_symbol))
+        });
+
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(_, inst)| inst)
+            .collect()
+    }
+}
+
+/// Minimum similarity for a fuzzy match to be returned
+const FUZZY_MIN_SCORE: f64 = 0.5;
+
+/// Uppercase and strip whitespace so "Hdfc Bank" matches "HDFCBANK"
+fn normalize_search_text(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Similarity in `0.0..=1.0` between a normalized query and a candidate
+fn fuzzy_score(query: &str, candidate: &str) -> f64 {
+    let candidate = normalize_search_text(candidate);
+    if query.is_empty() || candidate.is_empty() {
+        return 0.0;
+    }
+    if candidate == query {
+        return 1.0;
+    }
+
+    let distance = levenshtein(query, &candidate);
+    let max_len = query.chars().count().max(candidate.chars().count());
+    let similarity = 1.0 - distance as f64 / max_len as f64;
+
+    // Prefix matches ("RELI" -> "RELIANCE") rank just below exact matches
+    if candidate.starts_with(query) {
+        similarity.max(0.9)
+    } else {
+        similarity
+    }
+}
+
+/// Classic two-row Levenshtein edit distance over chars
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
 }
 
 impl InstrumentLookup {
@@ -383,3 +667,241 @@ impl InstrumentLookup {
         self
     }
 }
+
+impl InstrumentIndex {
+    /// Index an instruments list, e.g. the result of `instruments_typed`
+    pub fn new(instruments: Vec<Instrument>) -> Self {
+        let mut by_symbol = HashMap::with_capacity(instruments.len());
+        let mut by_token = HashMap::with_capacity(instruments.len());
+
+        for (index, instrument) in instruments.iter().enumerate() {
+            by_symbol.insert(
+                (instrument.exchange, instrument.trading_symbol.clone()),
+                index,
+            );
+            if let Ok(token) = instrument.instrument_token.parse::<u32>() {
+                by_token.insert(token, index);
+            }
+        }
+
+        Self {
+            instruments,
+            by_symbol,
+            by_token,
+        }
+    }
+
+    /// Find an instrument by exchange and trading symbol
+    pub fn by_symbol(&self, exchange: Exchange, trading_symbol: &str) -> Option<&Instrument> {
+        self.by_symbol
+            .get(&(exchange, trading_symbol.to_string()))
+            .map(|&index| &self.instruments[index])
+    }
+
+    /// Find an instrument by instrument token
+    pub fn by_token(&self, token: u32) -> Option<&Instrument> {
+        self.by_token
+            .get(&token)
+            .map(|&index| &self.instruments[index])
+    }
+
+    /// Resolve an exchange and trading symbol to its instrument token
+    pub fn token_for(&self, exchange: Exchange, trading_symbol: &str) -> Option<u32> {
+        self.by_symbol(exchange, trading_symbol)
+            .and_then(|instrument| instrument.instrument_token.parse().ok())
+    }
+
+    /// Number of indexed instruments
+    pub fn len(&self) -> usize {
+        self.instruments.len()
+    }
+
+    /// Whether the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.instruments.is_empty()
+    }
+
+    /// Iterate over the indexed instruments in their original order
+    pub fn iter(&self) -> impl Iterator<Item = &Instrument> {
+        self.instruments.iter()
+    }
+}
+
+impl From<Vec<Instrument>> for InstrumentIndex {
+    fn from(instruments: Vec<Instrument>) -> Self {
+        Self::new(instruments)
+    }
+}
+
+impl FromIterator<Instrument> for InstrumentIndex {
+    fn from_iter<I: IntoIterator<Item = Instrument>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instrument(symbol: &str, name: &str, exchange: &str, instrument_type: &str) -> Instrument {
+        serde_json::from_value(serde_json::json!({
+            "instrument_token": "1",
+            "exchange_token": "1",
+            "tradingsymbol": symbol,
+            "name": name,
+            "last_price": "0",
+            "expiry": "",
+            "strike": "0",
+            "tick_size": "0.05",
+            "lot_size": "1",
+            "instrument_type": instrument_type,
+            "segment": exchange,
+            "exchange": exchange,
+        }))
+        .unwrap()
+    }
+
+    fn sample_instruments() -> Vec<Instrument> {
+        vec![
+            instrument("INFY", "INFOSYS", "NSE", "EQ"),
+            instrument("RELIANCE", "RELIANCE INDUSTRIES", "NSE", "EQ"),
+            instrument("HDFCBANK", "HDFC BANK", "NSE", "EQ"),
+            instrument("TCS", "TATA CONSULTANCY SERV LT", "NSE", "EQ"),
+            instrument("RELIANCE", "RELIANCE INDUSTRIES", "BSE", "EQ"),
+        ]
+    }
+
+    #[test]
+    fn test_option_and_expiry_helpers() {
+        let mut call = instrument("NIFTY24DEC24000CE", "NIFTY", "NSE", "CE");
+        call.expiry = NaiveDate::from_ymd_opt(2024, 12, 26);
+        let put = instrument("NIFTY24DEC24000PE", "NIFTY", "NSE", "PE");
+        let equity = instrument("INFY", "INFOSYS", "NSE", "EQ");
+
+        assert!(call.is_option() && call.is_call() && !call.is_put());
+        assert!(put.is_option() && put.is_put() && !put.is_call());
+        assert!(!equity.is_option() && !equity.is_future());
+
+        let today = NaiveDate::from_ymd_opt(2024, 12, 19).unwrap();
+        assert_eq!(call.days_to_expiry_from(today), Some(7));
+        assert_eq!(
+            call.days_to_expiry_from(NaiveDate::from_ymd_opt(2024, 12, 27).unwrap()),
+            Some(-1)
+        );
+        assert_eq!(equity.days_to_expiry_from(today), None);
+        assert_eq!(equity.days_to_expiry(), None);
+    }
+
+    #[test]
+    fn test_instrument_index_lookups() {
+        let mut instruments = sample_instruments();
+        for (offset, instrument) in instruments.iter_mut().enumerate() {
+            instrument.instrument_token = (408065 + offset).to_string();
+        }
+        let index = InstrumentIndex::new(instruments);
+
+        assert_eq!(index.len(), 5);
+        assert_eq!(index.token_for(Exchange::NSE, "RELIANCE"), Some(408066));
+        assert_eq!(
+            index.by_symbol(Exchange::BSE, "RELIANCE").unwrap().exchange,
+            Exchange::BSE
+        );
+        assert_eq!(index.by_token(408068).unwrap().trading_symbol, "TCS");
+        assert!(index.by_symbol(Exchange::BSE, "INFY").is_none());
+        assert!(index.by_token(1).is_none());
+    }
+
+    #[test]
+    fn test_round_to_tick() {
+        let infy = instrument("INFY", "INFOSYS", "NSE", "EQ");
+
+        assert_eq!(infy.round_to_tick(100.03), 100.05);
+        assert_eq!(infy.round_to_tick(100.02), 100.0);
+        assert_eq!(infy.round_to_tick(1234.56), 1234.55);
+        assert!(infy.is_valid_price(100.05));
+        assert!(infy.is_valid_price(infy.round_to_tick(100.03)));
+        assert!(!infy.is_valid_price(100.03));
+    }
+
+    #[test]
+    fn test_market_status_from_schedule() {
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+
+        // Monday 2024-01-15
+        let open = MarketStatus::from_schedule(Exchange::NSE, at("2024-01-15T10:00:00+05:30"));
+        assert!(open.is_open());
+        let pre = MarketStatus::from_schedule(Exchange::NSE, at("2024-01-15T09:05:00+05:30"));
+        assert!(pre.is_pre_market());
+        let closed = MarketStatus::from_schedule(Exchange::NFO, at("2024-01-15T15:45:00+05:30"));
+        assert!(closed.is_closed());
+        let mcx = MarketStatus::from_schedule(Exchange::MCX, at("2024-01-15T21:00:00+05:30"));
+        assert!(mcx.is_open());
+
+        let weekend = MarketStatus::from_schedule(Exchange::NSE, at("2024-01-13T10:00:00+05:30"));
+        assert!(weekend.is_closed());
+        assert!(!weekend.is_trading_allowed());
+
+        for exchange in [Exchange::GLOBAL, Exchange::NSEIX, Exchange::MF] {
+            let status = MarketStatus::from_schedule(exchange, at("2024-01-15T10:00:00+05:30"));
+            assert!(status.is_unknown());
+            assert!(!status.is_open());
+        }
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("", "ABC"), 3);
+        assert_eq!(levenshtein("KITTEN", "SITTING"), 3);
+        assert_eq!(levenshtein("RELIANCE", "RELIANCE"), 0);
+    }
+
+    #[test]
+    fn test_fuzzy_search_finds_misspelled_symbol() {
+        let instruments = sample_instruments();
+        let results = InstrumentSearch::fuzzy("RELAINCE", 3).search(&instruments);
+
+        assert!(!results.is_empty());
+        assert_eq!(results[0].trading_symbol, "RELIANCE");
+    }
+
+    #[test]
+    fn test_fuzzy_search_ignores_case_and_spacing() {
+        let instruments = sample_instruments();
+        let results = InstrumentSearch::fuzzy("hdfc  bnk", 1).search(&instruments);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].trading_symbol, "HDFCBANK");
+    }
+
+    #[test]
+    fn test_fuzzy_search_respects_filters_and_limit() {
+        let instruments = sample_instruments();
+        let results = InstrumentSearch::fuzzy("RELIANCE", 10)
+            .exchange(Exchange::BSE)
+            .search(&instruments);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].exchange, Exchange::BSE);
+
+        let results = InstrumentSearch::fuzzy("I", 2).search(&instruments);
+        assert!(results.len() <= 2);
+    }
+
+    #[test]
+    fn test_exact_search_uses_substring() {
+        let instruments = sample_instruments();
+        let results = InstrumentSearch::new("bank".to_string()).search(&instruments);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].trading_symbol, "HDFCBANK");
+        assert!(InstrumentSearch::new("RELAINCE".to_string())
+            .search(&instruments)
+            .is_empty());
+
+        let results = InstrumentSearch::new("RELAINCE".to_string())
+            .fuzzy_matching(true)
+            .limit(1)
+            .search(&instruments);
+        assert_eq!(results[0].trading_symbol, "RELIANCE");
+    }
+}
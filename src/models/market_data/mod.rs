@@ -6,15 +6,18 @@
  * - Real-time quotes and market depth
  * - Historical data (OHLCV) and candlestick patterns
  * - Market status and exchange information
+ * - Streaming ticker modes
  */
 
 pub mod historical;
 pub mod instruments;
 pub mod market_depth;
 pub mod quotes;
+pub mod ticks;
 
 // Re-export all public types
 pub use historical::*;
 pub use instruments::*;
 pub use market_depth::*;
 pub use quotes::*;
+pub use ticks::*;
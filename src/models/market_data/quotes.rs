@@ -1,8 +1,17 @@
-use crate::models::common::Exchange;
+use crate::models::common::timestamps::deserialize_optional_kite_time;
+use crate::models::common::{Exchange, MarketSegment};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
 
 /// Real-time quote data
+///
+/// Currency (CDS) and commodity (MCX) quotes omit some fields that equity
+/// quotes carry, and illiquid contracts may report a null
+/// `last_trade_time`. Such fields fall back to zero, an empty depth or
+/// `None` instead of failing to parse. Prices are in rupees for every
+/// segment; REST quotes need no currency scaling.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Quote {
     /// Instrument token
@@ -10,7 +19,7 @@ pub struct Quote {
     pub instrument_token: u32,
 
     /// Trading symbol
-    #[serde(rename = "tradingsymbol")]
+    #[serde(rename = "tradingsymbol", default)]
     pub trading_symbol: String,
 
     /// Exchange
@@ -21,26 +30,31 @@ pub struct Quote {
     pub last_price: f64,
 
     /// Last traded quantity
-    #[serde(rename = "last_quantity")]
+    #[serde(rename = "last_quantity", default)]
     pub last_quantity: u32,
 
-    /// Last traded time
-    #[serde(rename = "last_trade_time")]
-    pub last_trade_time: DateTime<Utc>,
+    /// Last traded time, `None` for contracts that haven't traded
+    #[serde(
+        rename = "last_trade_time",
+        default,
+        deserialize_with = "deserialize_optional_kite_time"
+    )]
+    pub last_trade_time: Option<DateTime<Utc>>,
 
     /// Average traded price
-    #[serde(rename = "average_price")]
+    #[serde(rename = "average_price", default)]
     pub average_price: f64,
 
     /// Volume traded
+    #[serde(default)]
     pub volume: u64,
 
     /// Buy quantity
-    #[serde(rename = "buy_quantity")]
+    #[serde(rename = "buy_quantity", default)]
     pub buy_quantity: u64,
 
     /// Sell quantity
-    #[serde(rename = "sell_quantity")]
+    #[serde(rename = "sell_quantity", default)]
     pub sell_quantity: u64,
 
     /// Open interest (for derivatives)
@@ -55,15 +69,33 @@ pub struct Quote {
     #[serde(rename = "oi_day_low")]
     pub oi_day_low: Option<u64>,
 
+    /// Upper circuit limit for the day
+    #[serde(default)]
+    pub upper_circuit_limit: Option<f64>,
+
+    /// Lower circuit limit for the day
+    #[serde(default)]
+    pub lower_circuit_limit: Option<f64>,
+
     /// Net change from previous close
-    #[serde(rename = "net_change")]
+    #[serde(rename = "net_change", default)]
     pub net_change: f64,
 
     /// OHLC data
     pub ohlc: OHLC,
 
     /// Market depth
+    #[serde(default)]
     pub depth: MarketDepth,
+
+    /// Set when the quote was fetched while the exchange was closed, so
+    /// `last_price` is the frozen closing price rather than a live one
+    ///
+    /// Only filled in by the client when after-hours tagging is enabled
+    /// (see `KiteConnectConfig::tag_after_hours_quotes`), and never for
+    /// exchanges without a known schedule.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_after_hours: bool,
 }
 
 /// OHLC (Open, High, Low, Close) data
@@ -83,25 +115,30 @@ pub struct OHLC {
 }
 
 /// Market depth (order book)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MarketDepth {
     /// Buy orders (bids)
+    #[serde(default)]
     pub buy: Vec<DepthItem>,
 
     /// Sell orders (asks)
+    #[serde(default)]
     pub sell: Vec<DepthItem>,
 }
 
 /// Individual depth item (bid/ask)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DepthItem {
     /// Price
+    #[serde(default)]
     pub price: f64,
 
     /// Quantity
+    #[serde(default)]
     pub quantity: u32,
 
     /// Number of orders
+    #[serde(default)]
     pub orders: u32,
 }
 
@@ -117,6 +154,77 @@ pub struct LTP {
     pub last_price: f64,
 }
 
+/// OHLC quote as returned by the `/quote/ohlc` endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OHLCQuote {
+    /// Instrument token
+    pub instrument_token: u32,
+
+    /// Last traded price
+    pub last_price: f64,
+
+    /// OHLC data
+    pub ohlc: OHLC,
+}
+
+/// Valid trigger price range for an instrument
+///
+/// Returned by `trigger_range_typed`; stop-loss triggers of cover orders
+/// must lie within `lower..=upper`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerRange {
+    /// Instrument token
+    pub instrument_token: u32,
+
+    /// Lowest allowed trigger price
+    pub lower: f64,
+
+    /// Highest allowed trigger price
+    pub upper: f64,
+}
+
+impl TriggerRange {
+    /// Check if `price` is an allowed trigger price
+    pub fn contains(&self, price: f64) -> bool {
+        let (lower, upper) = self.bounds();
+        (lower..=upper).contains(&price)
+    }
+
+    /// Clamp `price` into the allowed range
+    ///
+    /// Never panics: bounds reported in the wrong order are swapped, and a
+    /// NaN bound leaves that side unbounded.
+    pub fn clamp(&self, price: f64) -> f64 {
+        let (lower, upper) = self.bounds();
+        price.max(lower).min(upper)
+    }
+
+    /// `lower` and `upper`, swapped if they arrive reversed
+    fn bounds(&self) -> (f64, f64) {
+        if self.lower > self.upper {
+            (self.upper, self.lower)
+        } else {
+            (self.lower, self.upper)
+        }
+    }
+}
+
+/// Full quote, OHLC and LTP for the same instruments
+///
+/// Each map is keyed by instrument identifier (e.g. `"NSE:INFY"`), as
+/// returned by the respective endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuoteBundle {
+    /// Full quotes from `/quote`
+    pub quotes: HashMap<String, Quote>,
+
+    /// OHLC quotes from `/quote/ohlc`
+    pub ohlc: HashMap<String, OHLCQuote>,
+
+    /// Last traded prices from `/quote/ltp`
+    pub ltp: HashMap<String, LTP>,
+}
+
 /// Quote request for multiple instruments
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuoteRequest {
@@ -160,7 +268,72 @@ pub struct OHLCV {
     pub oi: Option<u64>,
 }
 
+/// Serialize a value as one compact, newline-terminated JSON object
+fn to_json_line<T: Serialize>(value: &T) -> String {
+    let mut line = serde_json::to_string(value).expect("market data always serializes to JSON");
+    line.push('\n');
+    line
+}
+
+/// Write each item as a JSON Lines record, returning the number of lines written
+fn write_json_lines<'a, T, W>(
+    mut writer: W,
+    items: impl IntoIterator<Item = &'a T>,
+) -> std::io::Result<usize>
+where
+    T: Serialize + 'a,
+    W: Write,
+{
+    let mut count = 0;
+    for item in items {
+        serde_json::to_writer(&mut writer, item)?;
+        writer.write_all(b"\n")?;
+        count += 1;
+    }
+    writer.flush()?;
+    Ok(count)
+}
+
 impl Quote {
+    /// Start building a quote, e.g. for tests and mocks
+    pub fn builder() -> QuoteBuilder {
+        QuoteBuilder::new()
+    }
+
+    /// Serialize the quote as a single JSON Lines record
+    ///
+    /// The output is one compact JSON object terminated by `\n`, suitable for
+    /// log shipping or message queue producers. `last_trade_time` is written
+    /// in RFC3339 format.
+    pub fn to_json_line(&self) -> String {
+        to_json_line(self)
+    }
+
+    /// Write a batch of quotes as JSON Lines, returning the number of lines written
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kiteconnect_async_wasm::models::market_data::Quote;
+    ///
+    /// # fn example(quotes: Vec<Quote>) -> std::io::Result<()> {
+    /// let file = std::fs::File::create("quotes.jsonl")?;
+    /// Quote::write_json_lines(std::io::BufWriter::new(file), &quotes)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_json_lines<'a, W: Write>(
+        writer: W,
+        quotes: impl IntoIterator<Item = &'a Quote>,
+    ) -> std::io::Result<usize> {
+        write_json_lines(writer, quotes)
+    }
+
+    /// Get the market segment of the quoted instrument
+    pub fn segment(&self) -> MarketSegment {
+        self.exchange.market_segment()
+    }
+
     /// Get the current bid price (highest buy price)
     pub fn bid_price(&self) -> Option<f64> {
         self.depth.buy.first().map(|item| item.price)
@@ -226,6 +399,11 @@ impl Quote {
         self.ohlc.high - self.ohlc.low
     }
 
+    /// Get the day's `(low, high)` from OHLC
+    pub fn day_bounds(&self) -> (f64, f64) {
+        (self.ohlc.low, self.ohlc.high)
+    }
+
     /// Get the day's range percentage
     pub fn day_range_percentage(&self) -> f64 {
         if self.ohlc.low > 0.0 {
@@ -235,6 +413,22 @@ impl Quote {
         }
     }
 
+    /// Check if the last price has hit the upper circuit limit
+    ///
+    /// `false` when the quote carries no circuit limits.
+    pub fn is_upper_circuit(&self) -> bool {
+        self.upper_circuit_limit
+            .is_some_and(|limit| limit > 0.0 && self.last_price >= limit)
+    }
+
+    /// Check if the last price has hit the lower circuit limit
+    ///
+    /// `false` when the quote carries no circuit limits.
+    pub fn is_lower_circuit(&self) -> bool {
+        self.lower_circuit_limit
+            .is_some_and(|limit| limit > 0.0 && self.last_price <= limit)
+    }
+
     /// Get position within the day's range (0.0 = at low, 1.0 = at high)
     pub fn position_in_range(&self) -> f64 {
         let range = self.day_range();
@@ -268,9 +462,229 @@ impl Quote {
             None
         }
     }
+
+    /// Get the depth imbalance ratio (total bid quantity / total ask quantity)
+    ///
+    /// Values above 1.0 indicate more resting buy interest than sell interest.
+    /// Returns `None` when the sell side of the book is empty.
+    pub fn imbalance_ratio(&self) -> Option<f64> {
+        self.order_flow_ratio()
+    }
+}
+
+/// Builder for synthesizing quotes in tests and mocks
+///
+/// Starts from an empty NSE quote: zero prices and volumes, no open interest,
+/// empty depth and no `last_trade_time`.
+///
+/// # Example
+///
+/// ```rust
+/// use kiteconnect_async_wasm::models::market_data::Quote;
+///
+/// let quote = Quote::builder()
+///     .trading_symbol("INFY")
+///     .last_price(1412.5)
+///     .bid(1412.0, 10, 1)
+///     .ask(1413.0, 25, 2)
+///     .build();
+///
+/// assert_eq!(quote.spread(), Some(1.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuoteBuilder {
+    quote: Quote,
+}
+
+impl QuoteBuilder {
+    /// Create a new quote builder with empty defaults
+    pub fn new() -> Self {
+        Self {
+            quote: Quote {
+                instrument_token: 0,
+                trading_symbol: String::new(),
+                exchange: Exchange::NSE,
+                last_price: 0.0,
+                last_quantity: 0,
+                last_trade_time: None,
+                average_price: 0.0,
+                volume: 0,
+                buy_quantity: 0,
+                sell_quantity: 0,
+                open_interest: None,
+                oi_day_high: None,
+                oi_day_low: None,
+                upper_circuit_limit: None,
+                lower_circuit_limit: None,
+                net_change: 0.0,
+                ohlc: OHLC {
+                    open: 0.0,
+                    high: 0.0,
+                    low: 0.0,
+                    close: 0.0,
+                },
+                depth: MarketDepth::default(),
+                is_after_hours: false,
+            },
+        }
+    }
+
+    /// Set instrument token
+    pub fn instrument_token(mut self, instrument_token: u32) -> Self {
+        self.quote.instrument_token = instrument_token;
+        self
+    }
+
+    /// Set trading symbol
+    pub fn trading_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.quote.trading_symbol = symbol.into();
+        self
+    }
+
+    /// Set exchange
+    pub fn exchange(mut self, exchange: Exchange) -> Self {
+        self.quote.exchange = exchange;
+        self
+    }
+
+    /// Set last traded price
+    pub fn last_price(mut self, last_price: f64) -> Self {
+        self.quote.last_price = last_price;
+        self
+    }
+
+    /// Set last traded quantity
+    pub fn last_quantity(mut self, last_quantity: u32) -> Self {
+        self.quote.last_quantity = last_quantity;
+        self
+    }
+
+    /// Set last traded time
+    pub fn last_trade_time(mut self, last_trade_time: DateTime<Utc>) -> Self {
+        self.quote.last_trade_time = Some(last_trade_time);
+        self
+    }
+
+    /// Set average traded price
+    pub fn average_price(mut self, average_price: f64) -> Self {
+        self.quote.average_price = average_price;
+        self
+    }
+
+    /// Set volume traded
+    pub fn volume(mut self, volume: u64) -> Self {
+        self.quote.volume = volume;
+        self
+    }
+
+    /// Set total buy and sell quantities
+    pub fn buy_sell_quantity(mut self, buy_quantity: u64, sell_quantity: u64) -> Self {
+        self.quote.buy_quantity = buy_quantity;
+        self.quote.sell_quantity = sell_quantity;
+        self
+    }
+
+    /// Set open interest
+    pub fn open_interest(mut self, open_interest: u64) -> Self {
+        self.quote.open_interest = Some(open_interest);
+        self
+    }
+
+    /// Set lower and upper circuit limits
+    pub fn circuit_limits(mut self, lower: f64, upper: f64) -> Self {
+        self.quote.lower_circuit_limit = Some(lower);
+        self.quote.upper_circuit_limit = Some(upper);
+        self
+    }
+
+    /// Set net change from previous close
+    pub fn net_change(mut self, net_change: f64) -> Self {
+        self.quote.net_change = net_change;
+        self
+    }
+
+    /// Mark the quote as fetched while the exchange was closed
+    pub fn after_hours(mut self, is_after_hours: bool) -> Self {
+        self.quote.is_after_hours = is_after_hours;
+        self
+    }
+
+    /// Set OHLC data
+    pub fn ohlc(mut self, open: f64, high: f64, low: f64, close: f64) -> Self {
+        self.quote.ohlc = OHLC {
+            open,
+            high,
+            low,
+            close,
+        };
+        self
+    }
+
+    /// Append a bid level (levels are kept in insertion order)
+    pub fn bid(mut self, price: f64, quantity: u32, orders: u32) -> Self {
+        self.quote.depth.buy.push(DepthItem {
+            price,
+            quantity,
+            orders,
+        });
+        self
+    }
+
+    /// Append an ask level (levels are kept in insertion order)
+    pub fn ask(mut self, price: f64, quantity: u32, orders: u32) -> Self {
+        self.quote.depth.sell.push(DepthItem {
+            price,
+            quantity,
+            orders,
+        });
+        self
+    }
+
+    /// Build the quote
+    pub fn build(self) -> Quote {
+        self.quote
+    }
+}
+
+impl Default for QuoteBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Prices as [`Decimal`](crate::models::common::Decimal), for exact aggregation
+#[cfg(feature = "decimal")]
+impl Quote {
+    /// Last traded price as a `Decimal`
+    pub fn last_price_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.last_price)
+    }
+
+    /// Volume weighted average price as a `Decimal`
+    pub fn average_price_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.average_price)
+    }
+
+    /// Net change as a `Decimal`
+    pub fn net_change_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.net_change)
+    }
 }
 
 impl OHLC {
+    /// Serialize the OHLC data as a single JSON Lines record
+    pub fn to_json_line(&self) -> String {
+        to_json_line(self)
+    }
+
+    /// Write a batch of OHLC records as JSON Lines, returning the number of lines written
+    pub fn write_json_lines<'a, W: Write>(
+        writer: W,
+        ohlc: impl IntoIterator<Item = &'a OHLC>,
+    ) -> std::io::Result<usize> {
+        write_json_lines(writer, ohlc)
+    }
+
     /// Get the trading range (high - low)
     pub fn range(&self) -> f64 {
         self.high - self.low
@@ -412,3 +826,231 @@ impl OHLCV {
         self.typical_price() // Simplified; actual VWAP requires more data
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_quote() -> Quote {
+        serde_json::from_value(serde_json::json!({
+            "instrument_token": 408065,
+            "tradingsymbol": "INFY",
+            "exchange": "NSE",
+            "last_price": 1412.95,
+            "last_quantity": 5,
+            "last_trade_time": "2024-01-15T09:45:12Z",
+            "average_price": 1412.47,
+            "volume": 7360198,
+            "buy_quantity": 0,
+            "sell_quantity": 5191,
+            "oi": null,
+            "oi_day_high": null,
+            "oi_day_low": null,
+            "net_change": 0.0,
+            "ohlc": {"open": 1396.0, "high": 1421.75, "low": 1395.55, "close": 1389.65},
+            "depth": {
+                "buy": [{"price": 1412.9, "quantity": 10, "orders": 1}],
+                "sell": [{"price": 1412.95, "quantity": 5191, "orders": 13}]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_quote_json_line_round_trip() {
+        let quote = sample_quote();
+        let line = quote.to_json_line();
+
+        assert!(line.ends_with('\n'));
+        assert_eq!(line.matches('\n').count(), 1);
+        assert!(line.contains(r#""last_trade_time":"2024-01-15T09:45:12Z""#));
+
+        let parsed: Quote = serde_json::from_str(line.trim_end()).unwrap();
+        assert_eq!(parsed.instrument_token, quote.instrument_token);
+        assert_eq!(parsed.trading_symbol, quote.trading_symbol);
+        assert_eq!(parsed.last_price, quote.last_price);
+        assert_eq!(parsed.last_trade_time, quote.last_trade_time);
+        assert_eq!(parsed.depth.sell[0].quantity, 5191);
+    }
+
+    #[test]
+    fn test_write_json_lines_batch() {
+        let quotes = vec![sample_quote(), sample_quote()];
+        let mut buffer = Vec::new();
+
+        let written = Quote::write_json_lines(&mut buffer, &quotes).unwrap();
+        assert_eq!(written, 2);
+
+        let output = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            let parsed: Quote = serde_json::from_str(line).unwrap();
+            assert_eq!(parsed.trading_symbol, "INFY");
+        }
+
+        let mut buffer = Vec::new();
+        let written = OHLC::write_json_lines(&mut buffer, quotes.iter().map(|q| &q.ohlc)).unwrap();
+        assert_eq!(written, 2);
+
+        let output = String::from_utf8(buffer).unwrap();
+        let parsed: OHLC = serde_json::from_str(output.lines().next().unwrap()).unwrap();
+        assert_eq!(parsed.high, 1421.75);
+    }
+
+    #[test]
+    fn test_parse_mcx_quote_without_equity_fields() {
+        let quote: Quote = serde_json::from_value(serde_json::json!({
+            "instrument_token": 109134599,
+            "tradingsymbol": "CRUDEOIL24JANFUT",
+            "exchange": "MCX",
+            "last_price": 6123.0,
+            "last_trade_time": null,
+            "volume": 15432,
+            "oi": 12000,
+            "oi_day_high": 12500,
+            "oi_day_low": 11800,
+            "net_change": -21.0,
+            "ohlc": {"open": 6140.0, "high": 6175.0, "low": 6101.0, "close": 6144.0}
+        }))
+        .unwrap();
+
+        assert_eq!(quote.segment(), MarketSegment::Commodity);
+        assert_eq!(quote.last_trade_time, None);
+        assert_eq!(quote.average_price, 0.0);
+        assert_eq!(quote.open_interest, Some(12000));
+        assert!(quote.depth.buy.is_empty());
+    }
+
+    #[test]
+    fn test_parse_cds_quote_with_kite_timestamp() {
+        let quote: Quote = serde_json::from_value(serde_json::json!({
+            "instrument_token": 412675,
+            "tradingsymbol": "USDINR24JANFUT",
+            "exchange": "CDS",
+            "last_price": 83.1225,
+            "last_quantity": 1,
+            "last_trade_time": "2024-01-15 10:15:30",
+            "average_price": 83.1198,
+            "oi": null,
+            "oi_day_high": null,
+            "oi_day_low": null,
+            "ohlc": {"open": 83.1, "high": 83.14, "low": 83.0975, "close": 83.105},
+            "depth": {
+                "buy": [{"price": 83.12, "quantity": 250, "orders": 3}],
+                "sell": [{"price": 83.1225, "quantity": 100, "orders": 1}]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(quote.segment(), MarketSegment::Currency);
+        assert_eq!(quote.last_price, 83.1225);
+        assert_eq!(quote.volume, 0);
+        assert_eq!(
+            quote.last_trade_time.unwrap().to_rfc3339(),
+            "2024-01-15T04:45:30+00:00"
+        );
+        assert_eq!(quote.bid_price(), Some(83.12));
+    }
+
+    #[test]
+    fn test_builder_minimal_quote() {
+        let quote = Quote::builder().trading_symbol("INFY").build();
+
+        assert_eq!(quote.trading_symbol, "INFY");
+        assert_eq!(quote.exchange, Exchange::NSE);
+        assert_eq!(quote.volume, 0);
+        assert!(quote.depth.buy.is_empty() && quote.depth.sell.is_empty());
+        assert_eq!(quote.spread(), None);
+
+        let quote = Quote::builder()
+            .last_price(101.0)
+            .ohlc(100.0, 102.0, 99.0, 100.0)
+            .bid(100.5, 10, 1)
+            .ask(101.5, 20, 2)
+            .build();
+        assert_eq!(quote.spread(), Some(1.0));
+        assert_eq!(quote.day_range(), 3.0);
+        assert_eq!(quote.total_ask_quantity(), 20);
+    }
+
+    #[test]
+    fn test_circuit_and_day_helpers() {
+        let mut value = serde_json::to_value(sample_quote()).unwrap();
+        value["upper_circuit_limit"] = serde_json::json!(1528.6);
+        value["lower_circuit_limit"] = serde_json::json!(1250.7);
+        let quote: Quote = serde_json::from_value(value).unwrap();
+
+        assert_eq!(quote.upper_circuit_limit, Some(1528.6));
+        assert_eq!(quote.day_bounds(), (1395.55, 1421.75));
+        assert!(!quote.is_upper_circuit());
+        assert!(!quote.is_lower_circuit());
+
+        let quote = Quote::builder()
+            .last_price(110.0)
+            .net_change(10.0)
+            .ohlc(101.0, 110.0, 100.0, 100.0)
+            .circuit_limits(90.0, 110.0)
+            .build();
+        assert_eq!(quote.change_percentage(), 10.0);
+        assert!(quote.is_upper_circuit());
+        assert!(!quote.is_lower_circuit());
+
+        assert!(!sample_quote().is_upper_circuit());
+        assert!(sample_quote().lower_circuit_limit.is_none());
+    }
+
+    #[test]
+    fn test_trigger_range_clamp_tolerates_reversed_bounds() {
+        let range = TriggerRange {
+            instrument_token: 408065,
+            lower: 110.0,
+            upper: 90.0,
+        };
+        assert_eq!(range.clamp(120.0), 110.0);
+        assert_eq!(range.clamp(80.0), 90.0);
+        assert!(range.contains(100.0));
+
+        let range = TriggerRange {
+            instrument_token: 408065,
+            lower: f64::NAN,
+            upper: 110.0,
+        };
+        assert_eq!(range.clamp(120.0), 110.0);
+        assert_eq!(range.clamp(80.0), 80.0);
+    }
+
+    #[test]
+    fn test_parse_full_market_depth() {
+        let mut value = serde_json::to_value(sample_quote()).unwrap();
+        value["depth"] = serde_json::json!({
+            "buy": [
+                {"price": 1412.9, "quantity": 10, "orders": 1},
+                {"price": 1412.85, "quantity": 40, "orders": 2},
+                {"price": 1412.8, "quantity": 50, "orders": 3},
+                {"price": 1412.75, "quantity": 100, "orders": 4},
+                {"price": 1412.7, "quantity": 200, "orders": 5}
+            ],
+            "sell": [
+                {"price": 1412.95, "quantity": 20, "orders": 1},
+                {"price": 1413.0, "quantity": 30, "orders": 2},
+                {"price": 1413.05, "quantity": 50, "orders": 1},
+                {"price": 1413.1, "quantity": 0, "orders": 0},
+                {"price": 0, "quantity": 0, "orders": 0}
+            ]
+        });
+        let quote: Quote = serde_json::from_value(value).unwrap();
+
+        assert_eq!(quote.depth.buy.len(), 5);
+        assert_eq!(quote.depth.sell.len(), 5);
+        assert_eq!(quote.depth.buy[3].orders, 4);
+        assert_eq!(quote.bid_price(), Some(1412.9));
+        assert_eq!(quote.ask_price(), Some(1412.95));
+        assert_eq!(quote.total_bid_quantity(), 400);
+        assert_eq!(quote.total_ask_quantity(), 100);
+        assert_eq!(quote.imbalance_ratio(), Some(4.0));
+
+        let empty = Quote::builder().bid(100.0, 10, 1).build();
+        assert_eq!(empty.imbalance_ratio(), None);
+    }
+}
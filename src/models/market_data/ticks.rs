@@ -0,0 +1,382 @@
+use crate::models::common::{KiteError, KiteResult};
+use crate::models::market_data::{DepthItem, MarketDepth, OHLC};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Streaming mode requested for a ticker subscription
+///
+/// Each mode is a superset of the previous one: `Ltp` carries only the last
+/// price, `Quote` adds volume and OHLC, and `Full` adds open interest,
+/// timestamps and five levels of market depth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TickMode {
+    Ltp,
+    Quote,
+    Full,
+}
+
+impl std::fmt::Display for TickMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TickMode::Ltp => write!(f, "ltp"),
+            TickMode::Quote => write!(f, "quote"),
+            TickMode::Full => write!(f, "full"),
+        }
+    }
+}
+
+/// A decoded ticker packet
+///
+/// Fields not carried by the packet's mode are `None`: LTP packets only set
+/// `last_price`, quote packets add volume and OHLC, and full packets add open
+/// interest, timestamps and depth. Index packets (`tradable == false`) never
+/// carry volume, open interest or depth.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tick {
+    /// Instrument token
+    pub instrument_token: u32,
+
+    /// Mode the packet was sent in
+    pub mode: TickMode,
+
+    /// `false` for indices, which cannot be traded
+    pub tradable: bool,
+
+    /// Last traded price
+    pub last_price: f64,
+
+    /// Quantity of the last trade
+    pub last_quantity: Option<u32>,
+
+    /// Volume weighted average traded price
+    pub average_price: Option<f64>,
+
+    /// Volume traded today
+    pub volume_traded: Option<u32>,
+
+    /// Total pending buy quantity
+    pub total_buy_quantity: Option<u32>,
+
+    /// Total pending sell quantity
+    pub total_sell_quantity: Option<u32>,
+
+    /// Day's OHLC; `close` is the previous day's close
+    pub ohlc: Option<OHLC>,
+
+    /// Percentage change of `last_price` from the previous close
+    pub change: Option<f64>,
+
+    /// Time of the last trade
+    pub last_trade_time: Option<DateTime<Utc>>,
+
+    /// Open interest
+    pub oi: Option<u32>,
+
+    /// Day's highest open interest
+    pub oi_day_high: Option<u32>,
+
+    /// Day's lowest open interest
+    pub oi_day_low: Option<u32>,
+
+    /// Exchange timestamp of the packet
+    pub exchange_timestamp: Option<DateTime<Utc>>,
+
+    /// Five levels of bids and offers
+    pub depth: Option<MarketDepth>,
+}
+
+/// Exchange segment of indices, encoded in the low byte of the token
+const SEGMENT_INDICES: u32 = 9;
+/// Currency derivatives segment
+const SEGMENT_CDS: u32 = 3;
+/// BSE currency derivatives segment
+const SEGMENT_BCD: u32 = 6;
+
+impl Tick {
+    /// Decode a whole binary WebSocket frame into ticks
+    ///
+    /// A frame starts with a 2-byte packet count followed by length-prefixed
+    /// packets. Single-byte frames are heartbeats and decode to no ticks.
+    pub fn parse_frame(frame: &[u8]) -> KiteResult<Vec<Tick>> {
+        if frame.len() < 2 {
+            return Ok(Vec::new());
+        }
+
+        let count = u16::from_be_bytes([frame[0], frame[1]]) as usize;
+        let mut ticks = Vec::with_capacity(count);
+        let mut offset = 2;
+
+        for _ in 0..count {
+            let header = frame
+                .get(offset..offset + 2)
+                .ok_or_else(|| KiteError::general("Truncated tick frame"))?;
+            let length = u16::from_be_bytes([header[0], header[1]]) as usize;
+            offset += 2;
+
+            let packet = frame
+                .get(offset..offset + length)
+                .ok_or_else(|| KiteError::general("Truncated tick frame"))?;
+            ticks.push(Tick::from_packet(packet)?);
+            offset += length;
+        }
+
+        Ok(ticks)
+    }
+
+    /// Decode a single packet, inferring its mode from its length
+    ///
+    /// Supported lengths are 8 (LTP), 28/32 (index quote/full), 44 (quote)
+    /// and 184 (full).
+    pub fn from_packet(packet: &[u8]) -> KiteResult<Tick> {
+        let instrument_token = read_u32(packet, 0)?;
+        let segment = instrument_token & 0xff;
+        let divisor = match segment {
+            SEGMENT_CDS => 10_000_000.0,
+            SEGMENT_BCD => 10_000.0,
+            _ => 100.0,
+        };
+        let price = |offset: usize| read_u32(packet, offset).map(|v| v as f64 / divisor);
+
+        let mut tick = Tick {
+            instrument_token,
+            mode: TickMode::Ltp,
+            tradable: segment != SEGMENT_INDICES,
+            last_price: price(4)?,
+            last_quantity: None,
+            average_price: None,
+            volume_traded: None,
+            total_buy_quantity: None,
+            total_sell_quantity: None,
+            ohlc: None,
+            change: None,
+            last_trade_time: None,
+            oi: None,
+            oi_day_high: None,
+            oi_day_low: None,
+            exchange_timestamp: None,
+            depth: None,
+        };
+
+        match packet.len() {
+            8 => {}
+            // Index packets: ltp, high, low, open, close, change
+            28 | 32 => {
+                let ohlc = OHLC {
+                    high: price(8)?,
+                    low: price(12)?,
+                    open: price(16)?,
+                    close: price(20)?,
+                };
+                tick.mode = if packet.len() == 32 {
+                    TickMode::Full
+                } else {
+                    TickMode::Quote
+                };
+                tick.change = Some(percent_change(tick.last_price, ohlc.close));
+                tick.ohlc = Some(ohlc);
+                if packet.len() == 32 {
+                    tick.exchange_timestamp = Some(read_timestamp(packet, 28)?);
+                }
+            }
+            44 | 184 => {
+                let ohlc = OHLC {
+                    open: price(28)?,
+                    high: price(32)?,
+                    low: price(36)?,
+                    close: price(40)?,
+                };
+                tick.mode = TickMode::Quote;
+                tick.last_quantity = Some(read_u32(packet, 8)?);
+                tick.average_price = Some(price(12)?);
+                tick.volume_traded = Some(read_u32(packet, 16)?);
+                tick.total_buy_quantity = Some(read_u32(packet, 20)?);
+                tick.total_sell_quantity = Some(read_u32(packet, 24)?);
+                tick.change = Some(percent_change(tick.last_price, ohlc.close));
+                tick.ohlc = Some(ohlc);
+
+                if packet.len() == 184 {
+                    tick.mode = TickMode::Full;
+                    tick.last_trade_time = Some(read_timestamp(packet, 44)?);
+                    tick.oi = Some(read_u32(packet, 48)?);
+                    tick.oi_day_high = Some(read_u32(packet, 52)?);
+                    tick.oi_day_low = Some(read_u32(packet, 56)?);
+                    tick.exchange_timestamp = Some(read_timestamp(packet, 60)?);
+
+                    // 10 entries of quantity, price, orders and 2 bytes padding
+                    let mut depth = MarketDepth::default();
+                    for level in 0..10 {
+                        let offset = 64 + level * 12;
+                        let item = DepthItem {
+                            quantity: read_u32(packet, offset)?,
+                            price: price(offset + 4)?,
+                            orders: read_u16(packet, offset + 8)? as u32,
+                        };
+                        if level < 5 {
+                            depth.buy.push(item);
+                        } else {
+                            depth.sell.push(item);
+                        }
+                    }
+                    tick.depth = Some(depth);
+                }
+            }
+            length => {
+                return Err(KiteError::general(format!(
+                    "Unsupported tick packet length: {}",
+                    length
+                )))
+            }
+        }
+
+        Ok(tick)
+    }
+}
+
+fn read_u32(packet: &[u8], offset: usize) -> KiteResult<u32> {
+    packet
+        .get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| KiteError::general("Truncated tick packet"))
+}
+
+fn read_u16(packet: &[u8], offset: usize) -> KiteResult<u16> {
+    packet
+        .get(offset..offset + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or_else(|| KiteError::general("Truncated tick packet"))
+}
+
+fn read_timestamp(packet: &[u8], offset: usize) -> KiteResult<DateTime<Utc>> {
+    let seconds = read_u32(packet, offset)?;
+    Utc.timestamp_opt(seconds as i64, 0)
+        .single()
+        .ok_or_else(|| KiteError::general(format!("Invalid tick timestamp: {}", seconds)))
+}
+
+fn percent_change(last_price: f64, close: f64) -> f64 {
+    if close == 0.0 {
+        0.0
+    } else {
+        (last_price - close) * 100.0 / close
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFY: u32 = 408065; // NSE, segment 1
+    const NIFTY: u32 = 256265; // Indices, segment 9
+
+    fn packet(values: &[u32]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_be_bytes()).collect()
+    }
+
+    fn quote_values() -> Vec<u32> {
+        // token, ltp, last qty, avg price, volume, buy qty, sell qty, o, h, l, c
+        vec![
+            INFY, 150050, 10, 149820, 125000, 4000, 3500, 149000, 150500, 148500, 148800,
+        ]
+    }
+
+    #[test]
+    fn test_decode_ltp_packet() {
+        let tick = Tick::from_packet(&packet(&[INFY, 150050])).unwrap();
+        assert_eq!(tick.mode, TickMode::Ltp);
+        assert!(tick.tradable);
+        assert_eq!(tick.last_price, 1500.5);
+        assert!(tick.ohlc.is_none());
+        assert!(tick.volume_traded.is_none());
+        assert!(tick.depth.is_none());
+    }
+
+    #[test]
+    fn test_decode_quote_packet() {
+        let tick = Tick::from_packet(&packet(&quote_values())).unwrap();
+        assert_eq!(tick.mode, TickMode::Quote);
+        assert_eq!(tick.last_quantity, Some(10));
+        assert_eq!(tick.average_price, Some(1498.2));
+        assert_eq!(tick.volume_traded, Some(125000));
+        assert_eq!(tick.total_buy_quantity, Some(4000));
+        assert_eq!(tick.total_sell_quantity, Some(3500));
+        let ohlc = tick.ohlc.unwrap();
+        assert_eq!(
+            (ohlc.open, ohlc.high, ohlc.low, ohlc.close),
+            (1490.0, 1505.0, 1485.0, 1488.0)
+        );
+        assert!(tick.oi.is_none());
+        assert!(tick.last_trade_time.is_none());
+    }
+
+    #[test]
+    fn test_decode_full_packet() {
+        let mut values = quote_values();
+        // last trade time, oi, oi high, oi low, exchange timestamp
+        values.extend([1705290600, 5000, 5200, 4800, 1705290601]);
+        let mut bytes = packet(&values);
+        for level in 0..10u32 {
+            bytes.extend((100 + level).to_be_bytes());
+            bytes.extend((150000 + level * 5).to_be_bytes());
+            bytes.extend((level as u16 + 1).to_be_bytes());
+            bytes.extend([0, 0]);
+        }
+        assert_eq!(bytes.len(), 184);
+
+        let tick = Tick::from_packet(&bytes).unwrap();
+        assert_eq!(tick.mode, TickMode::Full);
+        assert_eq!(tick.oi, Some(5000));
+        assert_eq!(tick.oi_day_high, Some(5200));
+        assert_eq!(tick.oi_day_low, Some(4800));
+        assert_eq!(tick.last_trade_time.unwrap().timestamp(), 1705290600);
+        assert_eq!(tick.exchange_timestamp.unwrap().timestamp(), 1705290601);
+
+        let depth = tick.depth.unwrap();
+        assert_eq!(depth.buy.len(), 5);
+        assert_eq!(depth.sell.len(), 5);
+        assert_eq!(depth.buy[0].quantity, 100);
+        assert_eq!(depth.buy[0].price, 1500.0);
+        assert_eq!(depth.sell[0].orders, 6);
+        assert_eq!(depth.sell[4].price, 1500.45);
+    }
+
+    #[test]
+    fn test_decode_index_packets() {
+        // ltp, high, low, open, close, change
+        let values = [NIFTY, 2210000, 2220000, 2190000, 2195000, 2200000, 10000];
+        let tick = Tick::from_packet(&packet(&values)).unwrap();
+        assert_eq!(tick.mode, TickMode::Quote);
+        assert!(!tick.tradable);
+        assert_eq!(tick.ohlc.as_ref().unwrap().open, 21950.0);
+        assert!((tick.change.unwrap() - 0.4545).abs() < 1e-3);
+        assert!(tick.volume_traded.is_none());
+
+        let mut values = values.to_vec();
+        values.push(1705290600);
+        let tick = Tick::from_packet(&packet(&values)).unwrap();
+        assert_eq!(tick.mode, TickMode::Full);
+        assert_eq!(tick.exchange_timestamp.unwrap().timestamp(), 1705290600);
+    }
+
+    #[test]
+    fn test_parse_frame_with_multiple_packets() {
+        let ltp = packet(&[INFY, 150050]);
+        let quote = packet(&quote_values());
+        let mut frame = 2u16.to_be_bytes().to_vec();
+        for p in [&ltp, &quote] {
+            frame.extend((p.len() as u16).to_be_bytes());
+            frame.extend(p);
+        }
+
+        let ticks = Tick::parse_frame(&frame).unwrap();
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(ticks[0].mode, TickMode::Ltp);
+        assert_eq!(ticks[1].mode, TickMode::Quote);
+
+        // Heartbeat
+        assert!(Tick::parse_frame(&[0]).unwrap().is_empty());
+        // Truncated
+        assert!(Tick::parse_frame(&frame[..frame.len() - 1]).is_err());
+        assert!(Tick::from_packet(&[0; 12]).is_err());
+    }
+}
@@ -139,6 +139,7 @@ pub mod prelude {
         KiteResponse,
         KiteResult,
 
+        MarketSegment,
         OrderType,
         Product,
         RawResponse,
@@ -175,6 +176,8 @@ pub mod prelude {
 
     // Order types
     pub use super::orders::{
+        // Margin calculation
+        BasketMargins,
         BracketOrderBuilder,
         BracketOrderParams,
         BracketOrderResponse,
@@ -187,6 +190,9 @@ pub mod prelude {
         OrderCancellation,
         OrderHistory,
         OrderHistoryEntry,
+        OrderMarginParam,
+        OrderMarginParamBuilder,
+        OrderMargins,
         OrderMeta,
 
         // Order operations
@@ -198,6 +204,7 @@ pub mod prelude {
         OrderResponse,
 
         OrderStatus,
+        PostbackPayload,
         // Order history and trades
         Trade,
         TradeBook,
@@ -229,6 +236,8 @@ pub mod prelude {
     // Market data types
     pub use super::market_data::{
         Candle,
+        CorporateAction,
+        CorporateActions,
         DepthItem,
         DepthLevel,
         HistoricalData,
@@ -238,6 +247,7 @@ pub mod prelude {
         HistoricalQuote,
         // Instruments
         Instrument,
+        InstrumentIndex,
         InstrumentLookup,
 
         InstrumentSearch,
@@ -248,9 +258,14 @@ pub mod prelude {
         MarketDepthFull,
         MarketState,
         MarketStatus,
+        OHLCQuote,
         // Quotes
         Quote,
+        QuoteBundle,
         QuoteRequest,
+        // Ticker
+        Tick,
+        TickMode,
         LTP,
         OHLC,
         OHLCV,
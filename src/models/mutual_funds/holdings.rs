@@ -1,3 +1,4 @@
+use crate::models::common::{round_price, PRICE_DECIMALS};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
@@ -155,6 +156,8 @@ impl MFHolding {
 
 impl MFHoldings {
     /// Calculate portfolio summary
+    ///
+    /// Totals are rounded to [`PRICE_DECIMALS`] places.
     pub fn portfolio_summary(&self) -> MFPortfolioSummary {
         let total_investment = self.holdings.iter().map(|h| h.investment_value()).sum();
         let total_current_value = self.holdings.iter().map(|h| h.current_value()).sum();
@@ -170,10 +173,10 @@ impl MFHoldings {
         let loss_holdings = self.holdings.iter().filter(|h| h.is_loss()).count();
 
         MFPortfolioSummary {
-            total_investment,
-            total_current_value,
-            total_pnl,
-            total_pnl_percentage,
+            total_investment: round_price(total_investment, PRICE_DECIMALS),
+            total_current_value: round_price(total_current_value, PRICE_DECIMALS),
+            total_pnl: round_price(total_pnl, PRICE_DECIMALS),
+            total_pnl_percentage: round_price(total_pnl_percentage, PRICE_DECIMALS),
             holdings_count: self.holdings.len(),
             profitable_holdings,
             loss_holdings,
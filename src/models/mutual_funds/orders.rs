@@ -1,4 +1,6 @@
+use crate::models::common::timestamps::{deserialize_kite_time, deserialize_optional_kite_time};
 use crate::models::common::TransactionType;
+use crate::models::mutual_funds::MFInstrument;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +12,7 @@ pub struct MFOrder {
     pub order_id: String,
 
     /// Exchange order ID
-    #[serde(rename = "exchange_order_id")]
+    #[serde(rename = "exchange_order_id", default)]
     pub exchange_order_id: Option<String>,
 
     /// Trading symbol
@@ -24,10 +26,11 @@ pub struct MFOrder {
     pub status: MFOrderStatus,
 
     /// Status message
-    #[serde(rename = "status_message")]
+    #[serde(rename = "status_message", default)]
     pub status_message: Option<String>,
 
     /// Folio number
+    #[serde(default)]
     pub folio: Option<String>,
 
     /// Transaction type (BUY/SELL)
@@ -35,36 +38,54 @@ pub struct MFOrder {
     pub transaction_type: TransactionType,
 
     /// Amount (for purchases)
+    #[serde(default)]
     pub amount: Option<f64>,
 
     /// Quantity (for redemptions)
+    #[serde(default)]
     pub quantity: Option<f64>,
 
     /// Purchase type (FRESH/ADDITIONAL)
-    #[serde(rename = "purchase_type")]
+    #[serde(rename = "purchase_type", default)]
     pub purchase_type: Option<String>,
 
+    /// Order variety (e.g. `regular`, `sip`)
+    #[serde(default)]
+    pub variety: Option<String>,
+
     /// Order timestamp
-    #[serde(rename = "order_timestamp")]
+    #[serde(rename = "order_timestamp", deserialize_with = "deserialize_kite_time")]
     pub order_timestamp: DateTime<Utc>,
 
     /// Exchange timestamp
-    #[serde(rename = "exchange_timestamp")]
+    #[serde(
+        rename = "exchange_timestamp",
+        default,
+        deserialize_with = "deserialize_optional_kite_time"
+    )]
     pub exchange_timestamp: Option<DateTime<Utc>>,
 
     /// Settlement ID
-    #[serde(rename = "settlement_id")]
+    #[serde(rename = "settlement_id", default)]
     pub settlement_id: Option<String>,
 
     /// Average price (NAV at which units were allotted/redeemed)
-    #[serde(rename = "average_price")]
+    ///
+    /// Kite reports `0` until units are allotted; use
+    /// [`MFOrder::allotted_nav`] to treat that as "not yet known".
+    #[serde(rename = "average_price", default)]
     pub average_price: Option<f64>,
 
+    /// Last known NAV of the fund when the order was fetched (`0` if unknown)
+    #[serde(rename = "last_price", default)]
+    pub last_price: Option<f64>,
+
     /// Placed by user ID
-    #[serde(rename = "placed_by")]
+    #[serde(rename = "placed_by", default)]
     pub placed_by: String,
 
     /// Tag
+    #[serde(default)]
     pub tag: Option<String>,
 }
 
@@ -72,7 +93,9 @@ pub struct MFOrder {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum MFOrderStatus {
-    /// Order placed and pending
+    /// Order placed and awaiting allotment
+    Open,
+    /// Order completed and units allotted
     Complete,
     /// Order is cancelled
     Cancelled,
@@ -120,6 +143,16 @@ pub struct MFOrders {
 }
 
 impl MFOrder {
+    /// Check if order is still awaiting allotment
+    pub fn is_open(&self) -> bool {
+        self.status == MFOrderStatus::Open
+    }
+
+    /// NAV at which units were allotted, or `None` while it is not known
+    pub fn allotted_nav(&self) -> Option<f64> {
+        self.average_price.filter(|nav| *nav > 0.0)
+    }
+
     /// Check if order is completed
     pub fn is_complete(&self) -> bool {
         self.status == MFOrderStatus::Complete
@@ -150,7 +183,7 @@ impl MFOrder {
         match (
             self.is_purchase(),
             self.amount,
-            self.average_price,
+            self.allotted_nav(),
             self.quantity,
         ) {
             (true, Some(amount), _, _) => Some(amount),
@@ -164,7 +197,7 @@ impl MFOrder {
         match (
             self.is_purchase(),
             self.amount,
-            self.average_price,
+            self.allotted_nav(),
             self.quantity,
         ) {
             (true, Some(amount), Some(nav), _) if nav > 0.0 => Some(amount / nav),
@@ -183,7 +216,7 @@ impl MFOrder {
         if self.is_purchase() {
             self.amount
         } else {
-            match (self.average_price, self.quantity) {
+            match (self.allotted_nav(), self.quantity) {
                 (Some(nav), Some(qty)) => Some(nav * qty),
                 _ => None,
             }
@@ -221,25 +254,71 @@ impl MFOrderParams {
     }
 
     /// Validate the order parameters
+    ///
+    /// Purchases need either an `amount` or a `quantity`, redemptions need a
+    /// `quantity`, and the two are mutually exclusive. Use
+    /// [`validate_for`](Self::validate_for) to also check the fund's minimums.
     pub fn validate(&self) -> Result<(), String> {
         if self.trading_symbol.is_empty() {
             return Err("Trading symbol is required".to_string());
         }
 
+        if self.amount.is_some() && self.quantity.is_some() {
+            return Err("Amount and quantity are mutually exclusive".to_string());
+        }
+        if self.amount.is_some_and(|amount| amount <= 0.0) {
+            return Err("Amount must be positive".to_string());
+        }
+        if self.quantity.is_some_and(|quantity| quantity <= 0.0) {
+            return Err("Quantity must be positive".to_string());
+        }
+
         match self.transaction_type {
             TransactionType::BUY => {
-                if self.amount.is_none() || self.amount.unwrap() <= 0.0 {
-                    return Err(
-                        "Amount is required and must be positive for purchase orders".to_string(),
-                    );
+                if self.amount.is_none() && self.quantity.is_none() {
+                    return Err("Amount or quantity is required for purchase orders".to_string());
                 }
             }
             TransactionType::SELL => {
-                if self.quantity.is_none() || self.quantity.unwrap() <= 0.0 {
-                    return Err(
-                        "Quantity is required and must be positive for redemption orders"
-                            .to_string(),
-                    );
+                if self.quantity.is_none() {
+                    return Err("Quantity is required for redemption orders".to_string());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the order parameters against the fund's limits
+    ///
+    /// In addition to [`validate`](Self::validate), a purchase amount must be
+    /// at least the fund's `minimum_purchase_amount` and a redemption
+    /// quantity at least its `minimum_redemption_quantity`.
+    pub fn validate_for(&self, fund: &MFInstrument) -> Result<(), String> {
+        self.validate()?;
+
+        if fund.trading_symbol != self.trading_symbol {
+            return Err(format!(
+                "Order is for {} but fund is {}",
+                self.trading_symbol, fund.trading_symbol
+            ));
+        }
+
+        if let Some(amount) = self.amount {
+            if amount < fund.minimum_purchase_amount {
+                return Err(format!(
+                    "Amount {} is below the fund minimum of {}",
+                    amount, fund.minimum_purchase_amount
+                ));
+            }
+        }
+        if self.transaction_type == TransactionType::SELL {
+            if let Some(quantity) = self.quantity {
+                if quantity < fund.minimum_redemption_quantity {
+                    return Err(format!(
+                        "Quantity {} is below the fund minimum redemption of {}",
+                        quantity, fund.minimum_redemption_quantity
+                    ));
                 }
             }
         }
@@ -1,5 +1,8 @@
+use crate::models::common::timestamps::deserialize_kite_time;
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// SIP (Systematic Investment Plan) data structure
@@ -20,45 +23,67 @@ pub struct SIP {
     pub status: SIPStatus,
 
     /// Created timestamp
+    #[serde(deserialize_with = "deserialize_kite_time")]
     pub created: DateTime<Utc>,
 
     /// Frequency (monthly, weekly, daily)
     pub frequency: SIPFrequency,
 
     /// Installment amount
-    #[serde(rename = "installment_amount")]
+    #[serde(rename = "instalment_amount", alias = "installment_amount")]
     pub installment_amount: f64,
 
     /// Installments completed
-    #[serde(rename = "completed_instalments")]
+    #[serde(rename = "completed_instalments", default)]
     pub completed_instalments: u32,
 
-    /// Pending installments
-    #[serde(rename = "pending_instalments")]
+    /// Pending installments, `None` for a perpetual SIP
+    ///
+    /// Kite reports `-1` for perpetual SIPs; a list of pending instalments is
+    /// also accepted and counted.
+    #[serde(
+        rename = "pending_instalments",
+        default,
+        deserialize_with = "deserialize_pending_instalments"
+    )]
     pub pending_instalments: Option<u32>,
 
     /// Next installment date
-    #[serde(rename = "next_instalment")]
+    #[serde(
+        rename = "next_instalment",
+        default,
+        deserialize_with = "deserialize_optional_date"
+    )]
     pub next_instalment: Option<NaiveDate>,
 
     /// Last installment date
-    #[serde(rename = "last_instalment")]
+    #[serde(
+        rename = "last_instalment",
+        default,
+        deserialize_with = "deserialize_optional_date"
+    )]
     pub last_instalment: Option<NaiveDate>,
 
     /// SIP trigger price (if any)
-    #[serde(rename = "trigger_price")]
+    #[serde(rename = "trigger_price", default)]
     pub trigger_price: Option<f64>,
 
     /// Tag
+    #[serde(default)]
     pub tag: Option<String>,
 
     /// Dividend type
-    #[serde(rename = "dividend_type")]
+    #[serde(rename = "dividend_type", default)]
     pub dividend_type: String,
 
-    /// Step up configuration (if any)
-    #[serde(rename = "step_up")]
-    pub step_up: Option<SIPStepUp>,
+    /// Scheduled step-ups, sent by Kite as `{"DD-MM": percentage}`
+    #[serde(
+        rename = "step_up",
+        default,
+        serialize_with = "serialize_step_ups",
+        deserialize_with = "deserialize_step_ups"
+    )]
+    pub step_up: Vec<SIPStepUp>,
 }
 
 /// SIP status enumeration
@@ -89,18 +114,17 @@ pub enum SIPFrequency {
     Quarterly,
 }
 
-/// SIP step-up configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// SIP step-up: raise the instalment amount by `percentage` on `date` each year
+///
+/// Kite writes step-ups as `DD-MM:percentage`, e.g. `15-02:10` for a 10%
+/// increase every 15th of February.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SIPStepUp {
-    /// Step-up amount
-    pub amount: f64,
-
-    /// Step-up frequency (yearly, half-yearly)
-    pub frequency: String,
+    /// Day and month of the yearly step-up (`DD-MM`)
+    pub date: String,
 
-    /// Next step-up date
-    #[serde(rename = "next_step_up")]
-    pub next_step_up: Option<NaiveDate>,
+    /// Percentage increase applied on that date
+    pub percentage: f64,
 }
 
 /// SIP creation parameters
@@ -125,6 +149,15 @@ pub struct SIPParams {
     #[serde(rename = "initial_amount", skip_serializing_if = "Option::is_none")]
     pub initial_amount: Option<f64>,
 
+    /// Day the instalment is triggered on: 1-28 for monthly and quarterly
+    /// SIPs, 1-7 (day of the week) for weekly SIPs; not used for daily SIPs
+    #[serde(rename = "instalment_day", skip_serializing_if = "Option::is_none")]
+    pub instalment_day: Option<u32>,
+
+    /// Yearly step-up of the instalment amount
+    #[serde(rename = "step_up", skip_serializing_if = "Option::is_none")]
+    pub step_up: Option<SIPStepUp>,
+
     /// Tag
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
@@ -149,6 +182,14 @@ pub struct SIPModifyParams {
     #[serde(rename = "instalments", skip_serializing_if = "Option::is_none")]
     pub instalments: Option<u32>,
 
+    /// New instalment day, see [`SIPParams::instalment_day`]
+    #[serde(rename = "instalment_day", skip_serializing_if = "Option::is_none")]
+    pub instalment_day: Option<u32>,
+
+    /// New yearly step-up
+    #[serde(rename = "step_up", skip_serializing_if = "Option::is_none")]
+    pub step_up: Option<SIPStepUp>,
+
     /// SIP status (to pause/resume)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<SIPStatus>,
@@ -225,7 +266,7 @@ impl SIP {
 
     /// Check if step-up is configured
     pub fn has_step_up(&self) -> bool {
-        self.step_up.is_some()
+        !self.step_up.is_empty()
     }
 
     /// Get monthly equivalent amount (for comparison across frequencies)
@@ -269,6 +310,8 @@ impl SIPParams {
             instalments: None,
             frequency,
             initial_amount: None,
+            instalment_day: None,
+            step_up: None,
             tag: None,
         }
     }
@@ -285,6 +328,18 @@ impl SIPParams {
         self
     }
 
+    /// Set the instalment day
+    pub fn instalment_day(mut self, instalment_day: u32) -> Self {
+        self.instalment_day = Some(instalment_day);
+        self
+    }
+
+    /// Set a yearly step-up
+    pub fn step_up(mut self, step_up: SIPStepUp) -> Self {
+        self.step_up = Some(step_up);
+        self
+    }
+
     /// Set tag
     pub fn tag<S: Into<String>>(mut self, tag: S) -> Self {
         self.tag = Some(tag.into());
@@ -322,10 +377,118 @@ impl SIPParams {
             }
         }
 
+        validate_instalment_day(&self.frequency, self.instalment_day)?;
+        if let Some(step_up) = &self.step_up {
+            step_up.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SIPModifyParams {
+    /// Create modification parameters that leave everything unchanged
+    pub fn new(sip_id: impl Into<String>) -> Self {
+        Self {
+            sip_id: sip_id.into(),
+            amount: None,
+            frequency: None,
+            instalments: None,
+            instalment_day: None,
+            step_up: None,
+            status: None,
+        }
+    }
+
+    /// Set a new instalment amount
+    pub fn amount(mut self, amount: f64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Set a new frequency
+    pub fn frequency(mut self, frequency: SIPFrequency) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Set a new number of instalments
+    pub fn instalments(mut self, instalments: u32) -> Self {
+        self.instalments = Some(instalments);
+        self
+    }
+
+    /// Set a new instalment day
+    pub fn instalment_day(mut self, instalment_day: u32) -> Self {
+        self.instalment_day = Some(instalment_day);
+        self
+    }
+
+    /// Set a new yearly step-up
+    pub fn step_up(mut self, step_up: SIPStepUp) -> Self {
+        self.step_up = Some(step_up);
+        self
+    }
+
+    /// Pause or resume the SIP
+    pub fn status(mut self, status: SIPStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Validate modification parameters
+    ///
+    /// The instalment day is checked against the new frequency, or against
+    /// `current_frequency` when the frequency is not being changed. With
+    /// neither known it must fall within 1-28.
+    pub fn validate(&self, current_frequency: Option<&SIPFrequency>) -> Result<(), String> {
+        if matches!(self.amount, Some(amount) if amount <= 0.0) {
+            return Err("Amount must be positive".to_string());
+        }
+
+        if self.instalments == Some(0) {
+            return Err("Number of installments must be positive".to_string());
+        }
+
+        let frequency = self
+            .frequency
+            .as_ref()
+            .or(current_frequency)
+            .unwrap_or(&SIPFrequency::Monthly);
+        validate_instalment_day(frequency, self.instalment_day)?;
+        if let Some(step_up) = &self.step_up {
+            step_up.validate()?;
+        }
+
         Ok(())
     }
 }
 
+/// Check an instalment day against the SIP frequency
+fn validate_instalment_day(frequency: &SIPFrequency, day: Option<u32>) -> Result<(), String> {
+    let Some(day) = day else {
+        return Ok(());
+    };
+
+    let range = match frequency {
+        SIPFrequency::Monthly | SIPFrequency::Quarterly => 1..=28,
+        SIPFrequency::Weekly => 1..=7,
+        SIPFrequency::Daily => {
+            return Err("Instalment day cannot be set for a daily SIP".to_string())
+        }
+    };
+    if !range.contains(&day) {
+        return Err(format!(
+            "Instalment day for a {} SIP must be between {} and {}, got {}",
+            frequency,
+            range.start(),
+            range.end(),
+            day
+        ));
+    }
+    Ok(())
+}
+
 impl SIPs {
     /// Get active SIPs
     pub fn active_sips(&self) -> Vec<&SIP> {
@@ -375,6 +538,19 @@ impl SIPs {
     }
 }
 
+/// Lowercase form used when modifying a SIP (`active`, `paused`, ...)
+impl fmt::Display for SIPStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SIPStatus::Active => "active",
+            SIPStatus::Paused => "paused",
+            SIPStatus::Cancelled => "cancelled",
+            SIPStatus::Complete => "complete",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 impl fmt::Display for SIPFrequency {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -386,3 +562,172 @@ impl fmt::Display for SIPFrequency {
         write!(f, "{}", s)
     }
 }
+
+impl SIPStepUp {
+    /// Create a step-up for a `DD-MM` date
+    pub fn new(date: impl Into<String>, percentage: f64) -> Self {
+        Self {
+            date: date.into(),
+            percentage,
+        }
+    }
+
+    /// Validate the step-up before it is sent
+    pub fn validate(&self) -> Result<(), String> {
+        if self.date.is_empty() || self.date.contains(':') {
+            return Err(format!("Invalid step-up date: {:?}", self.date));
+        }
+        if self.percentage <= 0.0 {
+            return Err("Step-up percentage must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SIPStepUp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.date, self.percentage)
+    }
+}
+
+/// Deserialize Kite's `{"DD-MM": percentage}` step-up map (null means none)
+fn deserialize_step_ups<'de, D>(deserializer: D) -> Result<Vec<SIPStepUp>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let map = Option::<BTreeMap<String, f64>>::deserialize(deserializer)?.unwrap_or_default();
+    Ok(map
+        .into_iter()
+        .map(|(date, percentage)| SIPStepUp { date, percentage })
+        .collect())
+}
+
+/// Serialize step-ups back into Kite's map form
+fn serialize_step_ups<S>(step_ups: &[SIPStepUp], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_map(
+        step_ups
+            .iter()
+            .map(|step_up| (&step_up.date, step_up.percentage)),
+    )
+}
+
+/// Accept a count (negative for perpetual), a list of instalments or null
+fn deserialize_pending_instalments<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match JsonValue::deserialize(deserializer)? {
+        JsonValue::Number(n) => Ok(n.as_u64().and_then(|n| u32::try_from(n).ok())),
+        JsonValue::Array(items) => Ok(Some(items.len() as u32)),
+        JsonValue::Null => Ok(None),
+        other => Err(serde::de::Error::custom(format!(
+            "invalid pending_instalments: {}",
+            other
+        ))),
+    }
+}
+
+/// Accept `YYYY-MM-DD`, `YYYY-MM-DD HH:MM:SS`, `""` or null
+fn deserialize_optional_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.is_empty() => {
+            let date = s.get(..10).unwrap_or(&s);
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map(Some)
+                .map_err(serde::de::Error::custom)
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kite_sip() {
+        let sip: SIP = serde_json::from_value(serde_json::json!({
+            "dividend_type": "idcw",
+            "pending_instalments": -1,
+            "created": "2021-05-25 18:05:31",
+            "last_instalment": "2021-06-05 00:00:00",
+            "transaction_type": "BUY",
+            "frequency": "monthly",
+            "instalment_date": 5,
+            "fund": "Kotak Liquid Fund - Direct Plan - Regular Payout of Dividend",
+            "sip_id": "1234",
+            "tradingsymbol": "INF174K01N69",
+            "tag": "",
+            "instalment_amount": 1000,
+            "instalments": -1,
+            "status": "ACTIVE",
+            "order_id": "4343",
+            "completed_instalments": 1,
+            "next_instalment": "2021-07-05",
+            "trigger_price": 0,
+            "step_up": {"15-02": 10},
+            "sip_reg_num": null,
+            "sip_type": "normal"
+        }))
+        .unwrap();
+
+        assert_eq!(sip.frequency, SIPFrequency::Monthly);
+        assert_eq!(sip.status, SIPStatus::Active);
+        assert!(sip.is_perpetual());
+        assert_eq!(sip.installment_amount, 1000.0);
+        assert_eq!(sip.last_instalment, NaiveDate::from_ymd_opt(2021, 6, 5));
+        assert_eq!(sip.next_instalment, NaiveDate::from_ymd_opt(2021, 7, 5));
+        assert_eq!(sip.step_up, vec![SIPStepUp::new("15-02", 10.0)]);
+        assert_eq!(sip.step_up[0].to_string(), "15-02:10");
+
+        // Serialized SIPs read back unchanged
+        let round_trip: SIP = serde_json::from_value(serde_json::to_value(&sip).unwrap()).unwrap();
+        assert_eq!(round_trip.step_up, sip.step_up);
+        assert_eq!(round_trip.created, sip.created);
+
+        // A list of pending instalments is counted
+        let mut value = serde_json::to_value(&sip).unwrap();
+        value["pending_instalments"] = serde_json::json!([
+            {"date": "2021-07-05"},
+            {"date": "2021-08-05"}
+        ]);
+        let sip: SIP = serde_json::from_value(value).unwrap();
+        assert_eq!(sip.pending_instalments, Some(2));
+        assert_eq!(sip.total_instalments(), Some(3));
+    }
+
+    #[test]
+    fn test_instalment_day_validation() {
+        let monthly = SIPParams::monthly("INF174K01N69".to_string(), 1000.0);
+        assert!(monthly.clone().instalment_day(28).validate().is_ok());
+        assert!(monthly.clone().instalment_day(0).validate().is_err());
+        let err = monthly.clone().instalment_day(29).validate().unwrap_err();
+        assert!(err.contains("between 1 and 28"), "{}", err);
+
+        let weekly = SIPParams::weekly("INF174K01N69".to_string(), 1000.0);
+        assert!(weekly.clone().instalment_day(7).validate().is_ok());
+        assert!(weekly.instalment_day(8).validate().is_err());
+
+        let daily = SIPParams::daily("INF174K01N69".to_string(), 1000.0);
+        assert!(daily.instalment_day(1).validate().is_err());
+
+        let bad_step_up = monthly.step_up(SIPStepUp::new("15-02", 0.0));
+        assert!(bad_step_up.validate().is_err());
+
+        let modify = SIPModifyParams::new("1234").instalment_day(30);
+        assert!(modify.validate(None).is_err());
+        assert!(modify.validate(Some(&SIPFrequency::Monthly)).is_err());
+        let modify = SIPModifyParams::new("1234").instalment_day(20);
+        assert!(modify.validate(None).is_ok());
+        assert!(modify
+            .frequency(SIPFrequency::Weekly)
+            .validate(Some(&SIPFrequency::Monthly))
+            .is_err());
+    }
+}
@@ -5,13 +5,19 @@
  * - Order placement, modification, and cancellation
  * - Order status and history
  * - Order types and parameters
+ * - Margin calculation for order baskets
+ * - Order postbacks
  */
 
 pub mod order_data;
 pub mod order_history;
+pub mod order_margins;
 pub mod order_params;
+pub mod postback;
 
 // Re-export all public types
 pub use order_data::*;
 pub use order_history::*;
+pub use order_margins::*;
 pub use order_params::*;
+pub use postback::*;
@@ -1,6 +1,7 @@
+use crate::models::common::timestamps::{deserialize_kite_time, deserialize_optional_kite_time};
 use crate::models::common::{Exchange, OrderType, Product, TransactionType, Validity};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Order data structure as returned by KiteConnect API
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,15 +33,23 @@ pub struct Order {
     pub status_message_raw: Option<String>,
 
     /// Order timestamp
-    #[serde(rename = "order_timestamp")]
+    #[serde(rename = "order_timestamp", deserialize_with = "deserialize_kite_time")]
     pub order_timestamp: DateTime<Utc>,
 
-    /// Exchange timestamp
-    #[serde(rename = "exchange_timestamp")]
+    /// Exchange timestamp (null until the exchange acknowledges the order)
+    #[serde(
+        rename = "exchange_timestamp",
+        default,
+        deserialize_with = "deserialize_optional_kite_time"
+    )]
     pub exchange_timestamp: Option<DateTime<Utc>>,
 
     /// Exchange update timestamp
-    #[serde(rename = "exchange_update_timestamp")]
+    #[serde(
+        rename = "exchange_update_timestamp",
+        default,
+        deserialize_with = "deserialize_optional_kite_time"
+    )]
     pub exchange_update_timestamp: Option<DateTime<Utc>>,
 
     /// Trading symbol
@@ -69,47 +78,55 @@ pub struct Order {
     pub product: Product,
 
     /// Quantity
+    #[serde(default)]
     pub quantity: u32,
 
     /// Disclosed quantity
-    #[serde(rename = "disclosed_quantity")]
+    #[serde(rename = "disclosed_quantity", default)]
     pub disclosed_quantity: u32,
 
     /// Price
+    #[serde(default)]
     pub price: f64,
 
     /// Trigger price for SL orders
-    #[serde(rename = "trigger_price")]
+    #[serde(rename = "trigger_price", default)]
     pub trigger_price: f64,
 
     /// Average price at which the order was executed
-    #[serde(rename = "average_price")]
+    #[serde(rename = "average_price", default)]
     pub average_price: f64,
 
     /// Filled quantity
-    #[serde(rename = "filled_quantity")]
+    #[serde(rename = "filled_quantity", default)]
     pub filled_quantity: u32,
 
     /// Pending quantity
-    #[serde(rename = "pending_quantity")]
+    #[serde(rename = "pending_quantity", default)]
     pub pending_quantity: u32,
 
     /// Cancelled quantity
-    #[serde(rename = "cancelled_quantity")]
+    #[serde(rename = "cancelled_quantity", default)]
     pub cancelled_quantity: u32,
 
     /// Market protection percentage
-    #[serde(rename = "market_protection")]
+    #[serde(rename = "market_protection", default)]
     pub market_protection: f64,
 
     /// Meta information
+    #[serde(default, deserialize_with = "deserialize_order_meta")]
     pub meta: Option<OrderMeta>,
 
     /// Tag for the order
     pub tag: Option<String>,
 
     /// GUID for idempotency
+    #[serde(default)]
     pub guid: String,
+
+    /// Variety (regular, co, amo, iceberg, auction)
+    #[serde(default)]
+    pub variety: Option<String>,
 }
 
 /// Order status enumeration
@@ -128,6 +145,19 @@ pub enum OrderStatus {
     Put,
     /// Order validation passed
     Validated,
+    /// Order received by the OMS (transient, first state of a new order)
+    #[serde(rename = "PUT ORDER REQ RECEIVED")]
+    PutOrderReqReceived,
+    /// Order awaiting RMS validation (transient)
+    #[serde(rename = "VALIDATION PENDING")]
+    ValidationPending,
+    /// Order awaiting exchange acknowledgement (transient)
+    #[serde(rename = "OPEN PENDING")]
+    OpenPending,
+    /// Order modified (transient, precedes the order's next state)
+    Modified,
+    /// Order updated (transient)
+    Update,
     /// Order modification validation passed
     #[serde(rename = "MODIFY VALIDATION PENDING")]
     ModifyValidationPending,
@@ -158,6 +188,29 @@ pub struct OrderMeta {
     pub iceberg_quantity: Option<u32>,
 }
 
+/// Deserialize `meta`, which Kite sends as `""` when an order has none
+pub(crate) fn deserialize_order_meta<'de, D>(deserializer: D) -> Result<Option<OrderMeta>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Meta {
+        Meta(OrderMeta),
+        Empty(String),
+    }
+
+    match Option::<Meta>::deserialize(deserializer)? {
+        Some(Meta::Meta(meta)) => Ok(Some(meta)),
+        Some(Meta::Empty(s)) if s.is_empty() => Ok(None),
+        Some(Meta::Empty(s)) => Err(serde::de::Error::custom(format!(
+            "invalid order meta: {:?}",
+            s
+        ))),
+        None => Ok(None),
+    }
+}
+
 /// Order modification data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderModification {
@@ -193,6 +246,18 @@ pub struct OrderCancellation {
     /// Order ID that was cancelled
     #[serde(rename = "order_id")]
     pub order_id: String,
+
+    /// Why the cancellation failed, for results reported by
+    /// `KiteConnect::cancel_all_orders`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl OrderCancellation {
+    /// Check if the order was cancelled
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
 }
 
 /// Bracket order response
@@ -217,10 +282,20 @@ impl Order {
     pub fn is_open(&self) -> bool {
         matches!(
             self.status,
-            OrderStatus::Open | OrderStatus::Put | OrderStatus::Validated
+            OrderStatus::Open
+                | OrderStatus::Put
+                | OrderStatus::Validated
+                | OrderStatus::PutOrderReqReceived
+                | OrderStatus::ValidationPending
+                | OrderStatus::OpenPending
         )
     }
 
+    /// Check if order can still be cancelled (`OPEN` or `TRIGGER PENDING`)
+    pub fn is_cancellable(&self) -> bool {
+        matches!(self.status, OrderStatus::Open | OrderStatus::TriggerPending)
+    }
+
     /// Check if order is completed
     pub fn is_complete(&self) -> bool {
         self.status == OrderStatus::Complete
@@ -264,6 +339,11 @@ impl OrderStatus {
             OrderStatus::Open
                 | OrderStatus::Put
                 | OrderStatus::Validated
+                | OrderStatus::PutOrderReqReceived
+                | OrderStatus::ValidationPending
+                | OrderStatus::OpenPending
+                | OrderStatus::Modified
+                | OrderStatus::Update
                 | OrderStatus::ModifyValidationPending
                 | OrderStatus::ModifyPending
                 | OrderStatus::TriggerPending
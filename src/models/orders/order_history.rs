@@ -1,4 +1,5 @@
 use super::OrderStatus;
+use crate::models::common::timestamps::{deserialize_kite_time, deserialize_optional_kite_time};
 use crate::models::common::{Exchange, OrderType, Product, TransactionType, Validity};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -40,11 +41,14 @@ pub struct Trade {
     pub quantity: u32,
 
     /// Fill timestamp
-    #[serde(rename = "fill_timestamp")]
+    #[serde(rename = "fill_timestamp", deserialize_with = "deserialize_kite_time")]
     pub fill_timestamp: DateTime<Utc>,
 
     /// Exchange timestamp
-    #[serde(rename = "exchange_timestamp")]
+    #[serde(
+        rename = "exchange_timestamp",
+        deserialize_with = "deserialize_kite_time"
+    )]
     pub exchange_timestamp: DateTime<Utc>,
 
     /// Transaction type (BUY/SELL)
@@ -82,15 +86,23 @@ pub struct OrderHistoryEntry {
     pub status_message_raw: Option<String>,
 
     /// Order timestamp
-    #[serde(rename = "order_timestamp")]
+    #[serde(rename = "order_timestamp", deserialize_with = "deserialize_kite_time")]
     pub order_timestamp: DateTime<Utc>,
 
-    /// Exchange timestamp
-    #[serde(rename = "exchange_timestamp")]
+    /// Exchange timestamp (null until the exchange acknowledges the order)
+    #[serde(
+        rename = "exchange_timestamp",
+        default,
+        deserialize_with = "deserialize_optional_kite_time"
+    )]
     pub exchange_timestamp: Option<DateTime<Utc>>,
 
     /// Exchange update timestamp
-    #[serde(rename = "exchange_update_timestamp")]
+    #[serde(
+        rename = "exchange_update_timestamp",
+        default,
+        deserialize_with = "deserialize_optional_kite_time"
+    )]
     pub exchange_update_timestamp: Option<DateTime<Utc>>,
 
     /// Trading symbol
@@ -0,0 +1,348 @@
+use crate::models::common::{
+    Exchange, KiteError, KiteResult, OrderType, Product, TransactionType, Variety,
+};
+use serde::{Deserialize, Serialize};
+
+/// One order in a margin calculation (`/margins/orders` or `/margins/basket`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderMarginParam {
+    /// Exchange
+    pub exchange: Exchange,
+
+    /// Trading symbol
+    #[serde(rename = "tradingsymbol")]
+    pub trading_symbol: String,
+
+    /// Transaction type (BUY/SELL)
+    pub transaction_type: TransactionType,
+
+    /// Order variety
+    pub variety: Variety,
+
+    /// Product type
+    pub product: Product,
+
+    /// Order type
+    pub order_type: OrderType,
+
+    /// Quantity
+    pub quantity: u32,
+
+    /// Price (required for LIMIT orders)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+
+    /// Trigger price (required for SL and SL-M orders)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<f64>,
+}
+
+/// Builder for margin calculation orders
+#[derive(Debug, Clone)]
+pub struct OrderMarginParamBuilder {
+    params: OrderMarginParam,
+}
+
+impl OrderMarginParamBuilder {
+    /// Create a new builder for a regular CNC limit buy
+    pub fn new() -> Self {
+        Self {
+            params: OrderMarginParam {
+                exchange: Exchange::NSE,
+                trading_symbol: String::new(),
+                transaction_type: TransactionType::BUY,
+                variety: Variety::Regular,
+                product: Product::CNC,
+                order_type: OrderType::LIMIT,
+                quantity: 0,
+                price: None,
+                trigger_price: None,
+            },
+        }
+    }
+
+    /// Set trading symbol
+    pub fn trading_symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.params.trading_symbol = symbol.into();
+        self
+    }
+
+    /// Set exchange
+    pub fn exchange(mut self, exchange: Exchange) -> Self {
+        self.params.exchange = exchange;
+        self
+    }
+
+    /// Set transaction type
+    pub fn transaction_type(mut self, transaction_type: TransactionType) -> Self {
+        self.params.transaction_type = transaction_type;
+        self
+    }
+
+    /// Set variety
+    pub fn variety(mut self, variety: Variety) -> Self {
+        self.params.variety = variety;
+        self
+    }
+
+    /// Set product
+    pub fn product(mut self, product: Product) -> Self {
+        self.params.product = product;
+        self
+    }
+
+    /// Set order type
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.params.order_type = order_type;
+        self
+    }
+
+    /// Set quantity
+    pub fn quantity(mut self, quantity: u32) -> Self {
+        self.params.quantity = quantity;
+        self
+    }
+
+    /// Set price (for limit orders)
+    pub fn price(mut self, price: f64) -> Self {
+        self.params.price = Some(price);
+        self
+    }
+
+    /// Set trigger price (for SL orders)
+    pub fn trigger_price(mut self, trigger_price: f64) -> Self {
+        self.params.trigger_price = Some(trigger_price);
+        self
+    }
+
+    /// Build the margin calculation order
+    ///
+    /// Returns `KiteError::InputException` for missing fields.
+    pub fn build(self) -> KiteResult<OrderMarginParam> {
+        if self.params.trading_symbol.is_empty() {
+            return Err(KiteError::input_exception("Trading symbol is required"));
+        }
+
+        if self.params.quantity == 0 {
+            return Err(KiteError::input_exception(
+                "Quantity must be greater than 0",
+            ));
+        }
+
+        if self.params.order_type == OrderType::LIMIT && self.params.price.is_none() {
+            return Err(KiteError::input_exception(
+                "Price is required for LIMIT orders",
+            ));
+        }
+
+        if matches!(self.params.order_type, OrderType::SL | OrderType::SLM)
+            && self.params.trigger_price.is_none()
+        {
+            return Err(KiteError::input_exception(
+                "Trigger price is required for SL/SL-M orders",
+            ));
+        }
+
+        Ok(self.params)
+    }
+}
+
+impl Default for OrderMarginParamBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Margin required for one order of a basket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderMargins {
+    /// Margin type ("equity" or "commodity")
+    #[serde(rename = "type")]
+    pub margin_type: String,
+
+    /// Trading symbol
+    #[serde(rename = "tradingsymbol")]
+    pub trading_symbol: String,
+
+    /// Exchange
+    pub exchange: Exchange,
+
+    /// SPAN margin
+    #[serde(default)]
+    pub span: f64,
+
+    /// Exposure margin
+    #[serde(default)]
+    pub exposure: f64,
+
+    /// Option premium
+    #[serde(default)]
+    pub option_premium: f64,
+
+    /// Additional margin
+    #[serde(default)]
+    pub additional: f64,
+
+    /// Bracket order margin
+    #[serde(default)]
+    pub bo: f64,
+
+    /// Cash credit
+    #[serde(default)]
+    pub cash: f64,
+
+    /// VAR margin
+    #[serde(default)]
+    pub var: f64,
+
+    /// Realised and unrealised P&L
+    #[serde(default)]
+    pub pnl: OrderMarginPnl,
+
+    /// Margin leverage allowed for the trade
+    #[serde(default)]
+    pub leverage: f64,
+
+    /// Total margin required for the order
+    pub total: f64,
+}
+
+/// P&L component of an order margin calculation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrderMarginPnl {
+    /// Realised P&L
+    #[serde(default)]
+    pub realised: f64,
+
+    /// Unrealised P&L
+    #[serde(default)]
+    pub unrealised: f64,
+}
+
+impl OrderMargins {
+    /// SPAN plus exposure, the F&O margin blocked by the exchange
+    pub fn span_and_exposure(&self) -> f64 {
+        self.span + self.exposure
+    }
+}
+
+/// Margin totals of a whole basket, before or after offsetting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BasketMarginTotals {
+    /// SPAN margin
+    #[serde(default)]
+    pub span: f64,
+
+    /// Exposure margin
+    #[serde(default)]
+    pub exposure: f64,
+
+    /// Option premium
+    #[serde(default)]
+    pub option_premium: f64,
+
+    /// Additional margin
+    #[serde(default)]
+    pub additional: f64,
+
+    /// Bracket order margin
+    #[serde(default)]
+    pub bo: f64,
+
+    /// Cash credit
+    #[serde(default)]
+    pub cash: f64,
+
+    /// VAR margin
+    #[serde(default)]
+    pub var: f64,
+
+    /// Realised and unrealised P&L
+    #[serde(default)]
+    pub pnl: OrderMarginPnl,
+
+    /// Total margin
+    #[serde(default)]
+    pub total: f64,
+}
+
+/// Combined margin for a basket of orders (`POST /margins/basket`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasketMargins {
+    /// Sum of the legs' margins as if placed individually
+    pub initial: BasketMarginTotals,
+
+    /// Margin after offsetting hedged legs (and positions, if considered)
+    #[serde(rename = "final")]
+    pub final_margin: BasketMarginTotals,
+
+    /// Per-leg margins, in basket order
+    #[serde(default)]
+    pub orders: Vec<OrderMargins>,
+}
+
+impl BasketMargins {
+    /// Margin saved by placing the legs together
+    pub fn hedge_benefit(&self) -> f64 {
+        self.initial.total - self.final_margin.total
+    }
+
+    /// Total margin of each leg, in basket order
+    pub fn leg_totals(&self) -> Vec<f64> {
+        self.orders.iter().map(|order| order.total).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_validates_and_serializes() {
+        assert!(matches!(
+            OrderMarginParamBuilder::new()
+                .trading_symbol("INFY")
+                .quantity(1)
+                .build(),
+            Err(KiteError::InputException(_))
+        ));
+
+        let params = OrderMarginParamBuilder::new()
+            .exchange(Exchange::NFO)
+            .trading_symbol("NIFTY24JANFUT")
+            .transaction_type(TransactionType::SELL)
+            .product(Product::NRML)
+            .order_type(OrderType::MARKET)
+            .quantity(50)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["tradingsymbol"], "NIFTY24JANFUT");
+        assert_eq!(json["variety"], "regular");
+        assert_eq!(json["order_type"], "MARKET");
+        assert!(json.get("price").is_none());
+    }
+
+    #[test]
+    fn test_basket_margins_hedge_benefit() {
+        let json = r#"{
+            "initial": {"type": "", "tradingsymbol": "", "exchange": "",
+                "span": 150000, "exposure": 40000, "total": 190000},
+            "final": {"type": "", "tradingsymbol": "", "exchange": "",
+                "span": 30000, "exposure": 10000, "total": 40000},
+            "orders": [
+                {"type": "equity", "tradingsymbol": "NIFTY24JAN21000CE", "exchange": "NFO",
+                 "option_premium": 5000, "total": 5000},
+                {"type": "equity", "tradingsymbol": "NIFTY24JAN21200CE", "exchange": "NFO",
+                 "span": 150000, "exposure": 35000, "total": 185000}
+            ],
+            "charges": {"total": 40.5}
+        }"#;
+
+        let basket: BasketMargins = serde_json::from_str(json).unwrap();
+        assert_eq!(basket.final_margin.span, 30000.0);
+        assert_eq!(basket.hedge_benefit(), 150000.0);
+        assert_eq!(basket.leg_totals(), vec![5000.0, 185000.0]);
+    }
+}
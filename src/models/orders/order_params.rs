@@ -1,4 +1,7 @@
-use crate::models::common::{Exchange, OrderType, Product, TransactionType, Validity};
+use crate::models::common::{
+    Exchange, KiteError, OrderType, Product, TransactionType, Validity, Variety,
+};
+use crate::models::market_data::Instrument;
 use serde::{Deserialize, Serialize};
 
 /// Order placement parameters
@@ -72,6 +75,14 @@ pub struct OrderParams {
     /// Auction number
     #[serde(rename = "auction_number", skip_serializing_if = "Option::is_none")]
     pub auction_number: Option<String>,
+
+    /// Order variety chosen by the builder (`None` means regular)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variety: Option<Variety>,
+
+    /// Minutes a TTL-validity order stays open
+    #[serde(rename = "validity_ttl", skip_serializing_if = "Option::is_none")]
+    pub validity_ttl: Option<u32>,
 }
 
 /// Bracket order parameters
@@ -140,10 +151,26 @@ pub struct OrderModifyParams {
     pub parent_order_id: Option<String>,
 }
 
+impl OrderParams {
+    /// Snap `price` and `trigger_price` to the instrument's tick size
+    ///
+    /// Orders priced off-tick are rejected by the exchange; see
+    /// [`Instrument::round_to_tick`].
+    pub fn snap_to_tick(&mut self, instrument: &Instrument) {
+        self.price = self.price.map(|price| instrument.round_to_tick(price));
+        self.trigger_price = self
+            .trigger_price
+            .map(|price| instrument.round_to_tick(price));
+    }
+}
+
 /// Builder for order parameters
 #[derive(Debug, Clone)]
 pub struct OrderBuilder {
     params: OrderParams,
+    product_set: bool,
+    inferred_product: Option<Product>,
+    intraday: bool,
 }
 
 impl OrderBuilder {
@@ -169,7 +196,12 @@ impl OrderBuilder {
                 iceberg_legs: None,
                 iceberg_quantity: None,
                 auction_number: None,
+                variety: None,
+                validity_ttl: None,
             },
+            product_set: false,
+            inferred_product: None,
+            intraday: false,
         }
     }
 
@@ -206,6 +238,23 @@ impl OrderBuilder {
     /// Set product
     pub fn product(mut self, product: Product) -> Self {
         self.params.product = product;
+        self.product_set = true;
+        self
+    }
+
+    /// Infer the product from the instrument if `product` is never set
+    ///
+    /// Opt-in: uses [`Instrument::default_product`], i.e. `NRML` for F&O and
+    /// `CNC` for equity delivery, or `MIS` when [`intraday`](Self::intraday)
+    /// is also set. An explicit [`product`](Self::product) always wins.
+    pub fn infer_product_from(mut self, instrument: &Instrument) -> Self {
+        self.inferred_product = Some(instrument.default_product());
+        self
+    }
+
+    /// Mark the order as intraday so an unset product resolves to `MIS`
+    pub fn intraday(mut self) -> Self {
+        self.intraday = true;
         self
     }
 
@@ -227,6 +276,37 @@ impl OrderBuilder {
         self
     }
 
+    /// Use `TTL` validity, keeping the order open for `minutes`
+    ///
+    /// Only limit orders accept TTL validity.
+    pub fn validity_ttl(mut self, minutes: u32) -> Self {
+        self.params.validity = Some(Validity::TTL);
+        self.params.validity_ttl = Some(minutes);
+        self
+    }
+
+    /// Set order variety
+    pub fn variety(mut self, variety: Variety) -> Self {
+        self.params.variety = Some(variety);
+        self
+    }
+
+    /// Place as an after-market order (`amo` variety)
+    pub fn amo(self) -> Self {
+        self.variety(Variety::AMO)
+    }
+
+    /// Place as a cover order (`co` variety); set the stop-loss with `trigger_price`
+    pub fn co(self) -> Self {
+        self.variety(Variety::CO)
+    }
+
+    /// Bid in an exchange auction (`auction` variety)
+    pub fn auction<S: Into<String>>(mut self, auction_number: S) -> Self {
+        self.params.auction_number = Some(auction_number.into());
+        self.variety(Variety::Auction)
+    }
+
     /// Set disclosed quantity
     pub fn disclosed_quantity(mut self, disclosed_quantity: u32) -> Self {
         self.params.disclosed_quantity = Some(disclosed_quantity);
@@ -246,37 +326,91 @@ impl OrderBuilder {
     }
 
     /// Configure for iceberg order
+    ///
+    /// Sets the `iceberg` variety; `quantity` is the size of each leg.
     pub fn iceberg(mut self, legs: u32, quantity: u32) -> Self {
+        self.params.variety = Some(Variety::Iceberg);
         self.params.iceberg_legs = Some(legs);
         self.params.iceberg_quantity = Some(quantity);
         self
     }
 
     /// Build the order parameters
-    pub fn build(self) -> Result<OrderParams, String> {
-        // Validate required fields
-        if self.params.trading_symbol.is_empty() {
-            return Err("Trading symbol is required".to_string());
+    ///
+    /// Returns `KiteError::InputException` for missing fields and for
+    /// combinations Kite would reject, such as an iceberg market order.
+    pub fn build(mut self) -> Result<OrderParams, KiteError> {
+        if !self.product_set {
+            if self.intraday {
+                self.params.product = Product::MIS;
+            } else if let Some(product) = self.inferred_product {
+                self.params.product = product;
+            }
         }
 
-        if self.params.quantity == 0 {
-            return Err("Quantity must be greater than 0".to_string());
-        }
+        validate_order(&self.params).map_err(KiteError::input_exception)?;
 
-        // Validate price for limit orders
-        if self.params.order_type == OrderType::LIMIT && self.params.price.is_none() {
-            return Err("Price is required for LIMIT orders".to_string());
-        }
+        Ok(self.params)
+    }
+}
+
+/// Check an order for missing fields and unsupported combinations
+fn validate_order(params: &OrderParams) -> Result<(), String> {
+    if params.trading_symbol.is_empty() {
+        return Err("Trading symbol is required".to_string());
+    }
+
+    if params.quantity == 0 {
+        return Err("Quantity must be greater than 0".to_string());
+    }
+
+    // Validate price for limit orders
+    if params.order_type == OrderType::LIMIT && params.price.is_none() {
+        return Err("Price is required for LIMIT orders".to_string());
+    }
+
+    // Validate trigger price for SL orders
+    if matches!(params.order_type, OrderType::SL | OrderType::SLM) && params.trigger_price.is_none()
+    {
+        return Err("Trigger price is required for SL/SL-M orders".to_string());
+    }
 
-        // Validate trigger price for SL orders
-        if matches!(self.params.order_type, OrderType::SL | OrderType::SLM)
-            && self.params.trigger_price.is_none()
-        {
-            return Err("Trigger price is required for SL/SL-M orders".to_string());
+    let is_iceberg = params.variety == Some(Variety::Iceberg);
+    if is_iceberg {
+        if !matches!(params.order_type, OrderType::LIMIT | OrderType::SL) {
+            return Err("Iceberg orders must be LIMIT or SL orders".to_string());
+        }
+        let (Some(legs), Some(leg_quantity)) = (params.iceberg_legs, params.iceberg_quantity)
+        else {
+            return Err("Iceberg orders require iceberg legs and quantity".to_string());
+        };
+        if !(2..=10).contains(&legs) {
+            return Err("Iceberg legs must be between 2 and 10".to_string());
         }
+        if leg_quantity == 0 || leg_quantity.saturating_mul(legs) < params.quantity {
+            return Err("Iceberg legs must together cover the order quantity".to_string());
+        }
+    } else if params.iceberg_legs.is_some() || params.iceberg_quantity.is_some() {
+        return Err("Iceberg legs require the iceberg variety".to_string());
+    }
 
-        Ok(self.params)
+    if params.variety == Some(Variety::Auction) && params.auction_number.is_none() {
+        return Err("Auction orders require an auction number".to_string());
+    }
+
+    match (params.validity, params.validity_ttl) {
+        (Some(Validity::TTL), None | Some(0)) => {
+            return Err("TTL validity requires validity_ttl minutes".to_string());
+        }
+        (Some(Validity::TTL), _) if params.order_type != OrderType::LIMIT => {
+            return Err("TTL validity is only allowed for LIMIT orders".to_string());
+        }
+        (Some(Validity::TTL), _) => {}
+        (_, Some(_)) => return Err("validity_ttl requires TTL validity".to_string()),
+        _ => {}
     }
+
+    Ok(())
 }
 
 impl Default for OrderBuilder {
@@ -316,6 +450,8 @@ impl BracketOrderBuilder {
                 iceberg_legs: None,
                 iceberg_quantity: None,
                 auction_number: None,
+                variety: None,
+                validity_ttl: None,
             },
             squareoff: None,
             stoploss: None,
@@ -412,3 +548,156 @@ impl Default for BracketOrderBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instrument(
+        symbol: &str,
+        instrument_type: &str,
+        segment: &str,
+        exchange: &str,
+    ) -> Instrument {
+        serde_json::from_value(serde_json::json!({
+            "instrument_token": "5720322",
+            "exchange_token": "22345",
+            "tradingsymbol": symbol,
+            "name": "NIFTY",
+            "last_price": "0",
+            "expiry": "2024-12-26",
+            "strike": "0",
+            "tick_size": "0.05",
+            "lot_size": "25",
+            "instrument_type": instrument_type,
+            "segment": segment,
+            "exchange": exchange
+        }))
+        .unwrap()
+    }
+
+    fn market_order(symbol: &str, exchange: Exchange) -> OrderBuilder {
+        OrderBuilder::new()
+            .trading_symbol(symbol)
+            .exchange(exchange)
+            .transaction_type(TransactionType::BUY)
+            .order_type(OrderType::MARKET)
+            .quantity(25)
+    }
+
+    #[test]
+    fn test_iceberg_and_ttl_validation() {
+        let params = market_order("RELIANCE", Exchange::NSE)
+            .order_type(OrderType::LIMIT)
+            .price(2500.0)
+            .quantity(100)
+            .iceberg(4, 25)
+            .build()
+            .unwrap();
+        assert_eq!(params.variety, Some(Variety::Iceberg));
+
+        let err = market_order("RELIANCE", Exchange::NSE)
+            .quantity(100)
+            .iceberg(4, 25)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, KiteError::InputException(ref msg) if msg.contains("LIMIT or SL")));
+
+        // Iceberg fields are meaningless once the variety is switched to AMO
+        assert!(market_order("RELIANCE", Exchange::NSE)
+            .order_type(OrderType::LIMIT)
+            .price(2500.0)
+            .quantity(100)
+            .iceberg(4, 25)
+            .amo()
+            .build()
+            .is_err());
+
+        let ttl = market_order("RELIANCE", Exchange::NSE)
+            .order_type(OrderType::LIMIT)
+            .price(2500.0)
+            .validity_ttl(5)
+            .build()
+            .unwrap();
+        assert_eq!(ttl.validity, Some(Validity::TTL));
+        assert_eq!(ttl.validity_ttl, Some(5));
+
+        assert!(market_order("RELIANCE", Exchange::NSE)
+            .validity_ttl(5)
+            .build()
+            .is_err());
+        assert!(market_order("RELIANCE", Exchange::NSE)
+            .validity(Validity::TTL)
+            .build()
+            .is_err());
+
+        assert!(market_order("RELIANCE", Exchange::NSE)
+            .variety(Variety::Auction)
+            .build()
+            .is_err());
+        let auction = market_order("RELIANCE", Exchange::NSE)
+            .auction("22")
+            .build()
+            .unwrap();
+        assert_eq!(auction.variety, Some(Variety::Auction));
+        assert_eq!(auction.auction_number.as_deref(), Some("22"));
+    }
+
+    #[test]
+    fn test_snap_to_tick_rounds_price_and_trigger() {
+        let future = instrument("NIFTY24DECFUT", "FUT", "NFO-FUT", "NFO");
+        let mut params = market_order("NIFTY24DECFUT", Exchange::NFO)
+            .order_type(OrderType::SL)
+            .price(100.03)
+            .trigger_price(99.98)
+            .build()
+            .unwrap();
+
+        params.snap_to_tick(&future);
+        assert_eq!(params.price, Some(100.05));
+        assert_eq!(params.trigger_price, Some(100.0));
+    }
+
+    #[test]
+    fn test_infers_nrml_for_nfo_order() {
+        let future = instrument("NIFTY24DECFUT", "FUT", "NFO-FUT", "NFO");
+        let params = market_order("NIFTY24DECFUT", Exchange::NFO)
+            .infer_product_from(&future)
+            .build()
+            .unwrap();
+
+        assert_eq!(params.product, Product::NRML);
+    }
+
+    #[test]
+    fn test_product_inference_is_opt_in_and_overridable() {
+        let future = instrument("NIFTY24DECFUT", "FUT", "NFO-FUT", "NFO");
+
+        // Without opting in, the builder keeps its CNC default
+        let params = market_order("NIFTY24DECFUT", Exchange::NFO)
+            .build()
+            .unwrap();
+        assert_eq!(params.product, Product::CNC);
+
+        let params = market_order("NIFTY24DECFUT", Exchange::NFO)
+            .infer_product_from(&future)
+            .intraday()
+            .build()
+            .unwrap();
+        assert_eq!(params.product, Product::MIS);
+
+        let params = market_order("NIFTY24DECFUT", Exchange::NFO)
+            .product(Product::MIS)
+            .infer_product_from(&future)
+            .build()
+            .unwrap();
+        assert_eq!(params.product, Product::MIS);
+
+        let equity = instrument("INFY", "EQ", "NSE", "NSE");
+        let params = market_order("INFY", Exchange::NSE)
+            .infer_product_from(&equity)
+            .build()
+            .unwrap();
+        assert_eq!(params.product, Product::CNC);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::models::common::{Exchange, OrderType, Product, TransactionType, Validity};
+use crate::models::orders::order_data::deserialize_order_meta;
+use crate::models::orders::{OrderMeta, OrderStatus};
+use serde::{Deserialize, Serialize};
+
+/// Order update posted by Kite to the app's postback URL
+///
+/// Timestamps are kept as the raw strings Kite sends, because the postback
+/// checksum is computed over `order_timestamp` exactly as received. Verify
+/// the payload with [`verify_postback`](crate::connect::verify_postback)
+/// before acting on it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostbackPayload {
+    /// User ID the order belongs to
+    #[serde(default)]
+    pub user_id: String,
+
+    /// Kite Connect app ID
+    #[serde(default)]
+    pub app_id: u64,
+
+    /// Who placed the order
+    #[serde(default)]
+    pub placed_by: String,
+
+    /// Order ID
+    pub order_id: String,
+
+    /// Exchange order ID
+    #[serde(default)]
+    pub exchange_order_id: Option<String>,
+
+    /// Parent order ID for bracket/cover orders
+    #[serde(default)]
+    pub parent_order_id: Option<String>,
+
+    /// Order status
+    pub status: OrderStatus,
+
+    /// Status message from exchange
+    #[serde(default)]
+    pub status_message: Option<String>,
+
+    /// Status message from OMS
+    #[serde(default)]
+    pub status_message_raw: Option<String>,
+
+    /// Order timestamp, as sent (`YYYY-MM-DD HH:MM:SS`, IST)
+    pub order_timestamp: String,
+
+    /// Exchange update timestamp, as sent
+    #[serde(default)]
+    pub exchange_update_timestamp: Option<String>,
+
+    /// Exchange timestamp, as sent
+    #[serde(default)]
+    pub exchange_timestamp: Option<String>,
+
+    /// Variety (regular, co, amo, iceberg, auction)
+    #[serde(default)]
+    pub variety: Option<String>,
+
+    /// Exchange
+    pub exchange: Exchange,
+
+    /// Trading symbol
+    #[serde(rename = "tradingsymbol")]
+    pub trading_symbol: String,
+
+    /// Instrument token
+    pub instrument_token: u32,
+
+    /// Order type
+    pub order_type: OrderType,
+
+    /// Transaction type (BUY/SELL)
+    pub transaction_type: TransactionType,
+
+    /// Validity
+    pub validity: Validity,
+
+    /// Product type
+    pub product: Product,
+
+    /// Quantity
+    pub quantity: u32,
+
+    /// Disclosed quantity
+    #[serde(default)]
+    pub disclosed_quantity: u32,
+
+    /// Price
+    #[serde(default)]
+    pub price: f64,
+
+    /// Trigger price for SL orders
+    #[serde(default)]
+    pub trigger_price: f64,
+
+    /// Average price at which the order was executed
+    #[serde(default)]
+    pub average_price: f64,
+
+    /// Filled quantity
+    #[serde(default)]
+    pub filled_quantity: u32,
+
+    /// Pending quantity
+    #[serde(default)]
+    pub pending_quantity: u32,
+
+    /// Cancelled quantity
+    #[serde(default)]
+    pub cancelled_quantity: u32,
+
+    /// Unfilled quantity
+    #[serde(default)]
+    pub unfilled_quantity: u32,
+
+    /// Market protection percentage
+    #[serde(default)]
+    pub market_protection: f64,
+
+    /// Meta information
+    #[serde(default, deserialize_with = "deserialize_order_meta")]
+    pub meta: Option<OrderMeta>,
+
+    /// Tag for the order
+    #[serde(default)]
+    pub tag: Option<String>,
+
+    /// GUID for idempotency
+    #[serde(default)]
+    pub guid: Option<String>,
+
+    /// SHA-256 checksum sent by Kite
+    #[serde(default)]
+    pub checksum: String,
+}
+
+impl PostbackPayload {
+    /// Input Kite hashes for the postback checksum: `order_id + order_timestamp + api_secret`
+    pub fn checksum_input(&self, api_secret: &str) -> String {
+        format!("{}{}{}", self.order_id, self.order_timestamp, api_secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_postback() {
+        let payload: PostbackPayload = serde_json::from_value(serde_json::json!({
+            "user_id": "AB1234",
+            "unfilled_quantity": 0,
+            "app_id": 1234,
+            "checksum": "2011845d9348bd6795151bf4258102a03431e3bb12a79c0df73fcb4b7fde4b5d",
+            "placed_by": "AB1234",
+            "order_id": "220303000308932",
+            "exchange_order_id": "1000000001482421",
+            "parent_order_id": null,
+            "status": "COMPLETE",
+            "status_message": null,
+            "status_message_raw": null,
+            "order_timestamp": "2022-03-03 09:24:25",
+            "exchange_update_timestamp": "2022-03-03 09:24:25",
+            "exchange_timestamp": "2022-03-03 09:24:25",
+            "variety": "regular",
+            "exchange": "NSE",
+            "tradingsymbol": "SBIN",
+            "instrument_token": 779521,
+            "order_type": "MARKET",
+            "transaction_type": "BUY",
+            "validity": "DAY",
+            "product": "CNC",
+            "quantity": 1,
+            "disclosed_quantity": 0,
+            "price": 0,
+            "trigger_price": 0,
+            "average_price": 470,
+            "filled_quantity": 1,
+            "pending_quantity": 0,
+            "cancelled_quantity": 0,
+            "market_protection": 0,
+            "meta": {},
+            "tag": null,
+            "guid": "XXXXXX"
+        }))
+        .unwrap();
+
+        assert_eq!(payload.status, OrderStatus::Complete);
+        assert_eq!(payload.trading_symbol, "SBIN");
+        assert_eq!(payload.average_price, 470.0);
+        assert_eq!(
+            payload.checksum_input("secret"),
+            "2203030003089322022-03-03 09:24:25secret"
+        );
+    }
+}
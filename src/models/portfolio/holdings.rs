@@ -1,10 +1,11 @@
-use crate::models::common::{Exchange, Product};
-use serde::{Deserialize, Serialize};
+use crate::models::common::{round_price, Exchange, Product, PRICE_DECIMALS};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Holdings data structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Holding {
-    /// Account ID
+    /// Account ID; absent from some responses
+    #[serde(default)]
     pub account_id: String,
 
     /// Trading symbol
@@ -25,18 +26,19 @@ pub struct Holding {
     pub instrument_token: u32,
 
     /// Quantity in the holding
+    #[serde(default)]
     pub quantity: i32,
 
     /// T1 quantity (can be sold after T+1 day)
-    #[serde(rename = "t1_quantity")]
+    #[serde(rename = "t1_quantity", default)]
     pub t1_quantity: i32,
 
     /// Realised quantity (can be sold immediately)
-    #[serde(rename = "realised_quantity")]
+    #[serde(rename = "realised_quantity", default)]
     pub realised_quantity: i32,
 
     /// Authorized quantity (pledged/unpledged)
-    #[serde(rename = "authorised_quantity")]
+    #[serde(rename = "authorised_quantity", default)]
     pub authorised_quantity: i32,
 
     /// Authorised date
@@ -44,11 +46,11 @@ pub struct Holding {
     pub authorised_date: Option<String>,
 
     /// Opening quantity at the start of the day
-    #[serde(rename = "opening_quantity")]
+    #[serde(rename = "opening_quantity", default)]
     pub opening_quantity: i32,
 
     /// Collateral quantity
-    #[serde(rename = "collateral_quantity")]
+    #[serde(rename = "collateral_quantity", default)]
     pub collateral_quantity: i32,
 
     /// Collateral type
@@ -56,44 +58,147 @@ pub struct Holding {
     pub collateral_type: Option<String>,
 
     /// Collateral update quantity
-    #[serde(rename = "collateral_update_quantity")]
+    #[serde(rename = "collateral_update_quantity", default)]
     pub collateral_update_quantity: i32,
 
     /// Discrepancy flag
+    #[serde(default)]
     pub discrepancy: bool,
 
     /// Average price at which the stock was bought
-    #[serde(rename = "average_price")]
+    #[serde(rename = "average_price", default)]
     pub average_price: f64,
 
     /// Last price from exchange
-    #[serde(rename = "last_price")]
+    #[serde(rename = "last_price", default)]
     pub last_price: f64,
 
     /// Close price
-    #[serde(rename = "close_price")]
+    #[serde(rename = "close_price", default)]
     pub close_price: f64,
 
     /// Price change
-    #[serde(rename = "price_change")]
+    #[serde(rename = "price_change", default)]
     pub price_change: f64,
 
     /// P&L (profit and loss)
+    #[serde(default)]
     pub pnl: f64,
 
-    /// Day change
-    #[serde(rename = "day_change")]
+    /// Day change (negative on a down day, 0 when Kite omits it)
+    #[serde(
+        rename = "day_change",
+        default,
+        deserialize_with = "deserialize_f64_or_zero"
+    )]
     pub day_change: f64,
 
-    /// Day change percentage
-    #[serde(rename = "day_change_percentage")]
+    /// Day change percentage (0 when Kite omits it)
+    #[serde(
+        rename = "day_change_percentage",
+        default,
+        deserialize_with = "deserialize_f64_or_zero"
+    )]
     pub day_change_percentage: f64,
 
     /// Used quantity (used for pledging)
-    #[serde(rename = "used_quantity")]
+    #[serde(rename = "used_quantity", default)]
     pub used_quantity: i32,
 }
 
+/// Treat a missing or null number as 0 (corporate actions can blank out day change)
+fn deserialize_f64_or_zero<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<f64>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Holding eligible for the settlement auction market
+///
+/// Returned by `/portfolio/holdings/auctions`. Sell orders against these
+/// holdings are placed with `Variety::Auction` and the `auction_number`
+/// reported here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuctionInstrument {
+    /// Trading symbol
+    #[serde(rename = "tradingsymbol")]
+    pub trading_symbol: String,
+
+    /// Exchange
+    pub exchange: Exchange,
+
+    /// Instrument token
+    pub instrument_token: u32,
+
+    /// ISIN (International Securities Identification Number)
+    #[serde(default)]
+    pub isin: String,
+
+    /// Product type
+    pub product: Product,
+
+    /// Auction number to quote when placing the auction order
+    pub auction_number: String,
+
+    /// Quantity held
+    pub quantity: i32,
+
+    /// T1 quantity (can be sold after T+1 day)
+    #[serde(default)]
+    pub t1_quantity: i32,
+
+    /// Realised quantity (can be sold immediately)
+    #[serde(default)]
+    pub realised_quantity: i32,
+
+    /// Authorised quantity
+    #[serde(default)]
+    pub authorised_quantity: i32,
+
+    /// Authorised date
+    #[serde(default)]
+    pub authorised_date: Option<String>,
+
+    /// Opening quantity at the start of the day
+    #[serde(default)]
+    pub opening_quantity: i32,
+
+    /// Collateral quantity
+    #[serde(default)]
+    pub collateral_quantity: i32,
+
+    /// Collateral type
+    #[serde(default)]
+    pub collateral_type: Option<String>,
+
+    /// Discrepancy flag
+    #[serde(default)]
+    pub discrepancy: bool,
+
+    /// Average price at which the stock was bought
+    pub average_price: f64,
+
+    /// Last price from exchange
+    pub last_price: f64,
+
+    /// Close price
+    #[serde(default)]
+    pub close_price: f64,
+
+    /// P&L (profit and loss)
+    #[serde(default)]
+    pub pnl: f64,
+
+    /// Day change (0 when Kite omits it)
+    #[serde(default, deserialize_with = "deserialize_f64_or_zero")]
+    pub day_change: f64,
+
+    /// Day change percentage (0 when Kite omits it)
+    #[serde(default, deserialize_with = "deserialize_f64_or_zero")]
+    pub day_change_percentage: f64,
+}
+
 /// Holdings summary
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HoldingsSummary {
@@ -171,6 +276,16 @@ impl Holding {
         self.pnl < 0.0
     }
 
+    /// Check if the holding gained value since the previous close
+    pub fn day_is_up(&self) -> bool {
+        self.day_change > 0.0
+    }
+
+    /// Check if the holding lost value since the previous close
+    pub fn day_is_down(&self) -> bool {
+        self.day_change < 0.0
+    }
+
     /// Get available quantity for trading
     pub fn available_quantity(&self) -> i32 {
         self.realised_quantity + self.t1_quantity
@@ -221,8 +336,44 @@ impl Holding {
     }
 }
 
+/// Prices as [`Decimal`](crate::models::common::Decimal), for exact aggregation
+#[cfg(feature = "decimal")]
+impl Holding {
+    /// Average buy price as a `Decimal`
+    pub fn average_price_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.average_price)
+    }
+
+    /// Last traded price as a `Decimal`
+    pub fn last_price_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.last_price)
+    }
+
+    /// Previous close price as a `Decimal`
+    pub fn close_price_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.close_price)
+    }
+
+    /// P&L as a `Decimal`
+    pub fn pnl_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.pnl)
+    }
+
+    /// Day change as a `Decimal`
+    pub fn day_change_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.day_change)
+    }
+
+    /// Market value at the last price as a `Decimal`
+    pub fn market_value_decimal(&self) -> crate::models::common::Decimal {
+        self.last_price_decimal() * crate::models::common::Decimal::from(self.quantity)
+    }
+}
+
 impl HoldingsSummary {
     /// Calculate from a list of holdings
+    ///
+    /// Totals are rounded to [`PRICE_DECIMALS`] places.
     pub fn from_holdings(holdings: &[Holding]) -> Self {
         let total_value = holdings.iter().map(|h| h.market_value()).sum();
         let total_investment = holdings.iter().map(|h| h.investment_value()).sum();
@@ -236,11 +387,11 @@ impl HoldingsSummary {
         };
 
         Self {
-            total_value,
-            total_investment,
-            total_pnl,
-            total_day_change,
-            total_day_change_percentage,
+            total_value: round_price(total_value, PRICE_DECIMALS),
+            total_investment: round_price(total_investment, PRICE_DECIMALS),
+            total_pnl: round_price(total_pnl, PRICE_DECIMALS),
+            total_day_change: round_price(total_day_change, PRICE_DECIMALS),
+            total_day_change_percentage: round_price(total_day_change_percentage, PRICE_DECIMALS),
             holdings_count: holdings.len(),
         }
     }
@@ -264,3 +415,81 @@ impl HoldingsSummary {
         self.total_pnl < 0.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn holding_json(day_change: Option<serde_json::Value>) -> serde_json::Value {
+        let mut value = json!({
+            "account_id": "AB1234",
+            "tradingsymbol": "INFY",
+            "exchange": "NSE",
+            "isin": "INE009A01021",
+            "product": "CNC",
+            "instrument_token": 408065,
+            "quantity": 10,
+            "t1_quantity": 0,
+            "realised_quantity": 10,
+            "authorised_quantity": 0,
+            "opening_quantity": 10,
+            "collateral_quantity": 0,
+            "collateral_update_quantity": 0,
+            "discrepancy": false,
+            "average_price": 1500.0,
+            "last_price": 1450.0,
+            "close_price": 1460.0,
+            "price_change": -10.0,
+            "pnl": -500.0,
+            "used_quantity": 0
+        });
+        if let Some(day_change) = day_change {
+            value["day_change"] = day_change.clone();
+            value["day_change_percentage"] = day_change;
+        }
+        value
+    }
+
+    #[test]
+    fn test_negative_day_change() {
+        let holding: Holding = serde_json::from_value(holding_json(Some(json!(-10.0)))).unwrap();
+        assert_eq!(holding.day_change, -10.0);
+        assert_eq!(holding.day_change_percentage, -10.0);
+        assert!(holding.day_is_down());
+        assert!(!holding.day_is_up());
+        assert!(!holding.is_profitable());
+    }
+
+    #[test]
+    fn test_zero_day_change() {
+        let holding: Holding = serde_json::from_value(holding_json(Some(json!(0.0)))).unwrap();
+        assert!(!holding.day_is_up());
+        assert!(!holding.day_is_down());
+    }
+
+    #[test]
+    fn test_absent_or_null_day_change_defaults_to_zero() {
+        for value in [
+            holding_json(None),
+            holding_json(Some(serde_json::Value::Null)),
+        ] {
+            let holding: Holding = serde_json::from_value(value).unwrap();
+            assert_eq!(holding.day_change, 0.0);
+            assert_eq!(holding.day_change_percentage, 0.0);
+            assert!(!holding.day_is_up());
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_getters() {
+        let holding: Holding = serde_json::from_value(holding_json(None)).unwrap();
+        let total = holding.pnl_decimal() + holding.pnl_decimal();
+        assert_eq!(total, crate::models::common::to_decimal(holding.pnl * 2.0));
+        assert_eq!(
+            holding.market_value_decimal(),
+            holding.last_price_decimal() * crate::models::common::Decimal::from(10)
+        );
+    }
+}
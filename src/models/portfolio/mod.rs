@@ -10,9 +10,11 @@
 
 pub mod conversions;
 pub mod holdings;
+pub mod pnl;
 pub mod positions;
 
 // Re-export all public types
 pub use conversions::*;
 pub use holdings::*;
+pub use pnl::*;
 pub use positions::*;
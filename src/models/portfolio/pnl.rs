@@ -0,0 +1,319 @@
+/*!
+ * Realized P&L matching for executed trades
+ *
+ * Pairs opening and closing fills per instrument in FIFO order to produce
+ * realized lots for tax and reporting. Everything here is pure: feed it the
+ * trades you already have (e.g. from `trades_typed()`) and inspect the lots.
+ */
+
+use crate::models::common::{round_price, Exchange, TransactionType, PRICE_DECIMALS};
+use crate::models::orders::Trade;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// A quantity opened by one trade and closed by another
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealizedLot {
+    /// Exchange
+    pub exchange: Exchange,
+
+    /// Trading symbol
+    #[serde(rename = "tradingsymbol")]
+    pub trading_symbol: String,
+
+    /// Side of the opening trade (`SELL` for short lots)
+    pub side: TransactionType,
+
+    /// Matched quantity
+    pub quantity: u32,
+
+    /// Trade ID that opened the lot, empty for an [`OpeningLot`]
+    pub open_trade_id: String,
+
+    /// Trade ID that closed the lot
+    pub close_trade_id: String,
+
+    /// Opening fill price
+    pub open_price: f64,
+
+    /// Closing fill price
+    pub close_price: f64,
+
+    /// Opening fill time
+    pub opened_at: DateTime<Utc>,
+
+    /// Closing fill time
+    pub closed_at: DateTime<Utc>,
+
+    /// Realized profit (negative for a loss)
+    ///
+    /// Left unrounded so sums over many lots stay exact to the paise; round
+    /// totals with [`total_pnl`].
+    pub pnl: f64,
+}
+
+impl RealizedLot {
+    /// Time between the opening and closing fills
+    pub fn holding_duration(&self) -> Duration {
+        self.closed_at - self.opened_at
+    }
+
+    /// Check if this lot was a short sale
+    pub fn is_short(&self) -> bool {
+        self.side == TransactionType::SELL
+    }
+}
+
+/// Quantity already open before the first trade, such as an overnight position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpeningLot {
+    /// Exchange
+    pub exchange: Exchange,
+
+    /// Trading symbol
+    #[serde(rename = "tradingsymbol")]
+    pub trading_symbol: String,
+
+    /// `BUY` for a long position, `SELL` for a short one
+    pub side: TransactionType,
+
+    /// Open quantity
+    pub quantity: u32,
+
+    /// Price the quantity was opened at
+    pub price: f64,
+
+    /// Time the quantity is treated as opened
+    pub opened_at: DateTime<Utc>,
+}
+
+/// Unmatched remainder of an opening trade or lot
+struct OpenLot<'a> {
+    side: TransactionType,
+    trade_id: &'a str,
+    price: f64,
+    opened_at: DateTime<Utc>,
+    remaining: u32,
+}
+
+/// Match buys and sells per symbol in FIFO order
+///
+/// Trades are processed in `fill_timestamp` order (input order breaks ties).
+/// Each fill first closes the oldest open lots on the opposite side; any
+/// leftover quantity opens a new lot, so short sales are handled the same
+/// way as long positions. Lots still open at the end are not reported.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kiteconnect_async_wasm::connect::KiteConnect;
+/// use kiteconnect_async_wasm::models::portfolio::pnl;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = KiteConnect::new("api_key", "access_token");
+/// let trades = client.trades_typed().await?;
+///
+/// for lot in pnl::match_fifo(&trades) {
+///     println!("{} x{}: ₹{:.2}", lot.trading_symbol, lot.quantity, lot.pnl);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn match_fifo(trades: &[Trade]) -> Vec<RealizedLot> {
+    match_fifo_from(&[], trades)
+}
+
+/// Match trades in FIFO order after the `opening` quantities
+///
+/// Like [`match_fifo`], but each symbol's queue starts with its opening lots,
+/// so a trade closing a position carried in from an earlier period realizes
+/// P&L against the opening price.
+pub fn match_fifo_from(opening: &[OpeningLot], trades: &[Trade]) -> Vec<RealizedLot> {
+    let mut ordered: Vec<&Trade> = trades.iter().collect();
+    ordered.sort_by_key(|trade| trade.fill_timestamp);
+
+    let mut open: HashMap<(Exchange, &str), VecDeque<OpenLot>> = HashMap::new();
+    for lot in opening.iter().filter(|lot| lot.quantity > 0) {
+        open.entry((lot.exchange, lot.trading_symbol.as_str()))
+            .or_default()
+            .push_back(OpenLot {
+                side: lot.side,
+                trade_id: "",
+                price: lot.price,
+                opened_at: lot.opened_at,
+                remaining: lot.quantity,
+            });
+    }
+    let mut realized = Vec::new();
+
+    for trade in ordered {
+        let queue = open
+            .entry((trade.exchange, trade.trading_symbol.as_str()))
+            .or_default();
+        let mut remaining = trade.quantity;
+
+        while remaining > 0 {
+            let Some(lot) = queue.front_mut() else {
+                break;
+            };
+            if lot.side == trade.transaction_type {
+                break;
+            }
+
+            let quantity = remaining.min(lot.remaining);
+            let per_unit = match lot.side {
+                TransactionType::BUY => trade.average_price - lot.price,
+                TransactionType::SELL => lot.price - trade.average_price,
+            };
+
+            realized.push(RealizedLot {
+                exchange: trade.exchange,
+                trading_symbol: trade.trading_symbol.clone(),
+                side: lot.side,
+                quantity,
+                open_trade_id: lot.trade_id.to_string(),
+                close_trade_id: trade.trade_id.clone(),
+                open_price: lot.price,
+                close_price: trade.average_price,
+                opened_at: lot.opened_at,
+                closed_at: trade.fill_timestamp,
+                pnl: per_unit * quantity as f64,
+            });
+
+            lot.remaining -= quantity;
+            remaining -= quantity;
+            if lot.remaining == 0 {
+                queue.pop_front();
+            }
+        }
+
+        if remaining > 0 {
+            queue.push_back(OpenLot {
+                side: trade.transaction_type,
+                trade_id: &trade.trade_id,
+                price: trade.average_price,
+                opened_at: trade.fill_timestamp,
+                remaining,
+            });
+        }
+    }
+
+    realized
+}
+
+/// Sum of the realized P&L of `lots`, rounded to paise
+pub fn total_pnl(lots: &[RealizedLot]) -> f64 {
+    round_price(lots.iter().map(|lot| lot.pnl).sum(), PRICE_DECIMALS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn trade(id: &str, symbol: &str, side: &str, quantity: u32, price: f64, time: &str) -> Trade {
+        serde_json::from_value(json!({
+            "trade_id": id,
+            "order_id": format!("order-{}", id),
+            "exchange_order_id": format!("exch-{}", id),
+            "tradingsymbol": symbol,
+            "exchange": "NSE",
+            "instrument_token": 408065,
+            "product": "CNC",
+            "average_price": price,
+            "quantity": quantity,
+            "fill_timestamp": time,
+            "exchange_timestamp": time,
+            "transaction_type": side
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_interleaved_buys_and_sells() {
+        let trades = vec![
+            trade("1", "INFY", "BUY", 10, 100.0, "2024-01-01T04:00:00Z"),
+            trade("2", "TCS", "BUY", 5, 3000.0, "2024-01-01T04:30:00Z"),
+            trade("3", "INFY", "BUY", 10, 110.0, "2024-01-02T04:00:00Z"),
+            trade("4", "INFY", "SELL", 15, 120.0, "2024-01-03T04:00:00Z"),
+            trade("5", "TCS", "SELL", 5, 2900.0, "2024-01-03T05:00:00Z"),
+            trade("6", "INFY", "SELL", 5, 105.0, "2024-01-04T04:00:00Z"),
+        ];
+
+        let lots = match_fifo(&trades);
+        assert_eq!(lots.len(), 4);
+
+        assert_eq!(
+            (lots[0].open_trade_id.as_str(), lots[0].quantity),
+            ("1", 10)
+        );
+        assert_eq!(lots[0].pnl, 200.0);
+        assert_eq!(lots[0].holding_duration(), Duration::days(2));
+
+        assert_eq!((lots[1].open_trade_id.as_str(), lots[1].quantity), ("3", 5));
+        assert_eq!(lots[1].pnl, 50.0);
+
+        assert_eq!(lots[2].trading_symbol, "TCS");
+        assert_eq!(lots[2].pnl, -500.0);
+
+        assert_eq!((lots[3].open_trade_id.as_str(), lots[3].quantity), ("3", 5));
+        assert_eq!(lots[3].close_trade_id, "6");
+        assert_eq!(lots[3].pnl, -25.0);
+    }
+
+    #[test]
+    fn test_only_totals_are_rounded_to_paise() {
+        let mut trades = Vec::new();
+        for i in 0..3 {
+            let time = format!("2024-01-01T0{}:00:00Z", 4 + i);
+            trades.push(trade(&format!("b{}", i), "IDEA", "BUY", 1, 10.004, &time));
+            trades.push(trade(&format!("s{}", i), "IDEA", "SELL", 1, 10.0, &time));
+        }
+
+        let lots = match_fifo(&trades);
+        assert_eq!(lots.len(), 3);
+        // Rounding each lot would give -0.00 three times
+        assert!(lots.iter().all(|lot| lot.pnl < 0.0 && lot.pnl > -0.005));
+        assert_eq!(total_pnl(&lots), -0.01);
+    }
+
+    #[test]
+    fn test_opening_lots_are_closed_first() {
+        let opening = vec![OpeningLot {
+            exchange: Exchange::NSE,
+            trading_symbol: "INFY".to_string(),
+            side: TransactionType::BUY,
+            quantity: 10,
+            price: 90.0,
+            opened_at: "2024-01-01T00:00:00Z".parse().unwrap(),
+        }];
+        let trades = vec![
+            trade("1", "INFY", "BUY", 5, 100.0, "2024-01-02T04:00:00Z"),
+            trade("2", "INFY", "SELL", 12, 110.0, "2024-01-02T05:00:00Z"),
+        ];
+
+        let lots = match_fifo_from(&opening, &trades);
+        assert_eq!(lots.len(), 2);
+        assert_eq!(lots[0].open_trade_id, "");
+        assert_eq!((lots[0].quantity, lots[0].pnl), (10, 200.0));
+        assert_eq!(lots[1].open_trade_id, "1");
+        assert_eq!((lots[1].quantity, lots[1].pnl), (2, 20.0));
+    }
+
+    #[test]
+    fn test_short_sale_and_unsorted_input() {
+        let trades = vec![
+            trade("2", "INFY", "BUY", 10, 95.0, "2024-01-01T06:00:00Z"),
+            trade("1", "INFY", "SELL", 10, 100.0, "2024-01-01T04:00:00Z"),
+            trade("3", "INFY", "BUY", 3, 90.0, "2024-01-01T07:00:00Z"),
+        ];
+
+        let lots = match_fifo(&trades);
+        assert_eq!(lots.len(), 1);
+        assert!(lots[0].is_short());
+        assert_eq!(lots[0].open_trade_id, "1");
+        assert_eq!(lots[0].pnl, 50.0);
+    }
+}
@@ -1,10 +1,12 @@
-use crate::models::common::{Exchange, Product, TransactionType};
+use crate::models::common::{round_price, Exchange, Product, TransactionType, PRICE_DECIMALS};
+use crate::models::portfolio::ConversionRequest;
 use serde::{Deserialize, Serialize};
 
 /// Position data structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
-    /// Account ID
+    /// Account ID; absent from some responses
+    #[serde(default)]
     pub account_id: String,
 
     /// Trading symbol
@@ -22,108 +24,129 @@ pub struct Position {
     pub product: Product,
 
     /// Net quantity (positive for long, negative for short)
+    #[serde(default)]
     pub quantity: i32,
 
     /// Overnight quantity
-    #[serde(rename = "overnight_quantity")]
+    #[serde(rename = "overnight_quantity", default)]
     pub overnight_quantity: i32,
 
     /// Multiplier for the instrument
+    #[serde(default)]
     pub multiplier: f64,
 
     /// Average price at which the position was taken
-    #[serde(rename = "average_price")]
+    #[serde(rename = "average_price", default)]
     pub average_price: f64,
 
     /// Close price
-    #[serde(rename = "close_price")]
+    #[serde(rename = "close_price", default)]
     pub close_price: f64,
 
     /// Last price from exchange
-    #[serde(rename = "last_price")]
+    #[serde(rename = "last_price", default)]
     pub last_price: f64,
 
     /// Current value of the position
+    #[serde(default)]
     pub value: f64,
 
     /// P&L (profit and loss)
+    #[serde(default)]
     pub pnl: f64,
 
     /// M2M (Mark to Market) P&L
+    #[serde(default)]
     pub m2m: f64,
 
     /// Unrealised P&L
+    #[serde(default)]
     pub unrealised: f64,
 
     /// Realised P&L
+    #[serde(default)]
     pub realised: f64,
 
     /// Buy quantity
-    #[serde(rename = "buy_quantity")]
+    #[serde(rename = "buy_quantity", default)]
     pub buy_quantity: u32,
 
     /// Buy price
-    #[serde(rename = "buy_price")]
+    #[serde(rename = "buy_price", default)]
     pub buy_price: f64,
 
     /// Buy value
-    #[serde(rename = "buy_value")]
+    #[serde(rename = "buy_value", default)]
     pub buy_value: f64,
 
     /// Buy M2M
-    #[serde(rename = "buy_m2m")]
+    #[serde(rename = "buy_m2m", default)]
     pub buy_m2m: f64,
 
     /// Sell quantity
-    #[serde(rename = "sell_quantity")]
+    #[serde(rename = "sell_quantity", default)]
     pub sell_quantity: u32,
 
     /// Sell price
-    #[serde(rename = "sell_price")]
+    #[serde(rename = "sell_price", default)]
     pub sell_price: f64,
 
     /// Sell value
-    #[serde(rename = "sell_value")]
+    #[serde(rename = "sell_value", default)]
     pub sell_value: f64,
 
     /// Sell M2M
-    #[serde(rename = "sell_m2m")]
+    #[serde(rename = "sell_m2m", default)]
     pub sell_m2m: f64,
 
     /// Day buy quantity
-    #[serde(rename = "day_buy_quantity")]
+    #[serde(rename = "day_buy_quantity", default)]
     pub day_buy_quantity: u32,
 
     /// Day buy price
-    #[serde(rename = "day_buy_price")]
+    #[serde(rename = "day_buy_price", default)]
     pub day_buy_price: f64,
 
     /// Day buy value
-    #[serde(rename = "day_buy_value")]
+    #[serde(rename = "day_buy_value", default)]
     pub day_buy_value: f64,
 
     /// Day sell quantity
-    #[serde(rename = "day_sell_quantity")]
+    #[serde(rename = "day_sell_quantity", default)]
     pub day_sell_quantity: u32,
 
     /// Day sell price
-    #[serde(rename = "day_sell_price")]
+    #[serde(rename = "day_sell_price", default)]
     pub day_sell_price: f64,
 
     /// Day sell value
-    #[serde(rename = "day_sell_value")]
+    #[serde(rename = "day_sell_value", default)]
     pub day_sell_value: f64,
 }
 
 /// Position type enumeration
+///
+/// Serialized as Kite's `position_type` values, `day` and `overnight`.
+/// The old `Day` and `Net` spellings are still accepted when deserializing.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum PositionType {
     /// Day positions (intraday)
+    #[serde(rename = "day", alias = "Day")]
     Day,
     /// Net positions (overnight + intraday)
+    #[serde(rename = "overnight", alias = "Net", alias = "net")]
     Net,
 }
 
+impl std::fmt::Display for PositionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PositionType::Day => write!(f, "day"),
+            PositionType::Net => write!(f, "overnight"),
+        }
+    }
+}
+
 /// Positions summary
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionsSummary {
@@ -270,8 +293,49 @@ impl Position {
     }
 }
 
+/// Prices as [`Decimal`](crate::models::common::Decimal), for exact aggregation
+#[cfg(feature = "decimal")]
+impl Position {
+    /// Average price as a `Decimal`
+    pub fn average_price_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.average_price)
+    }
+
+    /// Last traded price as a `Decimal`
+    pub fn last_price_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.last_price)
+    }
+
+    /// Close price as a `Decimal`
+    pub fn close_price_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.close_price)
+    }
+
+    /// P&L as a `Decimal`
+    pub fn pnl_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.pnl)
+    }
+
+    /// M2M P&L as a `Decimal`
+    pub fn m2m_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.m2m)
+    }
+
+    /// Realised P&L as a `Decimal`
+    pub fn realised_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.realised)
+    }
+
+    /// Unrealised P&L as a `Decimal`
+    pub fn unrealised_decimal(&self) -> crate::models::common::Decimal {
+        crate::models::common::to_decimal(self.unrealised)
+    }
+}
+
 impl PositionsSummary {
     /// Calculate from a list of positions
+    ///
+    /// Totals are rounded to [`PRICE_DECIMALS`] places.
     pub fn from_positions(positions: &[Position]) -> Self {
         let total_pnl = positions.iter().map(|p| p.pnl).sum();
         let total_m2m = positions.iter().map(|p| p.m2m).sum();
@@ -282,10 +346,10 @@ impl PositionsSummary {
         let loss_positions = positions.iter().filter(|p| p.is_loss()).count();
 
         Self {
-            total_pnl,
-            total_m2m,
-            total_unrealised,
-            total_realised,
+            total_pnl: round_price(total_pnl, PRICE_DECIMALS),
+            total_m2m: round_price(total_m2m, PRICE_DECIMALS),
+            total_unrealised: round_price(total_unrealised, PRICE_DECIMALS),
+            total_realised: round_price(total_realised, PRICE_DECIMALS),
             positions_count: positions.len(),
             profitable_positions,
             loss_positions,
@@ -378,4 +442,46 @@ impl PositionConversionRequest {
             Product::CNC,
         )
     }
+
+    /// Validate the conversion request
+    pub fn validate(&self) -> Result<(), String> {
+        if self.trading_symbol.is_empty() {
+            return Err("Trading symbol cannot be empty".to_string());
+        }
+
+        if self.quantity == 0 {
+            return Err("Quantity must be greater than 0".to_string());
+        }
+
+        if self.old_product == self.new_product {
+            return Err(format!(
+                "Position is already {}; old and new product must differ",
+                self.old_product
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ConversionRequest> for PositionConversionRequest {
+    /// Intraday (MIS) positions are converted as day positions, everything
+    /// else as overnight positions
+    fn from(request: ConversionRequest) -> Self {
+        let position_type = if request.from_product == Product::MIS {
+            PositionType::Day
+        } else {
+            PositionType::Net
+        };
+
+        Self::new(
+            request.exchange,
+            request.trading_symbol,
+            request.transaction_type,
+            position_type,
+            request.quantity,
+            request.from_product,
+            request.to_product,
+        )
+    }
 }
@@ -3,6 +3,22 @@
 //! These tests verify the public API functionality, retry mechanisms,
 //! error handling, and typed responses.
 
+use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig};
+
+/// Client configuration pointed at a mock server, with rate limiting off
+fn mock_config(url: impl Into<String>) -> KiteConnectConfig {
+    KiteConnectConfig {
+        base_url: url.into(),
+        enable_rate_limiting: false,
+        ..Default::default()
+    }
+}
+
+/// Client for a mock server, built from [`mock_config`]
+fn mock_client(url: impl Into<String>) -> KiteConnect {
+    KiteConnect::new_with_config("test_key", mock_config(url))
+}
+
 #[cfg(test)]
 mod tests {
     use kiteconnect_async_wasm::connect::KiteConnect;
@@ -57,6 +73,7 @@ mod api_tests {
     // Note: These tests require actual API credentials and network access
     // They are marked as ignored by default to prevent CI failures
 
+    #[cfg(feature = "legacy")]
     #[tokio::test]
     #[ignore]
     async fn test_instruments_api_call() {
@@ -113,10 +130,131 @@ mod configuration_tests {
         assert_eq!(config.timeout, 60);
         assert_eq!(config.base_url, "https://custom.api.url");
     }
+
+    #[test]
+    fn test_new_sets_request_timeout() {
+        use std::time::Duration;
+
+        let client = KiteConnect::new("test_key", "test_token");
+        assert_eq!(client.timeout(), Duration::from_secs(30));
+        assert_eq!(client.access_token(), "test_token");
+
+        let config = kiteconnect_async_wasm::connect::KiteConnectConfig {
+            timeout: 5,
+            ..Default::default()
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+        assert_eq!(client.timeout(), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_connection_pool_configuration() {
+        use kiteconnect_async_wasm::connect::KiteConnectConfig;
+        use std::time::Duration;
+
+        // The simple constructor pools connections with the config defaults
+        let defaults = KiteConnectConfig::default();
+        let client = KiteConnect::new("test_key", "test_token");
+        assert_eq!(client.max_idle_connections(), defaults.max_idle_connections);
+        assert_eq!(
+            client.idle_timeout(),
+            Duration::from_secs(defaults.idle_timeout)
+        );
+        assert!(defaults.tcp_keepalive.is_some());
+        assert_eq!(client.timeout(), Duration::from_secs(defaults.timeout));
+
+        // Tuned settings for high-frequency polling are applied
+        let config = KiteConnectConfig {
+            max_idle_connections: 32,
+            idle_timeout: 90,
+            tcp_keepalive: Some(15),
+            ..Default::default()
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+        assert_eq!(client.max_idle_connections(), 32);
+        assert_eq!(client.idle_timeout(), Duration::from_secs(90));
+    }
+
+    #[tokio::test]
+    async fn test_builder_configures_client() {
+        use kiteconnect_async_wasm::connect::RetryConfig;
+        use kiteconnect_async_wasm::models::common::KiteError;
+        use std::time::Duration;
+
+        let err = KiteConnect::builder().build().unwrap_err();
+        assert!(matches!(err, KiteError::InvalidParameter(_)));
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings")
+            .match_header("authorization", "token test_key:test_token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"success","data":[]}"#)
+            .create_async()
+            .await;
+
+        let client = KiteConnect::builder()
+            .api_key("test_key")
+            .access_token("test_token")
+            .base_url(server.url())
+            .timeout(Duration::from_millis(2500))
+            .retry(RetryConfig {
+                max_retries: 0,
+                ..Default::default()
+            })
+            .rate_limiting(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.timeout(), Duration::from_secs(3));
+        assert!(!client.is_rate_limiting_enabled());
+        assert!(client.holdings_typed().await.unwrap().is_empty());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_last_response_headers_captured_when_enabled() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("x-ratelimit-remaining", "7")
+            .with_body(r#"{"status":"success","data":[]}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = KiteConnect::builder()
+            .api_key("test_key")
+            .base_url(server.url())
+            .rate_limiting(false)
+            .build()
+            .unwrap();
+        client.holdings_typed().await.unwrap();
+        assert!(client.last_response_headers().is_none());
+
+        let client = KiteConnect::builder()
+            .api_key("test_key")
+            .base_url(server.url())
+            .rate_limiting(false)
+            .capture_response_headers(true)
+            .build()
+            .unwrap();
+        assert!(client.last_response_headers().is_none());
+        client.holdings_typed().await.unwrap();
+
+        let headers = client.last_response_headers().unwrap();
+        assert_eq!(headers["x-ratelimit-remaining"], "7");
+        mock.assert_async().await;
+    }
 }
 
 #[cfg(test)]
 mod error_handling_tests {
+    use super::mock_config;
+
     #[test]
     fn test_error_types() {
         // Test that our error types can be created and used
@@ -135,6 +273,94 @@ mod error_handling_tests {
         let auth_error = KiteError::Authentication("Invalid token".to_string());
         assert!(matches!(auth_error, KiteError::Authentication(_)));
     }
+
+    #[test]
+    fn test_error_types_from_crate_root_and_prelude() {
+        use kiteconnect_async_wasm::{KiteError, KiteResult};
+
+        fn fails() -> KiteResult<()> {
+            Err(KiteError::General("root".to_string()))
+        }
+        assert!(matches!(fails(), Err(KiteError::General(_))));
+
+        fn from_prelude() -> kiteconnect_async_wasm::prelude::KiteResult<u32> {
+            use kiteconnect_async_wasm::prelude::*;
+            let _config = KiteConnectConfig::default();
+            Err(KiteError::Shutdown)
+        }
+        let err: kiteconnect_async_wasm::models::common::KiteError = from_prelude().unwrap_err();
+        assert!(matches!(err, KiteError::Shutdown));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_exposes_retry_after() {
+        use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig, RetryConfig};
+        use kiteconnect_async_wasm::models::common::KiteError;
+        use std::time::Duration;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings")
+            .with_status(429)
+            .with_header("content-type", "application/json")
+            .with_header("retry-after", "2")
+            .with_body(r#"{"status":"error","message":"Too many requests","error_type":"NetworkException"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = KiteConnectConfig {
+            retry_config: RetryConfig {
+                max_retries: 0,
+                ..Default::default()
+            },
+            ..mock_config(server.url())
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+
+        let err = client.holdings_typed().await.unwrap_err();
+        assert!(matches!(err, KiteError::RateLimited { .. }));
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(2)));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_timeout_is_distinct_and_retried() {
+        use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig, RetryConfig};
+        use kiteconnect_async_wasm::models::common::KiteError;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        // Accept connections but never answer, so every request times out
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let counter = accepted.clone();
+        tokio::spawn(async move {
+            let mut open = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                open.push(stream);
+            }
+        });
+
+        let config = KiteConnectConfig {
+            timeout: 1,
+            retry_config: RetryConfig {
+                max_retries: 1,
+                base_delay: Duration::from_millis(10),
+                ..Default::default()
+            },
+            ..mock_config(format!("http://{}", addr))
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+
+        let err = client.holdings_typed().await.unwrap_err();
+        assert!(matches!(err, KiteError::Timeout(_)), "got {:?}", err);
+        assert!(err.is_retryable());
+        assert_eq!(accepted.load(Ordering::SeqCst), 2);
+    }
 }
 
 #[cfg(test)]
@@ -162,6 +388,8 @@ mod model_tests {
             iceberg_legs: None,
             iceberg_quantity: None,
             auction_number: None,
+            variety: None,
+            validity_ttl: None,
             tag: None,
         };
 
@@ -199,6 +427,8 @@ mod model_tests {
             iceberg_legs: None,
             iceberg_quantity: None,
             auction_number: None,
+            variety: None,
+            validity_ttl: None,
             tag: None,
         };
 
@@ -217,3 +447,3591 @@ mod model_tests {
         assert!(json_result.is_ok());
     }
 }
+
+#[cfg(test)]
+mod session_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::connect::verify_postback;
+    use kiteconnect_async_wasm::models::common::KiteError;
+    use kiteconnect_async_wasm::models::orders::PostbackPayload;
+
+    #[tokio::test]
+    async fn test_generate_session_double_submit() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/session/token")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"error","message":"Token is invalid or has expired.","error_type":"TokenException"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = mock_client(server.url());
+        let err = client
+            .generate_session_typed("used_request_token", "secret")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, KiteError::RequestTokenUsed(_)));
+        assert!(err.requires_reauth());
+        assert!(err.to_string().contains("Restart the login flow"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_generate_session_typed() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/session/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"success","data":{"user_id":"AB1234","user_name":"Test User","user_shortname":"Test","email":"test@example.com","user_type":"individual","broker":"ZERODHA","exchanges":["NSE","BSE"],"products":["CNC","MIS"],"order_types":["LIMIT","MARKET"],"api_key":"test_key","access_token":"new_access_token","public_token":"public","refresh_token":"","login_time":"2024-01-15 09:05:12","meta":{"demat_consent":"physical"},"avatar_url":null}}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = mock_client(server.url());
+        let session = client
+            .generate_session_typed("request_token", "secret")
+            .await
+            .unwrap();
+
+        assert_eq!(session.user_id, "AB1234");
+        assert_eq!(session.access_token, "new_access_token");
+        assert_eq!(session.login_time, "2024-01-15 09:05:12");
+        assert!(session.has_exchange("NSE"));
+        assert_eq!(client.access_token(), "new_access_token");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_generate_session_without_access_token() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/session/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"success","data":{"user_id":"AB1234"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = mock_client(server.url());
+        let err = client
+            .generate_session_typed("request_token", "secret")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, KiteError::Authentication(_)), "got {:?}", err);
+        assert!(err.to_string().contains("access_token"));
+        assert_eq!(client.access_token(), "");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_token_exception_runs_session_expiry_hook() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static EXPIRED: AtomicUsize = AtomicUsize::new(0);
+        fn on_expiry() {
+            EXPIRED.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let mut server = mockito::Server::new_async().await;
+        let holdings = server
+            .mock("GET", "/portfolio/holdings")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"error","message":"Incorrect `api_key` or `access_token`.","error_type":"TokenException"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let login = server
+            .mock("POST", "/session/token")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"error","message":"Token is invalid or has expired.","error_type":"TokenException"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = mock_client(server.url());
+        client.set_session_expiry_hook(on_expiry);
+
+        let err = client.holdings_typed().await.unwrap_err();
+        assert!(matches!(err, KiteError::TokenException(_)));
+        assert_eq!(EXPIRED.load(Ordering::SeqCst), 1);
+
+        // A rejected request token is not an expired session
+        let err = client
+            .generate_session_typed("used_request_token", "secret")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, KiteError::RequestTokenUsed(_)));
+        assert_eq!(EXPIRED.load(Ordering::SeqCst), 1);
+
+        holdings.assert_async().await;
+        login.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_async_session_hook_renews_and_retries_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let mut server = mockito::Server::new_async().await;
+        let expired = server
+            .mock("GET", "/portfolio/holdings")
+            .match_header("authorization", "token test_key:stale_token")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"error","message":"Incorrect `api_key` or `access_token`.","error_type":"TokenException"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let renewed = server
+            .mock("GET", "/portfolio/holdings")
+            .match_header("authorization", "token test_key:fresh_token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"success","data":[]}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let mut client = mock_client(server.url());
+        client.set_access_token("stale_token");
+        client.set_async_session_hook(Arc::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Some("fresh_token".to_string()) })
+        }));
+
+        let holdings = client.holdings_typed().await.unwrap();
+        assert!(holdings.is_empty());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(client.access_token(), "fresh_token");
+        expired.assert_async().await;
+        renewed.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_async_session_hook_without_token_returns_error() {
+        use std::sync::Arc;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"error","message":"Incorrect `api_key` or `access_token`.","error_type":"TokenException"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = mock_client(server.url());
+        client.set_async_session_hook(Arc::new(|| Box::pin(async { None })));
+
+        let err = client.holdings_typed().await.unwrap_err();
+        assert!(matches!(err, KiteError::TokenException(_)), "got {:?}", err);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_token_refresher_updates_shared_token() {
+        use kiteconnect_async_wasm::connect::RefreshSchedule;
+        use std::time::Duration;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/session/refresh_token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"success","data":{"access_token":"renewed_token","refresh_token":"next_refresh"}}"#)
+            .expect_at_least(2)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+        let handle = client.spawn_token_refresher_with_schedule(
+            "refresh_token".to_string(),
+            "secret".to_string(),
+            RefreshSchedule::every(Duration::from_millis(20)),
+        );
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while client.access_token() != "renewed_token" && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        // Let a second renewal run with the rotated refresh token
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        handle.abort();
+        assert!(handle.await.unwrap_err().is_cancelled());
+
+        // The original handle sees the token renewed by the background task
+        assert_eq!(client.access_token(), "renewed_token");
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_refresh_schedule_uses_injected_clock() {
+        use chrono::{TimeZone, Utc};
+        use kiteconnect_async_wasm::connect::RefreshSchedule;
+        use std::time::Duration;
+
+        // 05:00 IST: renew at 05:55 IST the same day
+        let schedule = RefreshSchedule::daily()
+            .with_clock(|| Utc.with_ymd_and_hms(2024, 1, 14, 23, 30, 0).unwrap());
+        assert_eq!(schedule.next_delay(), Duration::from_secs(55 * 60));
+
+        // 07:00 IST: already past today's renewal, wait for tomorrow's
+        let schedule = RefreshSchedule::daily()
+            .with_clock(|| Utc.with_ymd_and_hms(2024, 1, 15, 1, 30, 0).unwrap());
+        assert_eq!(
+            schedule.next_delay(),
+            Duration::from_secs((22 * 60 + 55) * 60)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_session_is_never_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/session/token")
+            .with_status(503)
+            .with_body("Service unavailable")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = mock_client(server.url());
+        assert!(client
+            .generate_session_typed("request_token", "secret")
+            .await
+            .is_err());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_verify_postback() {
+        let payload: PostbackPayload = serde_json::from_value(serde_json::json!({
+            "order_id": "220303000308932",
+            "status": "COMPLETE",
+            "order_timestamp": "2022-03-03 09:24:25",
+            "exchange": "NSE",
+            "tradingsymbol": "SBIN",
+            "instrument_token": 779521,
+            "order_type": "MARKET",
+            "transaction_type": "BUY",
+            "validity": "DAY",
+            "product": "CNC",
+            "quantity": 1,
+            "checksum": "f3336920db057beff5f3decf340d67ac78e0feda17f6e7bdb849473c43f9196d"
+        }))
+        .unwrap();
+
+        assert!(verify_postback(&payload, "secret", &payload.checksum).await);
+        assert!(verify_postback(&payload, "secret", &payload.checksum.to_uppercase()).await);
+        assert!(!verify_postback(&payload, "other_secret", &payload.checksum).await);
+        assert!(!verify_postback(&payload, "secret", "deadbeef").await);
+    }
+}
+
+#[cfg(test)]
+mod quote_polling_tests {
+    use super::{mock_client, mock_config};
+    use chrono::DateTime;
+    use futures_util::StreamExt;
+    use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig};
+    use kiteconnect_async_wasm::models::common::Exchange;
+    use kiteconnect_async_wasm::models::market_data::{MarketState, MarketStatus};
+    use std::time::Duration;
+
+    const QUOTE_RESPONSE: &str = r#"{
+        "status": "success",
+        "data": {
+            "NSE:INFY": {
+                "instrument_token": 408065,
+                "tradingsymbol": "INFY",
+                "exchange": "NSE",
+                "last_price": 1412.95,
+                "last_quantity": 5,
+                "last_trade_time": "2024-01-15T09:45:12Z",
+                "average_price": 1412.47,
+                "volume": 7360198,
+                "buy_quantity": 0,
+                "sell_quantity": 5191,
+                "net_change": 0.0,
+                "ohlc": {"open": 1396.0, "high": 1421.75, "low": 1395.55, "close": 1389.65},
+                "depth": {"buy": [], "sell": []}
+            }
+        }
+    }"#;
+
+    #[tokio::test]
+    async fn test_poll_quotes_emits_batches() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/quote")
+            .match_query(mockito::Matcher::UrlEncoded("i".into(), "NSE:INFY".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(QUOTE_RESPONSE)
+            .expect_at_least(2)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let stream = client.poll_quotes(vec!["NSE:INFY".to_string()], Duration::from_millis(10));
+        let batches: Vec<_> = stream.take(2).collect().await;
+
+        assert_eq!(batches.len(), 2);
+        for batch in batches {
+            let quotes = batch.unwrap();
+            assert_eq!(quotes["NSE:INFY"].last_price, 1412.95);
+            assert!(!quotes["NSE:INFY"].is_after_hours);
+        }
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_quotes_tagged_after_hours_when_market_closed() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/quote")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(QUOTE_RESPONSE)
+            .create_async()
+            .await;
+
+        let config = KiteConnectConfig {
+            tag_after_hours_quotes: true,
+            ..mock_config(server.url())
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+
+        // Sunday noon IST: the schedule reports NSE closed
+        let sunday = DateTime::parse_from_rfc3339("2024-01-14T12:00:00+05:30").unwrap();
+        let status = MarketStatus::from_schedule(Exchange::NSE, sunday.into());
+        assert_eq!(status.status, MarketState::Closed);
+        client.set_market_status(status);
+
+        let stream = client.poll_quotes(vec!["NSE:INFY".to_string()], Duration::from_secs(1));
+        futures_util::pin_mut!(stream);
+        let quotes = stream.next().await.unwrap().unwrap();
+
+        assert!(quotes["NSE:INFY"].is_after_hours);
+        assert_eq!(quotes["NSE:INFY"].last_price, 1412.95);
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_seeded_holiday_reported_by_market_status() {
+        let client = KiteConnect::new("test_key", "test_token");
+        let ist = chrono::FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        let today = chrono::Utc::now().with_timezone(&ist).date_naive();
+
+        // Cached before the calendar is set; setting it drops the entry
+        assert!(!client.market_status(Exchange::NSE).is_holiday());
+        client.set_market_holidays(Exchange::NSE, [today]);
+
+        assert!(client.market_status(Exchange::NSE).is_holiday());
+        assert!(!client.market_status(Exchange::MCX).is_holiday());
+        assert!(!client.market_status(Exchange::NSE).is_trading_allowed());
+
+        let statuses = client.market_statuses();
+        assert_eq!(statuses.len(), Exchange::all().len());
+        assert_eq!(statuses[0].exchange, Exchange::NSE);
+        assert_eq!(statuses[0].status, MarketState::Holiday);
+
+        client.set_market_holidays(Exchange::NSE, []);
+        assert!(client.market_holidays(Exchange::NSE).is_empty());
+        assert!(!client.market_status(Exchange::NSE).is_holiday());
+    }
+}
+
+#[cfg(test)]
+mod instruments_tests {
+    use super::{mock_client, mock_config};
+    use chrono::NaiveDate;
+    use kiteconnect_async_wasm::connect::{
+        CacheBackend, CacheConfig, KiteConnect, KiteConnectConfig,
+    };
+    use kiteconnect_async_wasm::models::common::{Exchange, InstrumentType};
+
+    const INSTRUMENTS_CSV: &str = "\
+instrument_token,exchange_token,tradingsymbol,name,last_price,expiry,strike,tick_size,lot_size,instrument_type,segment,exchange
+408065,1594,INFY,INFOSYS,0,,0,0.05,1,EQ,NSE,NSE
+256265,1001,NIFTY 50,NIFTY 50,0,,0,0,0,EQ,INDICES,NSE
+128053508,500209,INFY,INFOSYS,0,,0,0.05,1,EQ,BSE,BSE
+5720322,22345,NIFTY24DECFUT,NIFTY,0,2024-12-26,0,0.05,25,FUT,NFO-FUT,NFO
+5720578,22346,NIFTY24DEC24000CE,NIFTY,0,2024-12-26,24000,0.05,25,CE,NFO-OPT,NFO
+5720834,22347,NIFTY24D1924000CE,NIFTY,0,2024-12-19,24000,0.05,25,CE,NFO-OPT,NFO
+5721090,22348,NIFTY24D1924100CE,NIFTY,0,2024-12-19,24100,0.05,25,CE,NFO-OPT,NFO
+5721346,22349,NIFTY25JAN24000CE,NIFTY,0,2025-01-30,24000,0.05,25,CE,NFO-OPT,NFO
+5721602,22350,NIFTY24D1924000PE,NIFTY,0,2024-12-19,24000,0.05,25,PE,NFO-OPT,NFO
+5721858,22351,BANKNIFTY24DEC51000CE,BANKNIFTY,0,2024-12-24,51000,0.05,15,CE,NFO-OPT,NFO
+";
+
+    #[tokio::test]
+    async fn test_equities_returns_only_equities() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let nse = client.equities(Exchange::NSE).await.unwrap();
+        assert_eq!(nse.len(), 1);
+        assert_eq!(nse[0].trading_symbol, "INFY");
+        assert!(nse
+            .iter()
+            .all(|i| i.instrument_type == InstrumentType::EQ && i.exchange == Exchange::NSE));
+
+        // Served from the cached full list
+        let bse = client.equities(Exchange::BSE).await.unwrap();
+        assert_eq!(bse.len(), 1);
+        assert_eq!(bse[0].exchange, Exchange::BSE);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_instruments_by_exchanges_downloads_once() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let instruments = client
+            .instruments_by_exchanges(&[Exchange::NSE, Exchange::NFO])
+            .await
+            .unwrap();
+        assert_eq!(instruments.len(), 9);
+        assert!(instruments
+            .iter()
+            .all(|i| matches!(i.exchange, Exchange::NSE | Exchange::NFO)));
+
+        let bse = client
+            .instruments_by_exchanges(&[Exchange::BSE])
+            .await
+            .unwrap();
+        assert_eq!(bse.len(), 1);
+        assert!(client
+            .instruments_by_exchanges(&[])
+            .await
+            .unwrap()
+            .is_empty());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_instruments_parsed_from_chunked_gzip_body() {
+        use std::io::Write;
+
+        // Quoted field with a comma, split across chunks
+        let csv = INSTRUMENTS_CSV.replace(
+            "408065,1594,INFY,INFOSYS,",
+            "408065,1594,INFY,\"INFOSYS, LTD\",",
+        );
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(csv.as_bytes()).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_header("content-encoding", "gzip")
+            .with_chunked_body(move |w| {
+                for chunk in gzipped.chunks(7) {
+                    w.write_all(chunk)?;
+                    w.flush()?;
+                }
+                Ok(())
+            })
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let instruments = client.instruments_typed(None).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(instruments.len(), 10);
+        assert_eq!(instruments[0].name, "INFOSYS, LTD");
+        assert_eq!(instruments[9].trading_symbol, "BANKNIFTY24DEC51000CE");
+        assert_eq!(instruments[9].lot_size, 15);
+    }
+
+    #[tokio::test]
+    async fn test_unlabelled_gzip_instruments_detected_by_magic_bytes() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(INSTRUMENTS_CSV.as_bytes()).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let mut server = mockito::Server::new_async().await;
+        // A `.gz` dump served without Content-Encoding, magic bytes split
+        let mock = server
+            .mock("GET", "/instruments/NFO")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_chunked_body(move |w| {
+                w.write_all(&gzipped[..1])?;
+                w.flush()?;
+                w.write_all(&gzipped[1..])
+            })
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let instruments = client.instruments_typed(Some(Exchange::NFO)).await.unwrap();
+        mock.assert_async().await;
+
+        // The fixture is not filtered server-side; every row parses
+        assert_eq!(instruments.len(), 10);
+        assert_eq!(instruments[3].trading_symbol, "NIFTY24DECFUT");
+        assert_eq!(instruments[3].expiry, NaiveDate::from_ymd_opt(2024, 12, 26));
+    }
+
+    #[tokio::test]
+    async fn test_instruments_with_report_lists_skipped_rows() {
+        let csv = format!(
+            "{}{}{}",
+            INSTRUMENTS_CSV,
+            "999,1,SHORTROW,SHORT,0,,0\n",
+            "998,2,BADLOT,BAD,0,,0,0.05,many,EQ,NSE,NSE\n"
+        );
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(csv)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let report = client.instruments_with_report(None).await.unwrap();
+        assert_eq!(report.parsed.len(), 10);
+        assert_eq!(report.skipped, 2);
+        assert!(!report.is_clean());
+        assert!(report.errors[0].starts_with("line 12 (SHORTROW): missing field"));
+        assert!(report.errors[1].starts_with("line 13 (BADLOT):"));
+        assert_eq!(client.instruments_parse_skipped_rows(), 2);
+
+        // The lenient default returns the good rows, here from the cache
+        let instruments = client.instruments_typed(None).await.unwrap();
+        assert_eq!(instruments.len(), 10);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_instruments_cache_persists_to_disk() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache_dir = std::env::temp_dir().join(format!(
+            "kite-instruments-cache-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let config = || KiteConnectConfig {
+            cache_config: Some(CacheConfig {
+                cache_dir: Some(cache_dir.clone()),
+                ..Default::default()
+            }),
+            ..mock_config(server.url())
+        };
+
+        let client = KiteConnect::new_with_config("test_key", config());
+        assert_eq!(client.equities(Exchange::NSE).await.unwrap().len(), 1);
+        let cache_file = cache_dir.join("instruments.json");
+        assert!(cache_file.exists());
+
+        // A fresh client starts from the file instead of downloading again
+        let restarted = KiteConnect::new_with_config("test_key", config());
+        assert_eq!(restarted.equities(Exchange::BSE).await.unwrap().len(), 1);
+        mock.assert_async().await;
+
+        restarted.clear_instruments_cache().unwrap();
+        assert!(!cache_file.exists());
+        let _ = std::fs::remove_dir_all(&cache_dir);
+    }
+
+    /// Backend standing in for a store shared between processes
+    #[derive(Debug, Default)]
+    struct SharedBackend {
+        entries: std::sync::Mutex<
+            std::collections::HashMap<String, (serde_json::Value, std::time::SystemTime)>,
+        >,
+    }
+
+    impl CacheBackend for SharedBackend {
+        fn get(&self, key: &str) -> Option<(serde_json::Value, std::time::SystemTime)> {
+            self.entries.lock().unwrap().get(key).cloned()
+        }
+
+        fn set(&self, key: &str, value: serde_json::Value) {
+            self.entries
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), (value, std::time::SystemTime::now()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_instruments_cache_backend_shared_between_clients() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments/NSE")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let backend = std::sync::Arc::new(SharedBackend::default());
+        let config = || KiteConnectConfig {
+            cache_backend: Some(backend.clone()),
+            ..mock_config(server.url())
+        };
+
+        let first = KiteConnect::new_with_config("test_key", config());
+        first.instruments_typed(Some(Exchange::NSE)).await.unwrap();
+        assert!(backend.get("instruments:NSE").is_some());
+
+        // A second client is served from the shared backend
+        let second = KiteConnect::new_with_config("test_key", config());
+        let instruments = second.instruments_typed(Some(Exchange::NSE)).await.unwrap();
+        assert_eq!(instruments.len(), 10);
+        mock.assert_async().await;
+
+        // Shutting a client down leaves the shared entries alone
+        first.shutdown().await;
+        assert!(backend.get("instruments:NSE").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_instruments_cached_per_exchange() {
+        let mut server = mockito::Server::new_async().await;
+        let nse_mock = server
+            .mock("GET", "/instruments/NSE")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+        let bse_mock = server
+            .mock("GET", "/instruments/BSE")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+        let full_mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        // Alternating exchanges no longer evict each other
+        for _ in 0..2 {
+            client.instruments_typed(Some(Exchange::NSE)).await.unwrap();
+            client.instruments_typed(Some(Exchange::BSE)).await.unwrap();
+            client.instruments_typed(None).await.unwrap();
+        }
+
+        nse_mock.assert_async().await;
+        bse_mock.assert_async().await;
+        full_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_instruments_parse_skipped_rows() {
+        let csv = format!("{}408066,1595,BROKEN,BROKEN,0\n", INSTRUMENTS_CSV);
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(csv)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+        assert_eq!(client.instruments_parse_skipped_rows(), 0);
+
+        let instruments = client.instruments_typed(None).await.unwrap();
+        assert_eq!(instruments.len(), 10);
+        assert_eq!(client.instruments_parse_skipped_rows(), 1);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_expiries_sorted_and_distinct() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let calls = client
+            .expiries(Exchange::NFO, "nifty", InstrumentType::CE)
+            .await
+            .unwrap();
+        assert_eq!(
+            calls,
+            vec![date(2024, 12, 19), date(2024, 12, 26), date(2025, 1, 30)]
+        );
+
+        let futures = client
+            .expiries(Exchange::NFO, "NIFTY", InstrumentType::FUT)
+            .await
+            .unwrap();
+        assert_eq!(futures, vec![date(2024, 12, 26)]);
+
+        let none = client
+            .expiries(Exchange::BFO, "NIFTY", InstrumentType::CE)
+            .await
+            .unwrap();
+        assert!(none.is_empty());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_symbol_for_token_resolves_and_caches() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(INSTRUMENTS_CSV)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        assert_eq!(
+            client.symbol_for_token(408065).await.unwrap().as_deref(),
+            Some("NSE:INFY")
+        );
+        assert_eq!(
+            client.symbol_for_token(5720322).await.unwrap().as_deref(),
+            Some("NFO:NIFTY24DECFUT")
+        );
+        assert_eq!(client.symbol_for_token(1).await.unwrap(), None);
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod quote_validation_tests {
+    use kiteconnect_async_wasm::connect::KiteConnect;
+    use kiteconnect_async_wasm::models::common::KiteError;
+
+    #[tokio::test]
+    async fn test_quote_typed_rejects_missing_colon() {
+        let client = KiteConnect::new("test_key", "test_token");
+        let err = client
+            .quote_typed(vec!["NSE:INFY", "RELIANCE"])
+            .await
+            .unwrap_err();
+
+        match err {
+            KiteError::General(message) => {
+                assert!(message.contains("\"RELIANCE\""));
+                assert!(!message.contains("NSE:INFY"));
+            }
+            other => panic!("expected General error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_quote_typed_rejects_empty_entries() {
+        let client = KiteConnect::new("test_key", "test_token");
+        let err = client
+            .quote_typed(vec!["", "NSE:", "256265"])
+            .await
+            .unwrap_err();
+
+        match err {
+            KiteError::General(message) => {
+                assert!(message.contains("\"\""));
+                assert!(message.contains("\"NSE:\""));
+                assert!(!message.contains("256265"));
+            }
+            other => panic!("expected General error, got {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod quote_bundle_tests {
+    use super::{mock_client, mock_config};
+    use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig};
+    use kiteconnect_async_wasm::models::common::KiteError;
+    use mockito::Matcher;
+
+    const QUOTE_BODY: &str = r#"{"status": "success", "data": {"NSE:INFY": {
+        "instrument_token": 408065, "tradingsymbol": "INFY", "exchange": "NSE",
+        "last_price": 1500.5, "last_quantity": 10,
+        "last_trade_time": "2024-01-15T09:30:00Z", "average_price": 1498.2,
+        "volume": 125000, "buy_quantity": 4000, "sell_quantity": 3500,
+        "oi": null, "oi_day_high": null, "oi_day_low": null, "net_change": 12.5,
+        "ohlc": {"open": 1490.0, "high": 1505.0, "low": 1485.0, "close": 1488.0},
+        "depth": {"buy": [], "sell": []}
+    }}}"#;
+
+    #[tokio::test]
+    async fn test_quote_bundle_contains_all_three_results() {
+        let mut server = mockito::Server::new_async().await;
+        // Duplicate identifiers are only sent once
+        let query = Matcher::Exact("i=NSE%3AINFY".to_string());
+
+        let quote = server
+            .mock("GET", "/quote")
+            .match_query(query.clone())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(QUOTE_BODY)
+            .create_async()
+            .await;
+        let ohlc = server
+            .mock("GET", "/quote/ohlc")
+            .match_query(query.clone())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {"NSE:INFY": {
+                    "instrument_token": 408065, "last_price": 1500.5,
+                    "ohlc": {"open": 1490.0, "high": 1505.0, "low": 1485.0, "close": 1488.0}
+                }}}"#,
+            )
+            .create_async()
+            .await;
+        let ltp = server
+            .mock("GET", "/quote/ltp")
+            .match_query(query)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {"NSE:INFY": {
+                    "instrument_token": 408065, "last_price": 1500.5
+                }}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let bundle = client
+            .quote_bundle(vec!["NSE:INFY", "NSE:INFY"])
+            .await
+            .unwrap();
+
+        assert_eq!(bundle.quotes["NSE:INFY"].trading_symbol, "INFY");
+        assert_eq!(bundle.ohlc["NSE:INFY"].ohlc.open, 1490.0);
+        assert_eq!(bundle.ltp["NSE:INFY"].last_price, 1500.5);
+
+        quote.assert_async().await;
+        ohlc.assert_async().await;
+        ltp.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_quote_typed_uses_configured_batch_size() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/quote")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(QUOTE_BODY)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let config = KiteConnectConfig {
+            quote_batch_size: 100,
+            ..mock_config(server.url())
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+        assert_eq!(client.quote_batch_size(), 100);
+
+        let instruments: Vec<String> = (1..=250).map(|token| token.to_string()).collect();
+        let instruments: Vec<&str> = instruments.iter().map(String::as_str).collect();
+        client.quote_typed(instruments).await.unwrap();
+        mock.assert_async().await;
+
+        // Sizes above Kite's limit are rejected rather than capped
+        let config = KiteConnectConfig {
+            quote_batch_size: 1000,
+            ..mock_config(server.url())
+        };
+        let client = KiteConnect::new_with_config("test_key", config.clone());
+        let err = client.quote_typed(vec!["NSE:INFY"]).await.unwrap_err();
+        assert!(
+            matches!(err, KiteError::InputException(ref msg) if msg.contains("quote_batch_size"))
+        );
+
+        let err = KiteConnect::builder()
+            .api_key("test_key")
+            .config(config)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, KiteError::InputException(_)));
+    }
+
+    #[tokio::test]
+    async fn test_ohlc_and_ltp_typed_split_large_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let ohlc = server
+            .mock("GET", "/quote/ohlc")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {"NSE:INFY": {
+                    "instrument_token": 408065, "last_price": 1500.5,
+                    "ohlc": {"open": 1490.0, "high": 1505.0, "low": 1485.0, "close": 1488.0}
+                }}}"#,
+            )
+            .expect(2)
+            .create_async()
+            .await;
+        let ltp = server
+            .mock("GET", "/quote/ltp")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {"NSE:INFY": {
+                    "instrument_token": 408065, "last_price": 1500.5
+                }}}"#,
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let mut instruments: Vec<String> = (1..=500).map(|token| token.to_string()).collect();
+        instruments.push("NSE:INFY".to_string());
+        let instruments: Vec<&str> = instruments.iter().map(String::as_str).collect();
+
+        let ohlc_data = client.ohlc_typed(instruments.clone()).await.unwrap();
+        assert_eq!(ohlc_data.len(), 1);
+        assert_eq!(ohlc_data["NSE:INFY"].ohlc.open, 1490.0);
+
+        let ltp_data = client.ltp_typed(instruments).await.unwrap();
+        assert_eq!(ltp_data.len(), 1);
+        assert_eq!(ltp_data["NSE:INFY"].instrument_token, 408065);
+
+        ohlc.assert_async().await;
+        ltp.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_quote_ordered_preserves_input_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/quote")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(QUOTE_BODY)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let quotes = client
+            .quote_ordered(vec!["NSE:EXPIRED", "NSE:INFY"])
+            .await
+            .unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes[0].0, "NSE:EXPIRED");
+        assert!(quotes[0].1.is_none());
+        assert_eq!(quotes[1].0, "NSE:INFY");
+        assert_eq!(quotes[1].1.as_ref().unwrap().trading_symbol, "INFY");
+    }
+
+    #[tokio::test]
+    async fn test_quote_by_token_keys_by_instrument_token() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/quote")
+            .match_query(Matcher::Exact("i=408065&i=1".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(QUOTE_BODY.replace("NSE:INFY", "408065"))
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let quotes = client.quote_by_token(&[408065, 1, 408065]).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(quotes.len(), 1);
+        assert_eq!(quotes[&408065].trading_symbol, "INFY");
+        assert!(client.quote_by_token(&[]).await.unwrap().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod realized_pnl_tests {
+    use super::mock_client;
+
+    #[tokio::test]
+    async fn test_realized_pnl_today_for_round_trip() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/trades")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": [
+                    {"trade_id": "1", "order_id": "101", "exchange_order_id": "201",
+                     "tradingsymbol": "INFY", "exchange": "NSE", "instrument_token": 408065,
+                     "product": "MIS", "average_price": 1500.0, "quantity": 10,
+                     "fill_timestamp": "2024-01-15T04:00:00Z",
+                     "exchange_timestamp": "2024-01-15T04:00:00Z", "transaction_type": "BUY"},
+                    {"trade_id": "2", "order_id": "102", "exchange_order_id": "202",
+                     "tradingsymbol": "INFY", "exchange": "NSE", "instrument_token": 408065,
+                     "product": "MIS", "average_price": 1512.5, "quantity": 10,
+                     "fill_timestamp": "2024-01-15T08:00:00Z",
+                     "exchange_timestamp": "2024-01-15T08:00:00Z", "transaction_type": "SELL"},
+                    {"trade_id": "3", "order_id": "103", "exchange_order_id": "203",
+                     "tradingsymbol": "TCS", "exchange": "NSE", "instrument_token": 2953217,
+                     "product": "MIS", "average_price": 3500.0, "quantity": 5,
+                     "fill_timestamp": "2024-01-15T05:00:00Z",
+                     "exchange_timestamp": "2024-01-15T05:00:00Z", "transaction_type": "BUY"},
+                    {"trade_id": "4", "order_id": "104", "exchange_order_id": "204",
+                     "tradingsymbol": "SBIN", "exchange": "NSE", "instrument_token": 779521,
+                     "product": "CNC", "average_price": 610.0, "quantity": 20,
+                     "fill_timestamp": "2024-01-15T06:00:00Z",
+                     "exchange_timestamp": "2024-01-15T06:00:00Z", "transaction_type": "SELL"}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+        let positions = server
+            .mock("GET", "/portfolio/positions")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {
+                    "net": [
+                        {"tradingsymbol": "SBIN", "exchange": "NSE", "instrument_token": 779521,
+                         "product": "CNC", "quantity": 0, "overnight_quantity": 20,
+                         "multiplier": 1, "average_price": 600.0}
+                    ],
+                    "day": [
+                        {"tradingsymbol": "SBIN", "exchange": "NSE", "instrument_token": 779521,
+                         "product": "CNC", "quantity": -20, "overnight_quantity": 20,
+                         "multiplier": 1, "average_price": 610.0}
+                    ]}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let pnl = client.realized_pnl_today().await.unwrap();
+        mock.assert_async().await;
+        positions.assert_async().await;
+
+        assert_eq!(pnl.len(), 2);
+        assert_eq!(pnl["NSE:INFY"], 125.0);
+        // Closing the overnight position realizes against its average price
+        assert_eq!(pnl["NSE:SBIN"], 200.0);
+    }
+}
+
+#[cfg(test)]
+mod auction_tests {
+    use super::mock_client;
+
+    #[tokio::test]
+    async fn test_auction_instruments_typed() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings/auctions")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": [
+                    {"tradingsymbol": "ASHOKLEY", "exchange": "NSE",
+                     "instrument_token": 54273, "isin": "INE208A01029",
+                     "product": "CNC", "price": 0, "quantity": 1,
+                     "t1_quantity": 0, "realised_quantity": 1,
+                     "authorised_quantity": 0, "authorised_date": "2022-12-23 00:00:00",
+                     "opening_quantity": 1, "collateral_quantity": 0,
+                     "collateral_type": "", "discrepancy": false,
+                     "average_price": 145.0, "last_price": 148.15,
+                     "close_price": 147.65, "pnl": 3.15, "day_change": 0.5,
+                     "day_change_percentage": 0.338, "auction_number": "20"}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let auctions = client.auction_instruments().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(auctions.len(), 1);
+        assert_eq!(auctions[0].trading_symbol, "ASHOKLEY");
+        assert_eq!(auctions[0].auction_number, "20");
+        assert_eq!(auctions[0].instrument_token, 54273);
+        assert_eq!(auctions[0].quantity, 1);
+        assert_eq!(auctions[0].last_price, 148.15);
+    }
+}
+
+#[cfg(test)]
+mod position_conversion_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::models::common::{Exchange, KiteError, TransactionType};
+    use kiteconnect_async_wasm::models::portfolio::{
+        BulkConversionRequest, ConversionRequest, PositionConversionRequest,
+    };
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn test_convert_position_typed_sends_position_type() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/portfolio/positions")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("tradingsymbol".into(), "INFY".into()),
+                Matcher::UrlEncoded("position_type".into(), "day".into()),
+                Matcher::UrlEncoded("old_product".into(), "MIS".into()),
+                Matcher::UrlEncoded("new_product".into(), "CNC".into()),
+                Matcher::UrlEncoded("quantity".into(), "5".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": true}"#)
+            .create_async()
+            .await;
+        let client = mock_client(server.url());
+
+        let request = PositionConversionRequest::mis_to_cnc(
+            Exchange::NSE,
+            "INFY".to_string(),
+            TransactionType::BUY,
+            5,
+        );
+        assert!(client.convert_position_typed(request).await.unwrap());
+        mock.assert_async().await;
+
+        // Invalid requests are rejected before sending
+        let request = PositionConversionRequest::mis_to_cnc(
+            Exchange::NSE,
+            "INFY".to_string(),
+            TransactionType::BUY,
+            0,
+        );
+        let err = client.convert_position_typed(request).await.unwrap_err();
+        assert!(matches!(err, KiteError::InputException(_)));
+    }
+
+    #[tokio::test]
+    async fn test_convert_positions_bulk_reports_each_result() {
+        let mut server = mockito::Server::new_async().await;
+        let ok = server
+            .mock("PUT", "/portfolio/positions")
+            .match_body(Matcher::UrlEncoded("tradingsymbol".into(), "INFY".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": true}"#)
+            .create_async()
+            .await;
+        let rejected = server
+            .mock("PUT", "/portfolio/positions")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("tradingsymbol".into(), "TCS".into()),
+                Matcher::UrlEncoded("position_type".into(), "overnight".into()),
+            ]))
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "error", "message": "Insufficient quantity", "error_type": "InputException"}"#,
+            )
+            .create_async()
+            .await;
+        let client = mock_client(server.url());
+
+        let bulk = BulkConversionRequest::new()
+            .add_conversion(ConversionRequest::mis_to_cnc(
+                Exchange::NSE,
+                "INFY".to_string(),
+                TransactionType::BUY,
+                5,
+            ))
+            .add_conversion(ConversionRequest::cnc_to_mis(
+                Exchange::NSE,
+                "TCS".to_string(),
+                TransactionType::SELL,
+                2,
+            ));
+
+        let response = client.convert_positions_bulk(&bulk).await.unwrap();
+        ok.assert_async().await;
+        rejected.assert_async().await;
+
+        assert_eq!(response.status, "partial");
+        assert_eq!(response.success_count(), 1);
+        let failed = response.failed_conversions();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].trading_symbol, "TCS");
+        assert!(failed[0]
+            .error_message()
+            .unwrap()
+            .contains("Insufficient quantity"));
+
+        // Nothing is sent when any conversion is invalid
+        let empty = BulkConversionRequest::new();
+        let err = client.convert_positions_bulk(&empty).await.unwrap_err();
+        assert!(matches!(err, KiteError::InputException(_)));
+    }
+}
+
+#[cfg(test)]
+mod trades_window_tests {
+    use super::mock_client;
+    use chrono::NaiveTime;
+
+    #[tokio::test]
+    async fn test_trades_between_filters_by_ist_fill_time() {
+        let mut server = mockito::Server::new_async().await;
+        // Fills at 09:30, 12:30 and 13:00 IST
+        let mock = server
+            .mock("GET", "/trades")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": [
+                    {"trade_id": "1", "order_id": "101", "exchange_order_id": "201",
+                     "tradingsymbol": "INFY", "exchange": "NSE", "instrument_token": 408065,
+                     "product": "MIS", "average_price": 1500.0, "quantity": 10,
+                     "fill_timestamp": "2024-01-15T04:00:00Z",
+                     "exchange_timestamp": "2024-01-15T04:00:00Z", "transaction_type": "BUY"},
+                    {"trade_id": "2", "order_id": "102", "exchange_order_id": "202",
+                     "tradingsymbol": "INFY", "exchange": "NSE", "instrument_token": 408065,
+                     "product": "MIS", "average_price": 1510.0, "quantity": 10,
+                     "fill_timestamp": "2024-01-15T07:00:00Z",
+                     "exchange_timestamp": "2024-01-15T07:00:00Z", "transaction_type": "SELL"},
+                    {"trade_id": "3", "order_id": "103", "exchange_order_id": "203",
+                     "tradingsymbol": "TCS", "exchange": "NSE", "instrument_token": 2953217,
+                     "product": "MIS", "average_price": 3500.0, "quantity": 5,
+                     "fill_timestamp": "2024-01-15T07:30:00Z",
+                     "exchange_timestamp": "2024-01-15T07:30:00Z", "transaction_type": "BUY"}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let morning = client
+            .trades_between(
+                NaiveTime::from_hms_opt(9, 15, 0).unwrap(),
+                NaiveTime::from_hms_opt(12, 30, 0).unwrap(),
+            )
+            .await
+            .unwrap();
+        mock.assert_async().await;
+
+        let ids: Vec<&str> = morning.iter().map(|t| t.trade_id.as_str()).collect();
+        assert_eq!(ids, vec!["1"]);
+    }
+}
+
+#[cfg(test)]
+mod order_lookup_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::models::common::KiteError;
+    use kiteconnect_async_wasm::models::orders::OrderStatus;
+    use serde_json::json;
+
+    fn order_entry(status: &str, filled_quantity: u32) -> serde_json::Value {
+        json!({
+            "account_id": "AB1234", "order_id": "151220000000000",
+            "status": status, "order_timestamp": "2024-01-15T04:00:00Z",
+            "tradingsymbol": "INFY", "exchange": "NSE", "instrument_token": 408065,
+            "order_type": "LIMIT", "transaction_type": "BUY", "validity": "DAY",
+            "product": "CNC", "quantity": 10, "disclosed_quantity": 0,
+            "price": 1500.0, "trigger_price": 0.0, "average_price": 1500.0,
+            "filled_quantity": filled_quantity, "pending_quantity": 10 - filled_quantity,
+            "cancelled_quantity": 0, "market_protection": 0.0, "guid": "abc"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_order_returns_latest_history_entry() {
+        let mut server = mockito::Server::new_async().await;
+        let body = json!({
+            "status": "success",
+            "data": [order_entry("OPEN", 0), order_entry("COMPLETE", 10)]
+        });
+        let mock = server
+            .mock("GET", "/orders/151220000000000")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+
+        let order = mock_client(server.url())
+            .order("151220000000000")
+            .await
+            .unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(order.status, OrderStatus::Complete);
+        assert_eq!(order.filled_quantity, 10);
+    }
+
+    #[tokio::test]
+    async fn test_order_without_history_is_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/orders/missing")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": []}"#)
+            .create_async()
+            .await;
+
+        let err = mock_client(server.url())
+            .order("missing")
+            .await
+            .unwrap_err();
+        mock.assert_async().await;
+
+        assert!(matches!(err, KiteError::OrderException(ref msg) if msg.contains("missing")));
+    }
+
+    #[tokio::test]
+    async fn test_order_history_typed_parses_kite_timestamps_and_transient_states() {
+        let mut server = mockito::Server::new_async().await;
+        let mut received = order_entry("PUT ORDER REQ RECEIVED", 0);
+        received["order_timestamp"] = json!("2023-11-01 09:15:04");
+        received["exchange_timestamp"] = json!(null);
+        let mut validating = order_entry("VALIDATION PENDING", 0);
+        validating["order_timestamp"] = json!("2023-11-01 09:15:04");
+        let mut open = order_entry("OPEN", 0);
+        open["order_timestamp"] = json!("2023-11-01 09:15:05");
+        open["exchange_timestamp"] = json!("2023-11-01 09:15:05");
+
+        let body = json!({"status": "success", "data": [received, validating, open]});
+        let mock = server
+            .mock("GET", "/orders/151220000000000")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+
+        let history = mock_client(server.url())
+            .order_history_typed("151220000000000")
+            .await
+            .unwrap();
+        mock.assert_async().await;
+
+        let statuses: Vec<_> = history.iter().map(|entry| entry.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                OrderStatus::PutOrderReqReceived,
+                OrderStatus::ValidationPending,
+                OrderStatus::Open
+            ]
+        );
+        assert!(history.iter().all(|entry| entry.status.is_active()));
+        assert_eq!(
+            history[0].order_timestamp.to_rfc3339(),
+            "2023-11-01T03:45:04+00:00"
+        );
+        assert!(history[0].exchange_timestamp.is_none());
+        assert!(history[2].exchange_timestamp.is_some());
+    }
+}
+
+#[cfg(test)]
+mod cancel_all_orders_tests {
+    use super::mock_client;
+    use mockito::Matcher;
+    use serde_json::json;
+
+    fn order(
+        order_id: &str,
+        status: &str,
+        variety: &str,
+        parent: Option<&str>,
+    ) -> serde_json::Value {
+        json!({
+            "account_id": "AB1234", "order_id": order_id, "parent_order_id": parent,
+            "status": status, "order_timestamp": "2024-01-15T04:00:00Z",
+            "tradingsymbol": "INFY", "exchange": "NSE", "instrument_token": 408065,
+            "order_type": "LIMIT", "transaction_type": "BUY", "validity": "DAY",
+            "product": "MIS", "quantity": 10, "disclosed_quantity": 0,
+            "price": 1500.0, "trigger_price": 0.0, "average_price": 0.0,
+            "filled_quantity": 0, "pending_quantity": 10,
+            "cancelled_quantity": 0, "market_protection": 0.0, "guid": "abc",
+            "variety": variety
+        })
+    }
+
+    fn cancelled(order_id: &str) -> String {
+        json!({"status": "success", "data": {"order_id": order_id}}).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders_handles_cover_order_legs() {
+        let mut server = mockito::Server::new_async().await;
+        let book = json!({"status": "success", "data": [
+            order("1", "OPEN", "regular", None),
+            order("2", "COMPLETE", "regular", None),
+            order("3", "OPEN", "co", None),
+            order("4", "TRIGGER PENDING", "co", Some("3")),
+            order("5", "TRIGGER PENDING", "co", Some("9")),
+            order("6", "OPEN", "regular", None)
+        ]});
+        let orders = server
+            .mock("GET", "/orders")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(book.to_string())
+            .create_async()
+            .await;
+
+        let regular = server
+            .mock("DELETE", "/orders/regular/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cancelled("1"))
+            .create_async()
+            .await;
+        let parent = server
+            .mock("DELETE", "/orders/co/3")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cancelled("3"))
+            .create_async()
+            .await;
+        // Removed together with its parent
+        let child_of_cancelled = server
+            .mock("DELETE", "/orders/co/4")
+            .expect(0)
+            .create_async()
+            .await;
+        let leg = server
+            .mock("DELETE", "/orders/co/5")
+            .match_body(Matcher::PartialJson(json!({"parent_order_id": "9"})))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cancelled("5"))
+            .create_async()
+            .await;
+        let rejected = server
+            .mock("DELETE", "/orders/regular/6")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "error", "message": "Order cannot be cancelled", "error_type": "OrderException"}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let results = client.cancel_all_orders().await.unwrap();
+        for mock in [orders, regular, parent, child_of_cancelled, leg, rejected] {
+            mock.assert_async().await;
+        }
+
+        let ids: Vec<_> = results.iter().map(|r| r.order_id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "3", "5", "6"]);
+        assert!(results[..3].iter().all(|r| r.is_success()));
+        assert!(results[3]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("Order cannot be cancelled"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_order_polls_until_final() {
+        use kiteconnect_async_wasm::models::common::KiteError;
+        use kiteconnect_async_wasm::models::orders::OrderStatus;
+        use std::time::Duration;
+
+        let mut server = mockito::Server::new_async().await;
+        let history = |status: &str| {
+            json!({"status": "success", "data": [
+                order("1", "PUT ORDER REQ RECEIVED", "regular", None),
+                order("1", status, "regular", None)
+            ]})
+            .to_string()
+        };
+        let open = server
+            .mock("GET", "/orders/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(history("OPEN"))
+            .expect(2)
+            .create_async()
+            .await;
+        let complete = server
+            .mock("GET", "/orders/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(history("COMPLETE"))
+            .create_async()
+            .await;
+        let pending = server
+            .mock("GET", "/orders/2")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(history("OPEN"))
+            .expect_at_least(2)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let order = client
+            .wait_for_order_with_interval("1", Duration::from_secs(5), Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert_eq!(order.status, OrderStatus::Complete);
+        open.assert_async().await;
+        complete.assert_async().await;
+
+        let err = client
+            .wait_for_order_with_interval("2", Duration::from_millis(50), Duration::from_millis(10))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, KiteError::OrderException(_)));
+        pending.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod mf_order_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::models::common::KiteError;
+    use kiteconnect_async_wasm::models::mutual_funds::MFOrderParams;
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn test_place_mf_order_typed_buy_by_amount() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/mf/orders")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("tradingsymbol".into(), "INF846K01DP8".into()),
+                Matcher::UrlEncoded("transaction_type".into(), "BUY".into()),
+                Matcher::UrlEncoded("amount".into(), "5000".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": {"order_id": "mf-123"}}"#)
+            .create_async()
+            .await;
+
+        let params = MFOrderParams::purchase("INF846K01DP8".to_string(), 5000.0);
+        let response = mock_client(server.url())
+            .place_mf_order_typed(&params)
+            .await
+            .unwrap();
+
+        assert_eq!(response.order_id, "mf-123");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_place_mf_order_typed_rejects_amount_and_quantity() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/mf/orders")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let mut params = MFOrderParams::purchase("INF846K01DP8".to_string(), 5000.0);
+        params.quantity = Some(10.0);
+
+        let err = mock_client(server.url())
+            .place_mf_order_typed(&params)
+            .await
+            .unwrap_err();
+
+        match err {
+            KiteError::InvalidParameter(message) => assert!(message.contains("mutually exclusive")),
+            other => panic!("expected InvalidParameter, got {:?}", other),
+        }
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_mf_orders_typed_parses_pending_orders() {
+        use kiteconnect_async_wasm::models::mutual_funds::MFOrderStatus;
+
+        let mut server = mockito::Server::new_async().await;
+        let list = server
+            .mock("GET", "/mf/orders")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"success","data":[
+                    {"status":"OPEN","purchase_type":"FRESH","placed_by":"DA0017","amount":5000,"quantity":0,"settlement_id":null,"order_timestamp":"2021-06-30 08:33:07","average_price":0,"transaction_type":"BUY","exchange_order_id":null,"exchange_timestamp":null,"fund":"Axis Banking & PSU Debt Fund - Direct Plan, Growth","variety":"regular","folio":null,"tradingsymbol":"INF846K01DP8","tag":null,"order_id":"271989e0-a64e-4cf3-b4e4-afb8f38dd203","status_message":"","last_price":0},
+                    {"status":"COMPLETE","purchase_type":"ADDITIONAL","placed_by":"DA0017","amount":2000,"quantity":3.402,"settlement_id":"2114","order_timestamp":"2021-06-28 10:01:52","average_price":587.84,"transaction_type":"BUY","exchange_order_id":"254657127","exchange_timestamp":"2021-06-29 00:00:00","fund":"Parag Parikh Flexi Cap Fund - Direct Plan","variety":"regular","folio":"12345/67","tradingsymbol":"INF879O01027","tag":null,"order_id":"f7f5f2b6-6ea6-4d0c-8a45-1f0b3c4e4f7e","status_message":"","last_price":590.11}
+                ]}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let single = server
+            .mock("GET", "/mf/orders/f7f5f2b6-6ea6-4d0c-8a45-1f0b3c4e4f7e")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"success","data":{"status":"COMPLETE","placed_by":"DA0017","amount":2000,"quantity":3.402,"order_timestamp":"2021-06-28 10:01:52","average_price":587.84,"transaction_type":"BUY","fund":"Parag Parikh Flexi Cap Fund - Direct Plan","tradingsymbol":"INF879O01027","order_id":"f7f5f2b6-6ea6-4d0c-8a45-1f0b3c4e4f7e"}}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+        let orders = client.mf_orders_typed(None).await.unwrap();
+        assert_eq!(orders.len(), 2);
+
+        let pending = &orders[0];
+        assert_eq!(pending.status, MFOrderStatus::Open);
+        assert!(pending.is_open());
+        assert_eq!(
+            pending.fund,
+            "Axis Banking & PSU Debt Fund - Direct Plan, Growth"
+        );
+        assert_eq!(pending.average_price, Some(0.0));
+        assert_eq!(pending.allotted_nav(), None);
+        assert_eq!(pending.units(), None);
+        assert_eq!(
+            pending.order_timestamp.to_rfc3339(),
+            "2021-06-30T03:03:07+00:00"
+        );
+
+        let complete = &orders[1];
+        assert!(complete.is_complete());
+        assert_eq!(complete.allotted_nav(), Some(587.84));
+        assert!(complete.exchange_timestamp.is_some());
+
+        let by_id = client
+            .mf_orders_typed(Some("f7f5f2b6-6ea6-4d0c-8a45-1f0b3c4e4f7e"))
+            .await
+            .unwrap();
+        assert_eq!(by_id.len(), 1);
+        assert_eq!(by_id[0].order_id, complete.order_id);
+
+        list.assert_async().await;
+        single.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_mf_sips_and_holdings_typed() {
+        use kiteconnect_async_wasm::models::mutual_funds::{SIPFrequency, SIPStatus};
+
+        let mut server = mockito::Server::new_async().await;
+        let sips = server
+            .mock("GET", "/mf/sips")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"success","data":[{"dividend_type":"growth","pending_instalments":10,"created":"2021-05-25 18:05:31","last_instalment":"2021-06-05 00:00:00","transaction_type":"BUY","frequency":"weekly","instalment_date":5,"fund":"Kotak Liquid Fund - Direct Plan","sip_id":"1234","tradingsymbol":"INF174K01N69","tag":"","instalment_amount":1000,"instalments":12,"status":"PAUSED","order_id":"4343","completed_instalments":2,"next_instalment":"2021-07-05","trigger_price":0,"step_up":{"15-02":10},"sip_reg_num":null}]}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let holdings = server
+            .mock("GET", "/mf/holdings")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"success","data":[{"folio":"123123/123","fund":"Kotak Select Focus Fund - Direct Plan","tradingsymbol":"INF174K01LS2","average_price":30.729,"last_price":33.014,"last_price_date":"2021-06-30","pnl":594.769,"quantity":260.337,"pledged_quantity":0}]}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+        let sips_data = client.mf_sips_typed(None).await.unwrap();
+        assert_eq!(sips_data.len(), 1);
+        assert_eq!(sips_data[0].frequency, SIPFrequency::Weekly);
+        assert_eq!(sips_data[0].status, SIPStatus::Paused);
+        assert_eq!(sips_data[0].total_instalments(), Some(12));
+        assert!(sips_data[0].has_step_up());
+
+        let holdings_data = client.mf_holdings_typed().await.unwrap();
+        assert_eq!(holdings_data[0].folio, "123123/123");
+        assert_eq!(holdings_data[0].quantity, 260.337);
+
+        sips.assert_async().await;
+        holdings.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_place_and_modify_sip_typed() {
+        use kiteconnect_async_wasm::models::mutual_funds::{
+            SIPModifyParams, SIPParams, SIPStatus, SIPStepUp,
+        };
+
+        let mut server = mockito::Server::new_async().await;
+        let place = server
+            .mock("POST", "/mf/sips")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("tradingsymbol".into(), "INF174K01N69".into()),
+                Matcher::UrlEncoded("amount".into(), "1000".into()),
+                Matcher::UrlEncoded("frequency".into(), "monthly".into()),
+                Matcher::UrlEncoded("instalments".into(), "12".into()),
+                Matcher::UrlEncoded("instalment_day".into(), "15".into()),
+                Matcher::UrlEncoded("step_up".into(), "15-02:10".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"success","data":{"sip_id":"1234"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let modify = server
+            .mock("PUT", "/mf/sips/1234")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("amount".into(), "1500".into()),
+                Matcher::UrlEncoded("status".into(), "paused".into()),
+                Matcher::UrlEncoded("instalment_day".into(), "20".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status":"success","data":{"sip_id":"1234"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+        let params = SIPParams::monthly("INF174K01N69".to_string(), 1000.0)
+            .instalments(12)
+            .instalment_day(15)
+            .step_up(SIPStepUp::new("15-02", 10.0));
+        let response = client.place_mf_sip_typed(&params).await.unwrap();
+        assert_eq!(response.sip_id, "1234");
+
+        let changes = SIPModifyParams::new("1234")
+            .amount(1500.0)
+            .instalment_day(20)
+            .status(SIPStatus::Paused);
+        let response = client.modify_mf_sip_typed(&changes).await.unwrap();
+        assert_eq!(response.sip_id, "1234");
+
+        // Out-of-range instalment days are rejected before sending
+        let err = client
+            .place_mf_sip_typed(&params.clone().instalment_day(31))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, KiteError::InvalidParameter(_)),
+            "got {:?}",
+            err
+        );
+
+        place.assert_async().await;
+        modify.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod shutdown_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::models::common::KiteError;
+
+    #[tokio::test]
+    async fn test_calls_fail_after_shutdown() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+        let clone = client.clone();
+        let refresher =
+            client.spawn_token_refresher("refresh_token".to_string(), "secret".to_string());
+
+        client.shutdown().await;
+        assert!(clone.is_shut_down());
+        assert!(refresher.await.unwrap_err().is_cancelled());
+
+        let err = clone.holdings_typed().await.unwrap_err();
+        assert!(matches!(err, KiteError::Shutdown), "got {:?}", err);
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod ticker_stream_tests {
+    use futures_util::{SinkExt, StreamExt};
+    use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig, TickerConfig};
+    use kiteconnect_async_wasm::models::market_data::TickMode;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::WebSocketStream;
+
+    /// Accept a connection and answer the WebSocket handshake, returning the request URI
+    // The handshake callback's error type is tungstenite's, not ours
+    #[allow(clippy::result_large_err)]
+    async fn accept(listener: &TcpListener) -> (WebSocketStream<TcpStream>, String) {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut uri = String::new();
+        let socket =
+            tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response: Response| {
+                uri = request.uri().to_string();
+                Ok(response)
+            })
+            .await
+            .unwrap();
+        (socket, uri)
+    }
+
+    /// Read one text message sent by the client
+    async fn read_text(socket: &mut WebSocketStream<TcpStream>) -> String {
+        match socket.next().await.unwrap().unwrap() {
+            Message::Text(text) => text.to_string(),
+            other => panic!("expected a text message, got {:?}", other),
+        }
+    }
+
+    /// Send a binary message holding one tick packet
+    async fn send_packet(socket: &mut WebSocketStream<TcpStream>, packet: &[u8]) {
+        let mut frame = 1u16.to_be_bytes().to_vec();
+        frame.extend((packet.len() as u16).to_be_bytes());
+        frame.extend_from_slice(packet);
+        socket.send(Message::binary(frame)).await.unwrap();
+    }
+
+    /// Send a binary message holding one LTP packet
+    async fn send_ltp(socket: &mut WebSocketStream<TcpStream>, token: u32, price_paise: u32) {
+        let mut packet = token.to_be_bytes().to_vec();
+        packet.extend(price_paise.to_be_bytes());
+        send_packet(socket, &packet).await;
+    }
+
+    fn ticker_client(addr: std::net::SocketAddr, ticker_config: TickerConfig) -> KiteConnect {
+        let config = KiteConnectConfig {
+            ticker_url: format!("ws://{}", addr),
+            ticker_config,
+            ..Default::default()
+        };
+        let mut client = KiteConnect::new_with_config("test_key", config);
+        client.set_access_token("test_token");
+        client
+    }
+
+    #[tokio::test]
+    async fn test_ticker_rejects_invalid_accept_key() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(stream.read_u8().await.unwrap());
+            }
+            stream
+                .write_all(
+                    b"HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\n\
+                      Connection: Upgrade\r\nSec-WebSocket-Accept: test\r\n\r\n",
+                )
+                .await
+                .unwrap();
+            let _ = stream.read_u8().await;
+        });
+
+        let client = ticker_client(addr, TickerConfig::default());
+        assert!(client.ticker().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ticker_echoes_server_close() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = accept(&listener).await;
+            socket.send(Message::Close(None)).await.unwrap();
+            socket.next().await
+        });
+
+        let ticker_config = TickerConfig {
+            reconnect: false,
+            ..Default::default()
+        };
+        let mut ticker = ticker_client(addr, ticker_config).ticker().await.unwrap();
+
+        let reply = tokio::time::timeout(Duration::from_secs(5), server)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            matches!(reply, Some(Ok(Message::Close(_)))),
+            "got {:?}",
+            reply
+        );
+        // Without reconnection the stream ends with the connection
+        assert!(ticker.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ticker_reconnects_with_current_access_token() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (renewed, token_renewed) = tokio::sync::oneshot::channel::<()>();
+
+        let server = tokio::spawn(async move {
+            let (first, first_uri) = accept(&listener).await;
+            token_renewed.await.unwrap();
+            drop(first);
+
+            let (_second, second_uri) = accept(&listener).await;
+            (first_uri, second_uri)
+        });
+
+        let mut ticker_config = TickerConfig::default();
+        ticker_config.reconnect_backoff.base_delay = Duration::from_millis(10);
+        let mut client = ticker_client(addr, ticker_config);
+        let ticker = client.ticker().await.unwrap();
+
+        client.set_access_token("renewed_token");
+        renewed.send(()).unwrap();
+
+        let (first_uri, second_uri) = tokio::time::timeout(Duration::from_secs(5), server)
+            .await
+            .unwrap()
+            .unwrap();
+        drop(ticker);
+        assert!(first_uri.contains("access_token=test_token"));
+        assert!(second_uri.contains("access_token=renewed_token"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_streams_ticks_and_resubscribes_after_drop() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut first, request_uri) = accept(&listener).await;
+            let subscribed = vec![read_text(&mut first).await, read_text(&mut first).await];
+            send_ltp(&mut first, 408065, 150050).await;
+            // Simulate a dropped connection
+            drop(first);
+
+            let (mut second, _) = accept(&listener).await;
+            let resubscribed = vec![read_text(&mut second).await, read_text(&mut second).await];
+            send_ltp(&mut second, 408065, 150100).await;
+            // Hold the connection open until the client goes away
+            let _ = second.next().await;
+            (request_uri, subscribed, resubscribed)
+        });
+
+        let mut ticker_config = TickerConfig::default();
+        ticker_config.reconnect_backoff.base_delay = Duration::from_millis(10);
+        let client = ticker_client(addr, ticker_config);
+
+        let ticks = client.subscribe(vec![408065], TickMode::Ltp).await.unwrap();
+        futures_util::pin_mut!(ticks);
+
+        let first = ticks.next().await.unwrap();
+        assert_eq!(first.instrument_token, 408065);
+        assert_eq!(first.last_price, 1500.5);
+        let second = tokio::time::timeout(Duration::from_secs(5), ticks.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(second.last_price, 1501.0);
+
+        client.shutdown().await;
+        let (request_uri, subscribed, resubscribed) = server.await.unwrap();
+        assert!(request_uri.contains("api_key=test_key"));
+        assert!(request_uri.contains("access_token=test_token"));
+        assert_eq!(
+            subscribed,
+            vec![
+                r#"{"a":"subscribe","v":[408065]}"#,
+                r#"{"a":"mode","v":["ltp",[408065]]}"#
+            ]
+        );
+        assert_eq!(resubscribed, subscribed);
+    }
+
+    #[tokio::test]
+    async fn test_kite_ticker_subscribe_and_set_mode() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = accept(&listener).await;
+            let mut messages = Vec::new();
+            for _ in 0..3 {
+                messages.push(read_text(&mut socket).await);
+            }
+
+            // Full packet: quote fields, timestamps and OI, then 5 bids and 5 asks
+            let mut packet = Vec::new();
+            for value in [
+                408065u32, 150050, 10, 149900, 5000, 1200, 1300, 149000, 151000,
+            ] {
+                packet.extend(value.to_be_bytes());
+            }
+            for value in [148500u32, 149500, 1700000000, 0, 0, 0, 1700000001] {
+                packet.extend(value.to_be_bytes());
+            }
+            for level in 0..10u32 {
+                packet.extend((100 + level).to_be_bytes());
+                packet.extend((150000 + level * 5).to_be_bytes());
+                packet.extend(((level + 1) as u16).to_be_bytes());
+                packet.extend([0, 0]);
+            }
+            send_packet(&mut socket, &packet).await;
+
+            let _ = socket.next().await;
+            messages
+        });
+
+        let client = ticker_client(addr, TickerConfig::default());
+
+        let mut ticker = client.ticker().await.unwrap();
+        ticker.subscribe(&[408065, 738561]).unwrap();
+        ticker.set_mode(TickMode::Full, &[408065]).unwrap();
+        assert_eq!(ticker.subscriptions().mode(408065), Some(TickMode::Full));
+        assert_eq!(ticker.subscriptions().mode(738561), Some(TickMode::Quote));
+
+        let tick = tokio::time::timeout(Duration::from_secs(5), ticker.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(tick.mode, TickMode::Full);
+        assert_eq!(tick.last_price, 1500.5);
+        let depth = tick.depth.unwrap();
+        assert_eq!(depth.buy.len(), 5);
+        assert_eq!(depth.sell.len(), 5);
+        assert_eq!(depth.buy[0].price, 1500.0);
+        assert_eq!(depth.sell[4].quantity, 109);
+        assert_eq!(depth.sell[4].orders, 10);
+
+        drop(ticker);
+        let messages = server.await.unwrap();
+        assert_eq!(
+            messages,
+            vec![
+                r#"{"a":"subscribe","v":[408065,738561]}"#,
+                r#"{"a":"mode","v":["quote",[408065,738561]]}"#,
+                r#"{"a":"mode","v":["full",[408065]]}"#,
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod margins_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::models::auth::TradingSegment;
+
+    fn segment_json(cash: f64, net: f64) -> String {
+        format!(
+            r#"{{"enabled": true, "net": {net},
+                "available": {{"cash": {cash}, "opening_balance": {cash}, "live_balance": {cash},
+                    "adhoc_margin": 0, "collateral": 0, "intraday_payin": 0}},
+                "utilised": {{"debits": 0, "exposure": 0, "m2m_unrealised": 0, "m2m_realised": 0,
+                    "option_premium": 0, "payout": 0, "span": 0, "holding_sales": 0,
+                    "turnover": 0, "liquid": 0, "stock_collateral": 0}}}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_all_margins_keyed_by_segment() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/user/margins")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"status": "success", "data": {{"equity": {}, "commodity": {}}}}}"#,
+                segment_json(50000.0, 48000.0),
+                segment_json(10000.0, 9500.0)
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let margins = client.all_margins().await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(margins.len(), 2);
+        assert_eq!(margins[&TradingSegment::Equity].net, 48000.0);
+        assert_eq!(margins[&TradingSegment::Commodity].available.cash, 10000.0);
+    }
+
+    #[tokio::test]
+    async fn test_segment_margins_typed_for_equity() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/user/margins/equity")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"status": "success", "data": {}}}"#,
+                segment_json(50000.0, 48000.0)
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let equity = client
+            .segment_margins_typed(TradingSegment::Equity)
+            .await
+            .unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(equity.net, 48000.0);
+        assert_eq!(equity.available.cash, 50000.0);
+        assert_eq!(equity.utilised.debits, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_margins_typed_combined_and_by_segment() {
+        let mut server = mockito::Server::new_async().await;
+        let equity = segment_json(50000.0, 48000.0)
+            .replace(r#""live_balance": 50000"#, r#""live_balance": 47250.5"#)
+            .replace(r#""debits": 0"#, r#""debits": 2749.5"#);
+        let all = server
+            .mock("GET", "/user/margins")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"status": "success", "data": {{"equity": {}, "commodity": {}}}}}"#,
+                equity,
+                segment_json(10000.0, 9500.0)
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+        let commodity = server
+            .mock("GET", "/user/margins/commodity")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"status": "success", "data": {}}}"#,
+                segment_json(10000.0, 9500.0)
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let margins = client.margins_typed(None).await.unwrap();
+        let equity = margins.equity.as_ref().unwrap();
+        assert_eq!(equity.available.live_balance, 47250.5);
+        assert_eq!(equity.utilised.debits, 2749.5);
+        assert_eq!(margins.commodity.as_ref().unwrap().net, 9500.0);
+
+        // Serialized margins parse back to the same values
+        let json = serde_json::to_string(&margins).unwrap();
+        let parsed: kiteconnect_async_wasm::models::auth::MarginData =
+            serde_json::from_str(&json).unwrap();
+        let parsed_equity = parsed.equity.unwrap();
+        assert_eq!(parsed_equity.available.live_balance, 47250.5);
+        assert_eq!(parsed_equity.utilised.debits, 2749.5);
+
+        let only_commodity = client
+            .margins_typed(Some(TradingSegment::Commodity))
+            .await
+            .unwrap();
+        assert!(only_commodity.equity.is_none());
+        assert_eq!(only_commodity.commodity.unwrap().available.cash, 10000.0);
+
+        all.assert_async().await;
+        commodity.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod historical_adjustment_tests {
+    use super::mock_client;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use kiteconnect_async_wasm::connect::KiteConnect;
+    use kiteconnect_async_wasm::models::common::Interval;
+    use kiteconnect_async_wasm::models::market_data::{CorporateActions, HistoricalDataRequest};
+
+    #[tokio::test]
+    async fn test_historical_data_typed_applies_split_adjustment() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments/historical/738561/day")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {"candles": [
+                    ["2023-06-14T00:00:00+0530", 2000.0, 2100.0, 1980.0, 2050.0, 1000],
+                    ["2023-06-15T00:00:00+0530", 1030.0, 1040.0, 1010.0, 1020.0, 2500]
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let request = HistoricalDataRequest::new(
+            738561,
+            NaiveDateTime::parse_from_str("2023-06-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            NaiveDateTime::parse_from_str("2023-06-30 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Interval::Day,
+        )
+        .adjust_for_corporate_actions(CorporateActions::new().split(
+            NaiveDate::from_ymd_opt(2023, 6, 15).unwrap(),
+            1,
+            2,
+        ));
+
+        let data = client.historical_data_typed(request).await.unwrap();
+
+        assert_eq!(data.candles[0].close, 1025.0);
+        assert_eq!(data.candles[0].volume, 2000);
+        assert_eq!(data.candles[1].close, 1020.0);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_historical_data_typed_parses_open_interest() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments/historical/12517890/minute")
+            .match_query(mockito::Matcher::UrlEncoded("oi".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {"candles": [
+                    ["2024-01-02T09:15:00+0530", 21800.0, 21810.0, 21790.0, 21805.0, 15000, 10200000],
+                    ["2024-01-02T09:16:00+0530", 21805.0, 21820.0, 21800.0, 21815.0, 9000, 10215000]
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let request = HistoricalDataRequest::new(
+            12517890,
+            NaiveDateTime::parse_from_str("2024-01-02 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            NaiveDateTime::parse_from_str("2024-01-02 09:17:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Interval::Minute,
+        )
+        .with_oi(true);
+        let data = client.historical_data_typed(request).await.unwrap();
+
+        assert_eq!(data.candles[0].volume, 15000);
+        assert_eq!(data.candles[0].oi(), Some(10200000));
+        assert_eq!(data.candles[1].oi(), Some(10215000));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_historical_endpoint_override_uses_mirror() {
+        use kiteconnect_async_wasm::connect::RateLimitCategory;
+
+        let mut production = mockito::Server::new_async().await;
+        let mut mirror = mockito::Server::new_async().await;
+        let candles = mirror
+            .mock("GET", "/instruments/historical/738561/day")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {"candles": [
+                    ["2023-06-14T00:00:00+0530", 2000.0, 2100.0, 1980.0, 2050.0, 1000]
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+        let holdings = production
+            .mock("GET", "/portfolio/holdings")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": []}"#)
+            .create_async()
+            .await;
+
+        let client = KiteConnect::builder()
+            .api_key("test_key")
+            .base_url(production.url())
+            .endpoint_override(RateLimitCategory::Historical, mirror.url())
+            .rate_limiting(false)
+            .build()
+            .unwrap();
+
+        let request = HistoricalDataRequest::new(
+            738561,
+            NaiveDateTime::parse_from_str("2023-06-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            NaiveDateTime::parse_from_str("2023-06-30 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Interval::Day,
+        );
+        let data = client.historical_data_typed(request).await.unwrap();
+        assert_eq!(data.candles.len(), 1);
+        assert!(client.holdings_typed().await.unwrap().is_empty());
+
+        candles.assert_async().await;
+        holdings.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod historical_concurrency_tests {
+    use super::{mock_client, mock_config};
+    use chrono::NaiveDateTime;
+    use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig, RateLimitCategory};
+    use kiteconnect_async_wasm::models::common::{Interval, KiteError};
+    use kiteconnect_async_wasm::models::market_data::HistoricalDataRequest;
+    use std::time::Duration;
+
+    fn candles_body(dates: &[&str]) -> String {
+        let candles: Vec<String> = dates
+            .iter()
+            .map(|d| format!(r#"["{}", 100.0, 101.0, 99.0, 100.5, 1000]"#, d))
+            .collect();
+        format!(
+            r#"{{"status": "success", "data": {{"candles": [{}]}}}}"#,
+            candles.join(",")
+        )
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_chunks_are_reassembled_in_order() {
+        let mut server = mockito::Server::new_async().await;
+
+        // Three 60-day minute chunks, each answering with its candles newest-first.
+        // Chunks sent earlier answer more slowly, so they complete in reverse order.
+        let chunks = [
+            (
+                "2024-03-26 15:30:00",
+                vec!["2024-05-24T10:00:00+0530", "2024-03-27T10:00:00+0530"],
+                300,
+            ),
+            (
+                "2024-01-26 15:29:00",
+                vec!["2024-03-22T10:00:00+0530", "2024-01-29T10:00:00+0530"],
+                150,
+            ),
+            (
+                "2024-01-01 09:15:00",
+                vec!["2024-01-25T10:00:00+0530", "2024-01-02T10:00:00+0530"],
+                0,
+            ),
+        ];
+
+        let mut mocks = Vec::new();
+        for (from, dates, delay_ms) in &chunks {
+            let body = candles_body(dates);
+            let delay = Duration::from_millis(*delay_ms);
+            mocks.push(
+                server
+                    .mock("GET", "/instruments/historical/738561/minute")
+                    .match_query(mockito::Matcher::UrlEncoded(
+                        "from".into(),
+                        from.to_string(),
+                    ))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_chunked_body(move |w| {
+                        std::thread::sleep(delay);
+                        w.write_all(body.as_bytes())
+                    })
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let client = mock_client(server.url());
+
+        let request = HistoricalDataRequest::new(
+            738561,
+            NaiveDateTime::parse_from_str("2024-01-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            NaiveDateTime::parse_from_str("2024-05-25 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Interval::Minute,
+        );
+        assert_eq!(request.split_into_valid_requests_reverse().len(), 3);
+
+        let data = client
+            .historical_data_chunked_concurrent(request, 3, false)
+            .await
+            .unwrap();
+
+        assert_eq!(data.candles.len(), 6);
+        assert_eq!(data.metadata.count, 6);
+        assert!(data.candles.windows(2).all(|w| w[0].date < w[1].date));
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_historical_data_typed_chunks_long_ranges() {
+        let mut server = mockito::Server::new_async().await;
+
+        // 60-day minute chunks, oldest first; the first two share a boundary candle
+        let chunks = [
+            (
+                "2024-01-01 09:15:00",
+                vec!["2024-01-02T10:00:00+0530", "2024-03-01T09:15:00+0530"],
+            ),
+            (
+                "2024-03-01 09:16:00",
+                vec!["2024-03-01T09:15:00+0530", "2024-04-22T10:00:00+0530"],
+            ),
+            ("2024-04-30 09:17:00", vec!["2024-05-02T10:00:00+0530"]),
+        ];
+
+        let mut mocks = Vec::new();
+        for (from, dates) in &chunks {
+            mocks.push(
+                server
+                    .mock("GET", "/instruments/historical/738561/minute")
+                    .match_query(mockito::Matcher::UrlEncoded(
+                        "from".into(),
+                        from.to_string(),
+                    ))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(candles_body(dates))
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let client = mock_client(server.url());
+
+        let request = HistoricalDataRequest::new(
+            738561,
+            NaiveDateTime::parse_from_str("2024-01-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            NaiveDateTime::parse_from_str("2024-05-05 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Interval::Minute,
+        );
+
+        let data = client.historical_data_typed(request).await.unwrap();
+
+        assert_eq!(data.candles.len(), 4);
+        assert_eq!(data.metadata.count, 4);
+        assert!(data.candles.windows(2).all(|w| w[0].date < w[1].date));
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_historical_data_typed_respects_max_chunks() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = KiteConnectConfig {
+            historical_max_chunks: 2,
+            ..mock_config(server.url())
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+
+        let request = HistoricalDataRequest::new(
+            738561,
+            NaiveDateTime::parse_from_str("2024-01-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            NaiveDateTime::parse_from_str("2024-05-05 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Interval::Minute,
+        );
+
+        let err = client.historical_data_typed(request).await.unwrap_err();
+        assert!(matches!(err, KiteError::InputException(_)));
+        assert!(err.to_string().contains("historical_max_chunks"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_out_of_range_historical_limits_are_rejected() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = KiteConnectConfig {
+            historical_max_chunks: 0,
+            ..mock_config(server.url())
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+
+        let from =
+            NaiveDateTime::parse_from_str("2024-01-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let to = NaiveDateTime::parse_from_str("2024-01-31 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let request = HistoricalDataRequest::new(738561, from, to, Interval::Day);
+
+        let err = client
+            .historical_data_typed(request.clone())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, KiteError::InputException(ref msg) if msg.contains("historical_max_chunks"))
+        );
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_historical_data_batch_keeps_partial_failures() {
+        let mut server = mockito::Server::new_async().await;
+        let ok = server
+            .mock("GET", "/instruments/historical/738561/day")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(candles_body(&["2024-01-02T00:00:00+0530"]))
+            .expect(2)
+            .create_async()
+            .await;
+        let failing = server
+            .mock("GET", "/instruments/historical/408065/day")
+            .match_query(mockito::Matcher::Any)
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "error", "message": "Invalid token", "error_type": "InputException"}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let from =
+            NaiveDateTime::parse_from_str("2024-01-01 09:15:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let to = NaiveDateTime::parse_from_str("2024-01-31 15:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let requests = [738561, 408065, 738561]
+            .into_iter()
+            .map(|token| HistoricalDataRequest::new(token, from, to, Interval::Day))
+            .collect();
+
+        // Concurrency above MAX_HISTORICAL_CONCURRENCY is clamped, not rejected
+        let results = client.historical_data_batch(requests, 10).await;
+
+        let tokens: Vec<u32> = results.iter().map(|(token, _)| *token).collect();
+        assert_eq!(tokens, vec![738561, 408065, 738561]);
+        assert_eq!(results[0].1.as_ref().unwrap().candles.len(), 1);
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_ok());
+        ok.assert_async().await;
+        failing.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_historical_data_typed_counts_against_historical_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments/historical/738561/day")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(candles_body(&["2024-01-02T00:00:00+0530"]))
+            .create_async()
+            .await;
+
+        let config = KiteConnectConfig {
+            base_url: server.url(),
+            enable_rate_limiting: true,
+            ..Default::default()
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+
+        let request = HistoricalDataRequest::new(
+            738561,
+            NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            NaiveDateTime::parse_from_str("2024-01-05 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            Interval::Day,
+        );
+        client.historical_data_typed(request).await.unwrap();
+        mock.assert_async().await;
+
+        let stats = client.rate_limiter_stats().await;
+        let count = |category| stats.categories[&category].request_count;
+        assert_eq!(count(RateLimitCategory::Historical), 1);
+        assert_eq!(count(RateLimitCategory::Quote), 0);
+        assert_eq!(count(RateLimitCategory::Standard), 0);
+    }
+}
+
+#[cfg(test)]
+mod profile_tests {
+    use super::mock_client;
+
+    #[tokio::test]
+    async fn test_profile_typed_without_demat_consent() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/user/profile")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {
+                    "user_id": "AB1234", "user_name": "Test User", "user_shortname": "Test",
+                    "user_type": "individual", "email": "test@example.com", "avatar_url": null,
+                    "broker": "ZERODHA", "exchanges": ["NSE", "BSE"], "products": ["CNC", "MIS"],
+                    "order_types": ["MARKET", "LIMIT"], "meta": {"demat_consent": null}}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let profile = client.profile_typed().await.unwrap();
+        assert_eq!(profile.user_id, "AB1234");
+        assert!(profile.has_exchange("BSE"));
+        assert_eq!(profile.meta.unwrap().demat_consent, "");
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod order_margins_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::models::common::{Exchange, OrderType, Product, TransactionType};
+    use kiteconnect_async_wasm::models::orders::OrderMarginParamBuilder;
+    use mockito::Matcher;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_order_margins_posts_json_basket() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/margins/orders")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(json!([
+                {"exchange": "NSE", "tradingsymbol": "INFY", "transaction_type": "BUY",
+                 "variety": "regular", "product": "CNC", "order_type": "LIMIT",
+                 "quantity": 10, "price": 1500.0},
+                {"exchange": "NFO", "tradingsymbol": "NIFTY24JANFUT", "transaction_type": "SELL",
+                 "variety": "regular", "product": "NRML", "order_type": "MARKET",
+                 "quantity": 50}
+            ])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": [
+                    {"type": "equity", "tradingsymbol": "INFY", "exchange": "NSE",
+                     "span": 0, "exposure": 0, "option_premium": 0, "additional": 0,
+                     "bo": 0, "cash": 0, "var": 15000, "pnl": {"realised": 0, "unrealised": 0},
+                     "leverage": 1, "total": 15000},
+                    {"type": "equity", "tradingsymbol": "NIFTY24JANFUT", "exchange": "NFO",
+                     "span": 98000.5, "exposure": 24000.25, "option_premium": 0,
+                     "additional": 0, "bo": 0, "cash": 0, "var": 0,
+                     "pnl": {"realised": 0, "unrealised": 0}, "leverage": 1,
+                     "total": 122000.75}
+                ]}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let orders = vec![
+            OrderMarginParamBuilder::new()
+                .trading_symbol("INFY")
+                .quantity(10)
+                .price(1500.0)
+                .build()
+                .unwrap(),
+            OrderMarginParamBuilder::new()
+                .exchange(Exchange::NFO)
+                .trading_symbol("NIFTY24JANFUT")
+                .transaction_type(TransactionType::SELL)
+                .product(Product::NRML)
+                .order_type(OrderType::MARKET)
+                .quantity(50)
+                .build()
+                .unwrap(),
+        ];
+
+        let margins = client.order_margins(orders).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(margins.len(), 2);
+        assert_eq!(margins[0].total, 15000.0);
+        assert_eq!(margins[1].span, 98000.5);
+        assert_eq!(margins[1].exposure, 24000.25);
+        assert_eq!(margins[1].span_and_exposure(), 122000.75);
+    }
+
+    #[tokio::test]
+    async fn test_order_margins_renews_session_through_async_hook() {
+        use std::sync::Arc;
+
+        let mut server = mockito::Server::new_async().await;
+        let expired = server
+            .mock("POST", "/margins/orders")
+            .match_header("authorization", "token test_key:stale_token")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status":"error","message":"Incorrect `api_key` or `access_token`.","error_type":"TokenException"}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let renewed = server
+            .mock("POST", "/margins/orders")
+            .match_header("authorization", "token test_key:fresh_token")
+            .match_header("content-type", "application/json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": []}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut client = mock_client(server.url());
+        client.set_access_token("stale_token");
+        client.set_async_session_hook(Arc::new(|| {
+            Box::pin(async { Some("fresh_token".to_string()) })
+        }));
+
+        let order = OrderMarginParamBuilder::new()
+            .trading_symbol("INFY")
+            .quantity(10)
+            .order_type(OrderType::MARKET)
+            .build()
+            .unwrap();
+        let margins = client.order_margins(vec![order]).await.unwrap();
+        assert!(margins.is_empty());
+        expired.assert_async().await;
+        renewed.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_basket_margins_considers_positions() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/margins/basket")
+            .match_query(Matcher::UrlEncoded(
+                "consider_positions".into(),
+                "true".into(),
+            ))
+            .match_header("content-type", "application/json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {
+                    "initial": {"type": "", "tradingsymbol": "", "exchange": "",
+                        "span": 150000, "exposure": 40000, "option_premium": 5000,
+                        "total": 195000},
+                    "final": {"type": "", "tradingsymbol": "", "exchange": "",
+                        "span": 30000, "exposure": 10000, "option_premium": 5000,
+                        "total": 45000},
+                    "orders": [
+                        {"type": "equity", "tradingsymbol": "NIFTY24JAN21000CE",
+                         "exchange": "NFO", "option_premium": 5000, "total": 5000},
+                        {"type": "equity", "tradingsymbol": "NIFTY24JAN21200CE",
+                         "exchange": "NFO", "span": 150000, "exposure": 40000,
+                         "total": 190000}
+                    ]}}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let leg = |symbol: &str, side| {
+            OrderMarginParamBuilder::new()
+                .exchange(Exchange::NFO)
+                .trading_symbol(symbol)
+                .transaction_type(side)
+                .product(Product::NRML)
+                .order_type(OrderType::MARKET)
+                .quantity(50)
+                .build()
+                .unwrap()
+        };
+        let spread = vec![
+            leg("NIFTY24JAN21000CE", TransactionType::BUY),
+            leg("NIFTY24JAN21200CE", TransactionType::SELL),
+        ];
+
+        let basket = client.basket_margins(spread, true).await.unwrap();
+        mock.assert_async().await;
+
+        assert_eq!(basket.initial.total, 195000.0);
+        assert_eq!(basket.final_margin.total, 45000.0);
+        assert_eq!(basket.hedge_benefit(), 150000.0);
+        assert_eq!(basket.leg_totals(), vec![5000.0, 190000.0]);
+    }
+}
+
+#[cfg(test)]
+mod gtt_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::models::common::{
+        Exchange, GttStatus, KiteError, OrderType, Product, TransactionType,
+    };
+    use kiteconnect_async_wasm::models::gtt::{
+        GTTCondition, GTTCreateParams, GTTModifyParams, GTTOrderParams,
+    };
+    use mockito::Matcher;
+
+    fn order(order_type: OrderType, price: f64) -> GTTOrderParams {
+        GTTOrderParams {
+            exchange: Exchange::NSE,
+            trading_symbol: "RELIANCE".to_string(),
+            transaction_type: TransactionType::SELL,
+            order_type,
+            product: Product::CNC,
+            quantity: 10,
+            price,
+            result: None,
+        }
+    }
+
+    fn condition(trigger_values: Vec<f64>) -> GTTCondition {
+        GTTCondition {
+            exchange: Exchange::NSE,
+            trading_symbol: "RELIANCE".to_string(),
+            trigger_values,
+            last_price: 2100.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_place_two_leg_gtt_sends_json_form_fields() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/gtt/triggers")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".into(), "two-leg".into()),
+                Matcher::UrlEncoded(
+                    "condition".into(),
+                    r#"{"exchange":"NSE","tradingsymbol":"RELIANCE","trigger_values":[2000.0,2200.0],"last_price":2100.0}"#.into(),
+                ),
+                Matcher::Regex("orders=".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": {"trigger_id": 123}}"#)
+            .create_async()
+            .await;
+
+        let params = GTTCreateParams::two_leg(
+            condition(vec![2000.0, 2200.0]),
+            vec![
+                order(OrderType::LIMIT, 1990.0),
+                order(OrderType::LIMIT, 2200.0),
+            ],
+        );
+        let response = mock_client(server.url())
+            .place_gtt_typed(params)
+            .await
+            .unwrap();
+        assert_eq!(response.id, 123);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_place_gtt_rejects_mismatched_legs() {
+        let server = mockito::Server::new_async().await;
+        let params =
+            GTTCreateParams::two_leg(condition(vec![2000.0]), vec![order(OrderType::MARKET, 0.0)]);
+
+        let err = mock_client(server.url())
+            .place_gtt_typed(params)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, KiteError::InputException(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_gtts_typed_parses_kite_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/gtt/triggers")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": [{
+                    "id": 112127, "user_id": "XX0000", "parent_trigger": null,
+                    "type": "single", "created_at": "2019-09-12 13:25:16",
+                    "updated_at": "2019-09-12 13:25:16", "expires_at": "2020-09-12 13:25:16",
+                    "status": "triggered",
+                    "condition": {"exchange": "NSE", "last_price": 798, "tradingsymbol": "INFY",
+                                  "trigger_values": [702], "instrument_token": 408065},
+                    "orders": [{"exchange": "NSE", "tradingsymbol": "INFY", "product": "CNC",
+                                "order_type": "LIMIT", "transaction_type": "BUY",
+                                "quantity": 1, "price": 702.5,
+                                "result": {"account_id": "XX0000", "order_type": "LIMIT",
+                                           "order_result": {"order_id": "190912000253131",
+                                                            "rejection_reason": "",
+                                                            "status": "success"}}}],
+                    "meta": null
+                }]}"#,
+            )
+            .create_async()
+            .await;
+
+        let gtts = mock_client(server.url()).get_gtts_typed().await.unwrap();
+        assert_eq!(gtts.len(), 1);
+        let gtt = &gtts[0];
+        assert_eq!(gtt.status, GttStatus::Triggered);
+        assert_eq!(gtt.created_at.to_rfc3339(), "2019-09-12T07:55:16+00:00");
+        assert!(gtt.expires_at.is_some());
+        assert_eq!(gtt.successful_order_ids(), vec!["190912000253131"]);
+        assert!(gtt.failed_orders().is_empty());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_modify_gtt_resends_full_trigger() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/gtt/triggers/123")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".into(), "two-leg".into()),
+                Matcher::UrlEncoded(
+                    "condition".into(),
+                    r#"{"exchange":"NSE","tradingsymbol":"RELIANCE","trigger_values":[2050.0,2200.0],"last_price":2100.0}"#.into(),
+                ),
+                Matcher::Regex("orders=".into()),
+                Matcher::UrlEncoded("expires_at".into(), "2024-12-31 15:30:00".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": {"trigger_id": 123}}"#)
+            .create_async()
+            .await;
+
+        let mut params = GTTModifyParams::new(
+            123,
+            condition(vec![2050.0, 2200.0]),
+            vec![
+                order(OrderType::LIMIT, 2045.0),
+                order(OrderType::LIMIT, 2200.0),
+            ],
+        );
+        params.expires_at = Some("2024-12-31T10:00:00Z".parse().unwrap());
+        let response = mock_client(server.url())
+            .modify_gtt_typed(123, params)
+            .await
+            .unwrap();
+        assert_eq!(response.id, 123);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_modify_gtt_rejects_descending_oco_triggers() {
+        let server = mockito::Server::new_async().await;
+        let params = GTTModifyParams::new(
+            123,
+            condition(vec![2200.0, 2050.0]),
+            vec![
+                order(OrderType::LIMIT, 2200.0),
+                order(OrderType::LIMIT, 2045.0),
+            ],
+        );
+
+        let err = mock_client(server.url())
+            .modify_gtt_typed(123, params)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, KiteError::General(ref msg) if msg.contains("ascending")));
+    }
+
+    #[tokio::test]
+    async fn test_delete_gtt_typed() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/gtt/triggers/123")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": {"trigger_id": 123}}"#)
+            .create_async()
+            .await;
+
+        let response = mock_client(server.url())
+            .delete_gtt_typed(123)
+            .await
+            .unwrap();
+        assert_eq!(response.id, 123);
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod place_order_tests {
+    use super::{mock_client, mock_config};
+    use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig, RetryConfig};
+    use kiteconnect_async_wasm::models::common::{Exchange, OrderType, Product, TransactionType};
+    use kiteconnect_async_wasm::models::orders::{OrderBuilder, OrderParams};
+    use mockito::Matcher;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_place_order_typed_sends_builder_fields() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/orders/iceberg")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("variety".into(), "iceberg".into()),
+                Matcher::UrlEncoded("exchange".into(), "NSE".into()),
+                Matcher::UrlEncoded("tradingsymbol".into(), "RELIANCE".into()),
+                Matcher::UrlEncoded("transaction_type".into(), "BUY".into()),
+                Matcher::UrlEncoded("order_type".into(), "LIMIT".into()),
+                Matcher::UrlEncoded("product".into(), "CNC".into()),
+                Matcher::UrlEncoded("quantity".into(), "100".into()),
+                Matcher::UrlEncoded("price".into(), "2500.5".into()),
+                Matcher::UrlEncoded("validity".into(), "DAY".into()),
+                Matcher::UrlEncoded("iceberg_legs".into(), "4".into()),
+                Matcher::UrlEncoded("iceberg_quantity".into(), "25".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": {"order_id": "151220000000000"}}"#)
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let params = OrderBuilder::new()
+            .exchange(Exchange::NSE)
+            .trading_symbol("RELIANCE")
+            .transaction_type(TransactionType::BUY)
+            .order_type(OrderType::LIMIT)
+            .product(Product::CNC)
+            .quantity(100)
+            .price(2500.5)
+            .iceberg(4, 25)
+            .build()
+            .unwrap();
+
+        let response = client.place_order_typed(&params).await.unwrap();
+        assert_eq!(response.order_id, "151220000000000");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_place_order_typed_routes_builder_presets() {
+        let mut server = mockito::Server::new_async().await;
+        let client = mock_client(server.url());
+
+        let limit_buy = || {
+            OrderBuilder::new()
+                .exchange(Exchange::NSE)
+                .trading_symbol("INFY")
+                .transaction_type(TransactionType::BUY)
+                .order_type(OrderType::LIMIT)
+                .product(Product::MIS)
+                .quantity(10)
+                .price(1500.0)
+        };
+        let presets = [
+            ("regular", limit_buy()),
+            ("amo", limit_buy().amo()),
+            ("co", limit_buy().co().trigger_price(1490.0)),
+            ("iceberg", limit_buy().iceberg(2, 5)),
+            ("auction", limit_buy().auction("22")),
+        ];
+
+        for (variety, builder) in presets {
+            let mock = server
+                .mock("POST", format!("/orders/{}", variety).as_str())
+                .match_body(Matcher::UrlEncoded("variety".into(), variety.into()))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(r#"{"status": "success", "data": {"order_id": "1"}}"#)
+                .expect(1)
+                .create_async()
+                .await;
+
+            client
+                .place_order_typed(&builder.build().unwrap())
+                .await
+                .unwrap();
+            mock.assert_async().await;
+        }
+    }
+
+    fn market_buy() -> OrderParams {
+        OrderBuilder::new()
+            .exchange(Exchange::NSE)
+            .trading_symbol("INFY")
+            .transaction_type(TransactionType::BUY)
+            .order_type(OrderType::MARKET)
+            .product(Product::CNC)
+            .quantity(1)
+            .build()
+            .unwrap()
+    }
+
+    fn retrying_client(server: &mockito::ServerGuard, retry_mutations: bool) -> KiteConnect {
+        let config = KiteConnectConfig {
+            retry_config: RetryConfig {
+                max_retries: 2,
+                base_delay: Duration::from_millis(1),
+                retry_mutations,
+                ..Default::default()
+            },
+            ..mock_config(server.url())
+        };
+        KiteConnect::new_with_config("test_key", config)
+    }
+
+    /// Client whose requests fail to connect, counted by `request_count`
+    fn unreachable_client(retry_mutations: bool) -> KiteConnect {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let config = KiteConnectConfig {
+            retry_config: RetryConfig {
+                max_retries: 2,
+                base_delay: Duration::from_millis(1),
+                retry_mutations,
+                ..Default::default()
+            },
+            ..mock_config(url)
+        };
+        KiteConnect::new_with_config("test_key", config)
+    }
+
+    #[tokio::test]
+    async fn test_place_order_is_not_retried_by_default() {
+        let client = unreachable_client(false);
+        assert!(client.place_order_typed(&market_buy()).await.is_err());
+        assert_eq!(client.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_place_order_retried_when_mutation_retries_enabled() {
+        let client = unreachable_client(true);
+        assert!(client.place_order_typed(&market_buy()).await.is_err());
+        assert_eq!(client.request_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_place_order_is_retried_by_default() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/orders/regular")
+            .with_status(429)
+            .with_body("Too many requests")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = retrying_client(&server, false);
+        assert!(client.place_order_typed(&market_buy()).await.is_err());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_orders_still_retried_without_mutation_retries() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/orders")
+            .with_status(429)
+            .with_body("Too many requests")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = retrying_client(&server, false);
+        assert!(client.orders_typed().await.is_err());
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod trigger_range_tests {
+    use super::mock_client;
+    use kiteconnect_async_wasm::models::common::TransactionType;
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn test_trigger_range_typed_parses_ranges() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments/trigger_range/sell")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::Regex("i=NSE%3AINFY".into()),
+                Matcher::Regex("i=NSE%3ARELIANCE".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"status": "success", "data": {
+                    "NSE:INFY": {"instrument_token": 408065, "lower": 1075.599, "upper": 1138.2},
+                    "NSE:RELIANCE": {"instrument_token": 738561, "lower": 870.57475, "upper": 902.15}
+                }}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = mock_client(server.url());
+
+        let ranges = client
+            .trigger_range_typed(TransactionType::SELL, &["NSE:INFY", "NSE:RELIANCE"])
+            .await
+            .unwrap();
+
+        let infy = &ranges["NSE:INFY"];
+        assert_eq!(infy.instrument_token, 408065);
+        assert!(infy.contains(1100.0));
+        assert!(!infy.contains(1200.0));
+        assert_eq!(infy.clamp(1200.0), 1138.2);
+        assert_eq!(ranges["NSE:RELIANCE"].upper, 902.15);
+        mock.assert_async().await;
+
+        assert!(client
+            .trigger_range_typed(TransactionType::BUY, &["not an instrument"])
+            .await
+            .is_err());
+    }
+}
+
+#[cfg(test)]
+mod lenient_parsing_tests {
+    use super::mock_client;
+
+    /// Holdings fixture with a non-optional field nulled out
+    fn drifted_holdings() -> String {
+        include_str!("../mocks/holdings.json").replacen("\"pnl\": 1470", "\"pnl\": null", 1)
+    }
+
+    #[tokio::test]
+    async fn test_lenient_parsing_survives_null_fields() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(drifted_holdings())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let strict = mock_client(server.url());
+        let err = strict.holdings_typed().await.unwrap_err();
+        assert!(err.to_string().contains("[0].pnl"), "got {}", err);
+
+        let lenient = strict.with_lenient_parsing(true);
+        let holdings = lenient.holdings_typed().await.unwrap();
+        assert_eq!(holdings.len(), 43);
+        assert_eq!(holdings[0].pnl, 0.0);
+        assert_eq!(holdings[0].trading_symbol, "BENGALASM");
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(test)]
+mod metrics_tests {
+    use super::mock_config;
+    use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig, RetryConfig};
+    use std::time::Duration;
+
+    fn client_for(server: &mockito::ServerGuard) -> KiteConnect {
+        let config = KiteConnectConfig {
+            retry_config: RetryConfig {
+                max_retries: 1,
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            },
+            ..mock_config(server.url())
+        };
+        KiteConnect::new_with_config("test_key", config)
+    }
+
+    #[tokio::test]
+    async fn test_metrics_snapshot_counts_retries_and_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings")
+            .with_status(429)
+            .with_body("Too many requests")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = client_for(&server);
+        assert!(client.holdings_typed().await.is_err());
+
+        let metrics = client.clone().metrics_snapshot();
+        assert_eq!(metrics.total_requests, 2);
+        assert_eq!(metrics.retries, 1);
+        assert_eq!(metrics.rate_limit_waits, 0);
+        assert_eq!(metrics.errors_by_type.get("RateLimited"), Some(&1));
+        assert_eq!(metrics.total_errors(), 1);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_metrics_snapshot_counts_cache_lookups() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/instruments")
+            .with_status(200)
+            .with_body(
+                "instrument_token,exchange_token,tradingsymbol,name,last_price,expiry,strike,tick_size,lot_size,instrument_type,segment,exchange\n\
+                 408065,1594,INFY,INFOSYS,0,,0,0.05,1,EQ,NSE,NSE\n",
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = client_for(&server);
+        client.instruments_typed(None).await.unwrap();
+        client.instruments_typed(None).await.unwrap();
+
+        let metrics = client.metrics_snapshot();
+        assert_eq!(metrics.cache_misses, 1);
+        assert_eq!(metrics.cache_hits, 1);
+        assert_eq!(metrics.cache_hit_ratio(), Some(0.5));
+        assert!(metrics.errors_by_type.is_empty());
+        mock.assert_async().await;
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tracing_tests {
+    use super::{mock_client, mock_config};
+    use kiteconnect_async_wasm::connect::{KiteConnect, KiteConnectConfig, RetryConfig};
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    type Spans = Arc<Mutex<Vec<HashMap<String, String>>>>;
+
+    /// Collects the fields of every span and tracks the entered one
+    #[derive(Default)]
+    struct SpanCollector {
+        spans: Spans,
+        metadata: Mutex<Vec<&'static Metadata<'static>>>,
+        entered: Mutex<Vec<Id>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl Subscriber for SpanCollector {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut spans = self.spans.lock().unwrap();
+            let mut fields = HashMap::new();
+            fields.insert("name".to_string(), attrs.metadata().name().to_string());
+            attrs.record(&mut FieldVisitor(&mut fields));
+            spans.push(fields);
+            self.metadata.lock().unwrap().push(attrs.metadata());
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            values.record(&mut FieldVisitor(&mut spans[span.into_u64() as usize - 1]));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, span: &Id) {
+            self.entered.lock().unwrap().push(span.clone());
+        }
+
+        fn exit(&self, _: &Id) {
+            self.entered.lock().unwrap().pop();
+        }
+
+        fn current_span(&self) -> tracing_core::span::Current {
+            match self.entered.lock().unwrap().last() {
+                Some(span) => {
+                    let metadata = self.metadata.lock().unwrap()[span.into_u64() as usize - 1];
+                    tracing_core::span::Current::new(span.clone(), metadata)
+                }
+                None => tracing_core::span::Current::none(),
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_request_spans_record_attempts() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/portfolio/holdings")
+            .with_status(429)
+            .with_body("Too many requests")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let collector = SpanCollector::default();
+        let spans = collector.spans.clone();
+        let _guard = tracing::subscriber::set_default(collector);
+
+        let config = KiteConnectConfig {
+            retry_config: RetryConfig {
+                max_retries: 1,
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            },
+            ..mock_config(server.url())
+        };
+        let client = KiteConnect::new_with_config("test_key", config);
+        assert!(client.holdings_typed().await.is_err());
+        mock.assert_async().await;
+
+        let spans: Vec<_> = spans
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|span| span["name"] == "kite_request")
+            .cloned()
+            .collect();
+        assert_eq!(spans.len(), 2);
+        for (attempt, span) in spans.iter().enumerate() {
+            assert_eq!(span["endpoint"], "/portfolio/holdings");
+            assert_eq!(span["method"], "GET");
+            assert_eq!(span["attempt"], attempt.to_string());
+            assert_eq!(span["status"], "429");
+            assert!(span.contains_key("elapsed_ms"));
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_json_request_spans() {
+        use kiteconnect_async_wasm::models::orders::OrderMarginParamBuilder;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/margins/orders")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"status": "success", "data": []}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let collector = SpanCollector::default();
+        let spans = collector.spans.clone();
+        let _guard = tracing::subscriber::set_default(collector);
+
+        let client = mock_client(server.url());
+        let order = OrderMarginParamBuilder::new()
+            .trading_symbol("INFY")
+            .quantity(1)
+            .price(1500.0)
+            .build()
+            .unwrap();
+        client.order_margins(vec![order]).await.unwrap();
+        mock.assert_async().await;
+
+        let spans = spans.lock().unwrap();
+        let span = spans
+            .iter()
+            .find(|span| span["name"] == "kite_request")
+            .expect("JSON request should be traced");
+        assert_eq!(span["endpoint"], "/margins/orders");
+        assert_eq!(span["method"], "POST");
+        assert_eq!(span["attempt"], "0");
+        assert_eq!(span["status"], "200");
+        assert!(span.contains_key("elapsed_ms"));
+    }
+}
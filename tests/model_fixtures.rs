@@ -0,0 +1,213 @@
+//! Serde round-trip checks for the typed models
+//!
+//! Each test deserializes a KiteConnect sample response from `mocks/`,
+//! serializes the models again and parses that output back, asserting that
+//! the important fields survive both steps. A renamed or mistyped field
+//! shows up here instead of against the live API.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use kiteconnect_async_wasm::models::common::{Exchange, GttStatus, Product, TransactionType};
+use kiteconnect_async_wasm::models::gtt::{GTTTriggerType, GTT};
+use kiteconnect_async_wasm::models::market_data::Quote;
+use kiteconnect_async_wasm::models::mutual_funds::{MFOrder, MFOrderStatus};
+use kiteconnect_async_wasm::models::orders::{Order, OrderStatus};
+use kiteconnect_async_wasm::models::portfolio::{Holding, Position};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// `data` of a fixture in `mocks/`
+fn fixture(name: &str) -> Value {
+    let path = format!("{}/mocks/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let body = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    let mut json: Value = serde_json::from_str(&body).unwrap();
+    json["data"].take()
+}
+
+/// Parse `data`, serialize the result and parse that again
+fn round_trip<T: DeserializeOwned + Serialize + Debug>(data: Value) -> (T, T) {
+    let parsed: T = serde_json::from_value(data).expect("fixture should deserialize");
+    let json = serde_json::to_value(&parsed).expect("model should serialize");
+    let reparsed: T = serde_json::from_value(json).expect("serialized model should deserialize");
+    (parsed, reparsed)
+}
+
+#[test]
+fn test_holdings_round_trip() {
+    let (parsed, reparsed): (Vec<Holding>, Vec<Holding>) = round_trip(fixture("holdings.json"));
+
+    assert_eq!(parsed.len(), 43);
+    assert_eq!(parsed[0].trading_symbol, "BENGALASM");
+    assert_eq!(parsed[0].exchange, Exchange::BSE);
+    assert_eq!(parsed[0].isin, "INE083K01017");
+    assert!(parsed[0].account_id.is_empty());
+    assert_eq!(parsed[2].day_change, 0.0);
+
+    for (a, b) in parsed.iter().zip(&reparsed) {
+        assert_eq!(a.trading_symbol, b.trading_symbol);
+        assert_eq!(a.exchange, b.exchange);
+        assert_eq!(a.instrument_token, b.instrument_token);
+        assert_eq!(a.product, b.product);
+        assert_eq!(a.quantity, b.quantity);
+        assert_eq!(a.t1_quantity, b.t1_quantity);
+        assert_eq!(a.average_price, b.average_price);
+        assert_eq!(a.last_price, b.last_price);
+        assert_eq!(a.pnl, b.pnl);
+        assert_eq!(a.day_change_percentage, b.day_change_percentage);
+    }
+}
+
+#[test]
+fn test_positions_round_trip() {
+    let data = fixture("positions.json");
+    let (parsed, reparsed): (Vec<Position>, Vec<Position>) = round_trip(data["net"].clone());
+
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed[0].exchange, Exchange::MCX);
+    assert_eq!(parsed[0].multiplier, 1000.0);
+    assert_eq!(parsed[1].overnight_quantity, 3);
+    assert_eq!(parsed[2].product, Product::CO);
+    assert_eq!(parsed[2].quantity, 0);
+
+    for (a, b) in parsed.iter().zip(&reparsed) {
+        assert_eq!(a.trading_symbol, b.trading_symbol);
+        assert_eq!(a.instrument_token, b.instrument_token);
+        assert_eq!(a.quantity, b.quantity);
+        assert_eq!(a.overnight_quantity, b.overnight_quantity);
+        assert_eq!(a.average_price, b.average_price);
+        assert_eq!(a.pnl, b.pnl);
+        assert_eq!(a.m2m, b.m2m);
+        assert_eq!(a.buy_m2m, b.buy_m2m);
+        assert_eq!(a.sell_m2m, b.sell_m2m);
+        assert_eq!(a.day_sell_quantity, b.day_sell_quantity);
+    }
+}
+
+#[test]
+fn test_orders_round_trip() {
+    let (parsed, reparsed): (Vec<Order>, Vec<Order>) = round_trip(fixture("orders.json"));
+
+    assert_eq!(parsed.len(), 7);
+    assert_eq!(parsed[0].status, OrderStatus::Complete);
+    assert_eq!(
+        parsed[0].order_timestamp.to_rfc3339(),
+        "2017-12-28T06:09:14+00:00"
+    );
+    assert_eq!(parsed[0].variety.as_deref(), Some("regular"));
+    assert!(parsed[0].meta.is_none());
+    assert_eq!(parsed[1].variety.as_deref(), Some("co"));
+    assert_eq!(parsed[1].product, Product::CO);
+    assert!(parsed[1].exchange_timestamp.is_some());
+
+    for (a, b) in parsed.iter().zip(&reparsed) {
+        assert_eq!(a.order_id, b.order_id);
+        assert_eq!(a.exchange_order_id, b.exchange_order_id);
+        assert_eq!(a.status, b.status);
+        assert_eq!(a.status_message, b.status_message);
+        assert_eq!(a.order_timestamp, b.order_timestamp);
+        assert_eq!(a.exchange_timestamp, b.exchange_timestamp);
+        assert_eq!(a.trading_symbol, b.trading_symbol);
+        assert_eq!(a.order_type, b.order_type);
+        assert_eq!(a.transaction_type, b.transaction_type);
+        assert_eq!(a.quantity, b.quantity);
+        assert_eq!(a.price, b.price);
+        assert_eq!(a.trigger_price, b.trigger_price);
+        assert_eq!(a.filled_quantity, b.filled_quantity);
+        assert_eq!(a.guid, b.guid);
+    }
+}
+
+#[test]
+fn test_quote_round_trip() {
+    let (parsed, reparsed): (HashMap<String, Quote>, HashMap<String, Quote>) =
+        round_trip(fixture("quote.json"));
+
+    let quote = &parsed["NSE:INFY"];
+    assert_eq!(quote.instrument_token, 408065);
+    assert_eq!(quote.last_price, 1412.95);
+    assert_eq!(quote.volume, 7360198);
+    assert_eq!(quote.open_interest, Some(0));
+    assert_eq!(quote.ohlc.close, 1389.65);
+    assert_eq!(quote.upper_circuit_limit, Some(1528.6));
+    assert_eq!(quote.depth.sell[0].quantity, 5191);
+    assert_eq!(
+        quote.last_trade_time.unwrap().to_rfc3339(),
+        "2021-06-08T10:15:52+00:00"
+    );
+
+    let again = &reparsed["NSE:INFY"];
+    assert_eq!(quote.last_trade_time, again.last_trade_time);
+    assert_eq!(quote.last_price, again.last_price);
+    assert_eq!(quote.average_price, again.average_price);
+    assert_eq!(quote.buy_quantity, again.buy_quantity);
+    assert_eq!(quote.sell_quantity, again.sell_quantity);
+    assert_eq!(quote.net_change, again.net_change);
+    assert_eq!(quote.lower_circuit_limit, again.lower_circuit_limit);
+    assert_eq!(quote.ohlc.open, again.ohlc.open);
+    assert_eq!(quote.depth.buy.len(), again.depth.buy.len());
+    assert_eq!(quote.depth.buy[1].price, again.depth.buy[1].price);
+}
+
+#[test]
+fn test_gtts_round_trip() {
+    let (parsed, reparsed): (Vec<GTT>, Vec<GTT>) = round_trip(fixture("gtts.json"));
+
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed[0].gtt_type, GTTTriggerType::Single);
+    assert_eq!(parsed[0].status, GttStatus::Active);
+    assert_eq!(parsed[0].condition.trigger_values, vec![1300.0]);
+    assert_eq!(parsed[1].gtt_type, GTTTriggerType::TwoLeg);
+    let result = parsed[1].orders[1].result.as_ref().unwrap();
+    assert_eq!(result.order_id, "210531000421286");
+    assert!(result.rejection_reason.is_none());
+
+    for (a, b) in parsed.iter().zip(&reparsed) {
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.gtt_type, b.gtt_type);
+        assert_eq!(a.status, b.status);
+        assert_eq!(a.created_at, b.created_at);
+        assert_eq!(a.expires_at, b.expires_at);
+        assert_eq!(a.condition.trading_symbol, b.condition.trading_symbol);
+        assert_eq!(a.condition.trigger_values, b.condition.trigger_values);
+        assert_eq!(a.orders.len(), b.orders.len());
+        for (x, y) in a.orders.iter().zip(&b.orders) {
+            assert_eq!(x.transaction_type, y.transaction_type);
+            assert_eq!(x.quantity, y.quantity);
+            assert_eq!(x.price, y.price);
+            assert_eq!(
+                x.result.as_ref().map(|r| &r.order_id),
+                y.result.as_ref().map(|r| &r.order_id)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_mf_orders_round_trip() {
+    let (parsed, reparsed): (Vec<MFOrder>, Vec<MFOrder>) = round_trip(fixture("mf_orders.json"));
+
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed[0].status, MFOrderStatus::Cancelled);
+    assert_eq!(parsed[0].transaction_type, TransactionType::BUY);
+    assert_eq!(parsed[0].amount, Some(5000.0));
+    assert_eq!(
+        parsed[0].order_timestamp.to_rfc3339(),
+        "2017-12-28T06:14:00+00:00"
+    );
+    assert!(parsed[1].exchange_timestamp.is_none());
+
+    for (a, b) in parsed.iter().zip(&reparsed) {
+        assert_eq!(a.order_id, b.order_id);
+        assert_eq!(a.trading_symbol, b.trading_symbol);
+        assert_eq!(a.fund, b.fund);
+        assert_eq!(a.status, b.status);
+        assert_eq!(a.amount, b.amount);
+        assert_eq!(a.quantity, b.quantity);
+        assert_eq!(a.order_timestamp, b.order_timestamp);
+        assert_eq!(a.exchange_timestamp, b.exchange_timestamp);
+        assert_eq!(a.average_price, b.average_price);
+        assert_eq!(a.purchase_type, b.purchase_type);
+    }
+}